@@ -0,0 +1,318 @@
+use std::io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult};
+
+use crate::netlink::{Attribute, RawAttribute, RawAttributeIter};
+use crate::netlink::{Flags, Message, Payload, ReceivedMessage, Socket, Type};
+use crate::utils;
+
+use super::{GenericHeader, GENL_HDRLEN};
+
+pub const GENL_ID_CTRL: u16 = 0x10;
+
+pub const CTRL_CMD_UNSPEC: u8 = 0;
+pub const CTRL_CMD_NEWFAMILY: u8 = 1;
+pub const CTRL_CMD_DELFAMILY: u8 = 2;
+pub const CTRL_CMD_GETFAMILY: u8 = 3;
+
+pub const CTRL_ATTR_UNSPEC: u16 = 0;
+pub const CTRL_ATTR_FAMILY_ID: u16 = 1;
+pub const CTRL_ATTR_FAMILY_NAME: u16 = 2;
+pub const CTRL_ATTR_VERSION: u16 = 3;
+pub const CTRL_ATTR_HDRSIZE: u16 = 4;
+pub const CTRL_ATTR_MAXATTR: u16 = 5;
+pub const CTRL_ATTR_OPS: u16 = 6;
+pub const CTRL_ATTR_MCAST_GROUPS: u16 = 7;
+
+pub const CTRL_ATTR_MCAST_GRP_UNSPEC: u16 = 0;
+pub const CTRL_ATTR_MCAST_GRP_NAME: u16 = 1;
+pub const CTRL_ATTR_MCAST_GRP_ID: u16 = 2;
+
+/// Attributes of the generic netlink controller, which keeps track of the
+/// registered families. They correspond to `CTRL_ATTR_*` in libc.
+#[doc(alias("CTRL_ATTR_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ControllerAttribute {
+    /// `CTRL_ATTR_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `CTRL_ATTR_FAMILY_ID` - the id of a family, used as the message type
+    /// of its messages.
+    FamilyId(u16),
+
+    /// `CTRL_ATTR_FAMILY_NAME`, e.g. `wireguard`.
+    FamilyName(String),
+
+    /// `CTRL_ATTR_VERSION`
+    Version(u32),
+
+    /// `CTRL_ATTR_HDRSIZE` - the size of the family-specific header that
+    /// follows the `GenericHeader`.
+    HeaderSize(u32),
+
+    /// `CTRL_ATTR_MAXATTR`
+    MaxAttribute(u32),
+
+    /// `CTRL_ATTR_OPS`
+    Operations(Vec<u8>),
+
+    /// `CTRL_ATTR_MCAST_GROUPS` - the multicast groups of a family, each
+    /// given as a list of attributes.
+    MulticastGroups(Vec<Vec<MulticastGroupAttribute>>),
+
+    Other(u16, Vec<u8>),
+}
+
+/// Attributes of a multicast group of a generic netlink family. They
+/// correspond to `CTRL_ATTR_MCAST_GRP_*` in libc.
+#[doc(alias("CTRL_ATTR_MCAST_GRP_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MulticastGroupAttribute {
+    /// `CTRL_ATTR_MCAST_GRP_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `CTRL_ATTR_MCAST_GRP_NAME`
+    Name(String),
+
+    /// `CTRL_ATTR_MCAST_GRP_ID` - the id to join with `NETLINK_ADD_MEMBERSHIP`.
+    Id(u32),
+
+    Other(u16, Vec<u8>),
+}
+
+impl ControllerAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                CTRL_ATTR_UNSPEC
+            }
+            Self::FamilyId(id) => {
+                buffer.extend(id.to_ne_bytes());
+                CTRL_ATTR_FAMILY_ID
+            }
+            Self::FamilyName(name) => {
+                buffer.extend(name.bytes());
+                buffer.push(0u8); // zero-terminated string
+                CTRL_ATTR_FAMILY_NAME
+            }
+            Self::Version(version) => {
+                buffer.extend(version.to_ne_bytes());
+                CTRL_ATTR_VERSION
+            }
+            Self::HeaderSize(size) => {
+                buffer.extend(size.to_ne_bytes());
+                CTRL_ATTR_HDRSIZE
+            }
+            Self::MaxAttribute(max) => {
+                buffer.extend(max.to_ne_bytes());
+                CTRL_ATTR_MAXATTR
+            }
+            Self::Operations(content) => {
+                buffer.extend(content.iter());
+                CTRL_ATTR_OPS
+            }
+            Self::MulticastGroups(groups) => {
+                for (i, group) in groups.iter().enumerate() {
+                    // The groups are nested in attributes numbered from 1
+                    utils::serialize_attribute_into(buffer, |buffer| {
+                        group.iter().for_each(|a| a.serialize_into(buffer));
+                        i as u16 + 1
+                    });
+                }
+                CTRL_ATTR_MCAST_GROUPS
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for ControllerAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            CTRL_ATTR_UNSPEC => Self::Unspecified(content),
+            CTRL_ATTR_FAMILY_ID => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::FamilyId(u16::from_ne_bytes(content))
+            }
+            CTRL_ATTR_FAMILY_NAME => {
+                let mut content = String::from_utf8(content).ok()?;
+                if content.ends_with('\0') {
+                    content.pop();
+                }
+                Self::FamilyName(content)
+            }
+            CTRL_ATTR_VERSION => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Version(u32::from_ne_bytes(content))
+            }
+            CTRL_ATTR_HDRSIZE => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::HeaderSize(u32::from_ne_bytes(content))
+            }
+            CTRL_ATTR_MAXATTR => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::MaxAttribute(u32::from_ne_bytes(content))
+            }
+            CTRL_ATTR_OPS => Self::Operations(content),
+            CTRL_ATTR_MCAST_GROUPS => {
+                let groups = RawAttributeIter::new(content.iter().cloned())
+                    .map(|group| {
+                        RawAttributeIter::new(group.into_payload().into_iter())
+                            .map(MulticastGroupAttribute::from_raw)
+                            .try_collect()
+                    })
+                    .try_collect()?;
+
+                Self::MulticastGroups(groups)
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+impl MulticastGroupAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                CTRL_ATTR_MCAST_GRP_UNSPEC
+            }
+            Self::Name(name) => {
+                buffer.extend(name.bytes());
+                buffer.push(0u8); // zero-terminated string
+                CTRL_ATTR_MCAST_GRP_NAME
+            }
+            Self::Id(id) => {
+                buffer.extend(id.to_ne_bytes());
+                CTRL_ATTR_MCAST_GRP_ID
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for MulticastGroupAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            CTRL_ATTR_MCAST_GRP_UNSPEC => Self::Unspecified(content),
+            CTRL_ATTR_MCAST_GRP_NAME => {
+                let mut content = String::from_utf8(content).ok()?;
+                if content.ends_with('\0') {
+                    content.pop();
+                }
+                Self::Name(content)
+            }
+            CTRL_ATTR_MCAST_GRP_ID => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Id(u32::from_ne_bytes(content))
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+/// A message of the generic netlink controller (`nlctrl`), e.g. a
+/// `CTRL_CMD_GETFAMILY` request or a `CTRL_CMD_NEWFAMILY` reply.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ControllerMessage {
+    command: u8,
+    attributes: Vec<ControllerAttribute>,
+}
+
+impl ControllerMessage {
+    pub const fn new(command: u8, attributes: Vec<ControllerAttribute>) -> Self {
+        Self {
+            command,
+            attributes,
+        }
+    }
+
+    pub const fn command(&self) -> u8 {
+        self.command
+    }
+
+    pub fn attributes(&self) -> &[ControllerAttribute] {
+        &self.attributes
+    }
+}
+
+impl Payload for ControllerMessage {
+    fn message_type() -> Type {
+        Type::Generic(GENL_ID_CTRL)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        let mut buffer = Vec::with_capacity(GENL_HDRLEN);
+        GenericHeader::new(self.command, 2).serialize_into(&mut buffer);
+
+        for attr in &self.attributes {
+            attr.serialize_into(&mut buffer)
+        }
+
+        buffer.into_boxed_slice()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        let header = GenericHeader::deserialize(bytes)?;
+        let attributes = RawAttributeIter::new(bytes[GENL_HDRLEN..].iter().cloned())
+            .map(ControllerAttribute::from_raw)
+            .try_collect()?;
+
+        Some(Self {
+            command: header.command,
+            attributes,
+        })
+    }
+}
+
+/// Looks up the id of the generic netlink family of the given name, e.g.
+/// `wireguard`. The id is needed to address messages to the family, and is
+/// recorded in the socket so that the replies of the family are recognized.
+///
+/// Fails with `NotFound` if the family is not registered, e.g. because its
+/// kernel module is not loaded.
+pub fn resolve_family_id(socket: &mut Socket, name: &str) -> IoResult<u16> {
+    let message = Message::new(
+        Flags::Request,
+        ControllerMessage::new(
+            CTRL_CMD_GETFAMILY,
+            vec![ControllerAttribute::FamilyName(name.to_owned())],
+        ),
+    );
+    socket.send_message(&message)?;
+
+    let reply = match socket.receive_message::<ControllerMessage>()? {
+        ReceivedMessage::Message(reply) => reply.into_payload(),
+        ReceivedMessage::Error(error) => {
+            return Err(IoError::from_raw_os_error(-error.payload().error_code()))
+        }
+        ReceivedMessage::Multipart(_) => return Err(IoErrorKind::InvalidData.into()),
+    };
+
+    let id = reply
+        .attributes()
+        .iter()
+        .find_map(|attr| match attr {
+            ControllerAttribute::FamilyId(id) => Some(*id),
+            _ => None,
+        })
+        .ok_or(IoErrorKind::InvalidData)?;
+
+    socket.add_family(name, id);
+    Ok(id)
+}
@@ -0,0 +1,189 @@
+use crate::netlink::{Attribute, RawAttribute, RawAttributeIter};
+use crate::utils;
+
+pub const ETHTOOL_A_BITSET_UNSPEC: u16 = 0;
+pub const ETHTOOL_A_BITSET_NOMASK: u16 = 1;
+pub const ETHTOOL_A_BITSET_SIZE: u16 = 2;
+pub const ETHTOOL_A_BITSET_BITS: u16 = 3;
+pub const ETHTOOL_A_BITSET_VALUE: u16 = 4;
+pub const ETHTOOL_A_BITSET_MASK: u16 = 5;
+
+pub const ETHTOOL_A_BITSET_BITS_UNSPEC: u16 = 0;
+pub const ETHTOOL_A_BITSET_BITS_BIT: u16 = 1;
+
+pub const ETHTOOL_A_BITSET_BIT_UNSPEC: u16 = 0;
+pub const ETHTOOL_A_BITSET_BIT_INDEX: u16 = 1;
+pub const ETHTOOL_A_BITSET_BIT_NAME: u16 = 2;
+pub const ETHTOOL_A_BITSET_BIT_VALUE: u16 = 3;
+
+/// Attributes of an ethtool bitset, e.g. of link modes or features. They
+/// correspond to `ETHTOOL_A_BITSET_*` in <linux/ethtool_netlink.h>.
+///
+/// A bitset is either compact (`Value` and `Mask` bitmaps) or a list of
+/// `Bits`, which is what the kernel replies with by default. In requests,
+/// only the listed bits are changed, unless `NoMask` is given; then the
+/// listed bits are set and all others cleared.
+#[doc(alias("ETHTOOL_A_BITSET_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EthtoolBitsetAttribute {
+    /// `ETHTOOL_A_BITSET_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `ETHTOOL_A_BITSET_NOMASK`
+    NoMask,
+
+    /// `ETHTOOL_A_BITSET_SIZE` - the number of significant bits.
+    Size(u32),
+
+    /// `ETHTOOL_A_BITSET_BITS`
+    Bits(Vec<EthtoolBit>),
+
+    /// `ETHTOOL_A_BITSET_VALUE`
+    Value(Vec<u8>),
+
+    /// `ETHTOOL_A_BITSET_MASK`
+    Mask(Vec<u8>),
+
+    Other(u16, Vec<u8>),
+}
+
+/// A single bit of a bitset, nested in `ETHTOOL_A_BITSET_BITS` as
+/// `ETHTOOL_A_BITSET_BIT_*` attributes. It is identified by its index, its
+/// name, or both.
+#[doc(alias("ETHTOOL_A_BITSET_BIT_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EthtoolBit {
+    pub index: Option<u32>,
+    pub name: Option<String>,
+    pub value: bool,
+}
+
+impl EthtoolBit {
+    pub fn named(name: &str, value: bool) -> Self {
+        Self {
+            index: None,
+            name: Some(name.to_owned()),
+            value,
+        }
+    }
+
+    pub const fn indexed(index: u32, value: bool) -> Self {
+        Self {
+            index: Some(index),
+            name: None,
+            value,
+        }
+    }
+
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| {
+            if let Some(index) = self.index {
+                utils::serialize_attribute_into(buffer, |buffer| {
+                    buffer.extend(index.to_ne_bytes());
+                    ETHTOOL_A_BITSET_BIT_INDEX
+                });
+            }
+            if let Some(name) = &self.name {
+                utils::serialize_attribute_into(buffer, |buffer| {
+                    buffer.extend(name.bytes());
+                    buffer.push(0u8); // zero-terminated string
+                    ETHTOOL_A_BITSET_BIT_NAME
+                });
+            }
+            if self.value {
+                // A flag, i.e. an attribute without payload
+                utils::serialize_attribute_into(buffer, |_| ETHTOOL_A_BITSET_BIT_VALUE);
+            }
+
+            ETHTOOL_A_BITSET_BITS_BIT | libc::NLA_F_NESTED as u16
+        });
+    }
+
+    pub fn deserialize(bytes: &[u8]) -> Option<Self> {
+        let mut bit = Self {
+            index: None,
+            name: None,
+            value: false,
+        };
+
+        for attr in RawAttributeIter::new(bytes.iter().cloned()) {
+            match attr.attr_type() {
+                ETHTOOL_A_BITSET_BIT_INDEX => {
+                    let content = <[u8; 4]>::try_from(attr.payload()).ok()?;
+                    bit.index = Some(u32::from_ne_bytes(content));
+                }
+                ETHTOOL_A_BITSET_BIT_NAME => {
+                    let mut name = String::from_utf8(attr.into_payload()).ok()?;
+                    if name.ends_with('\0') {
+                        name.pop();
+                    }
+                    bit.name = Some(name);
+                }
+                ETHTOOL_A_BITSET_BIT_VALUE => bit.value = true,
+                _ => {}
+            }
+        }
+
+        Some(bit)
+    }
+}
+
+impl EthtoolBitsetAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                ETHTOOL_A_BITSET_UNSPEC
+            }
+            Self::NoMask => ETHTOOL_A_BITSET_NOMASK,
+            Self::Size(size) => {
+                buffer.extend(size.to_ne_bytes());
+                ETHTOOL_A_BITSET_SIZE
+            }
+            Self::Bits(bits) => {
+                bits.iter().for_each(|bit| bit.serialize_into(buffer));
+                ETHTOOL_A_BITSET_BITS | libc::NLA_F_NESTED as u16
+            }
+            Self::Value(value) => {
+                buffer.extend(value.iter());
+                ETHTOOL_A_BITSET_VALUE
+            }
+            Self::Mask(mask) => {
+                buffer.extend(mask.iter());
+                ETHTOOL_A_BITSET_MASK
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for EthtoolBitsetAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            ETHTOOL_A_BITSET_UNSPEC => Self::Unspecified(content),
+            ETHTOOL_A_BITSET_NOMASK => Self::NoMask,
+            ETHTOOL_A_BITSET_SIZE => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Size(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_BITSET_BITS => {
+                let bits = RawAttributeIter::new(content.iter().cloned())
+                    .map(|bit| EthtoolBit::deserialize(bit.payload()))
+                    .try_collect()?;
+
+                Self::Bits(bits)
+            }
+            ETHTOOL_A_BITSET_VALUE => Self::Value(content),
+            ETHTOOL_A_BITSET_MASK => Self::Mask(content),
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
@@ -0,0 +1,200 @@
+use std::io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult};
+
+use crate::genetlink::resolve_family_id;
+use crate::netlink::{Attribute, RawAttribute, RawAttributeIter};
+use crate::netlink::{Flags, Message, ReceivedMessage, Socket};
+use crate::utils;
+
+use super::{
+    EthtoolAttributes, EthtoolBit, EthtoolBitsetAttribute, EthtoolCommand, EthtoolHeaderAttribute,
+    EthtoolReply, EthtoolRequest, ETHTOOL_GENL_NAME,
+};
+
+pub const ETHTOOL_A_FEATURES_UNSPEC: u16 = 0;
+pub const ETHTOOL_A_FEATURES_HEADER: u16 = 1;
+pub const ETHTOOL_A_FEATURES_HW: u16 = 2;
+pub const ETHTOOL_A_FEATURES_WANTED: u16 = 3;
+pub const ETHTOOL_A_FEATURES_ACTIVE: u16 = 4;
+pub const ETHTOOL_A_FEATURES_NOCHANGE: u16 = 5;
+
+/// Generic receive offload (`ethtool -K gro`)
+pub const FEATURE_GRO: &str = "rx-gro";
+/// Generic segmentation offload (`ethtool -K gso`)
+pub const FEATURE_GSO: &str = "tx-generic-segmentation";
+/// TCP segmentation offload over IPv4 (part of `ethtool -K tso`)
+pub const FEATURE_TSO: &str = "tx-tcp-segmentation";
+/// TCP segmentation offload over IPv6 (part of `ethtool -K tso`)
+pub const FEATURE_TSO6: &str = "tx-tcp6-segmentation";
+/// Receive checksum offload (`ethtool -K rx`)
+pub const FEATURE_RX_CHECKSUM: &str = "rx-checksum";
+
+/// Attributes of `GetFeatures` and `SetFeatures` requests and replies. They
+/// correspond to `ETHTOOL_A_FEATURES_*` in <linux/ethtool_netlink.h>.
+///
+/// Features are named like in `ethtool -k`, see `FEATURE_GRO` and friends. To
+/// toggle them, send the changed bits as `Wanted` in a `SetFeatures` request.
+/// The reply lists the `Wanted` features that could not be changed, and the
+/// `Active` ones that changed.
+#[doc(alias("ETHTOOL_A_FEATURES_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EthtoolFeaturesAttribute {
+    /// `ETHTOOL_A_FEATURES_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `ETHTOOL_A_FEATURES_HEADER`
+    Header(Vec<EthtoolHeaderAttribute>),
+
+    /// `ETHTOOL_A_FEATURES_HW` - the features that can be changed.
+    Hardware(Vec<EthtoolBitsetAttribute>),
+
+    /// `ETHTOOL_A_FEATURES_WANTED` - the features requested by the user.
+    Wanted(Vec<EthtoolBitsetAttribute>),
+
+    /// `ETHTOOL_A_FEATURES_ACTIVE` - the features in effect.
+    Active(Vec<EthtoolBitsetAttribute>),
+
+    /// `ETHTOOL_A_FEATURES_NOCHANGE` - the features that can never be
+    /// changed.
+    NoChange(Vec<EthtoolBitsetAttribute>),
+
+    Other(u16, Vec<u8>),
+}
+
+impl EthtoolFeaturesAttribute {
+    /// Builds the `Wanted` bitset of a `SetFeatures` request that turns the
+    /// named feature on or off, e.g. `FEATURE_GRO`. The other features are left
+    /// unchanged.
+    pub fn wanted(name: &str, enabled: bool) -> Self {
+        Self::Wanted(vec![EthtoolBitsetAttribute::Bits(vec![EthtoolBit::named(
+            name, enabled,
+        )])])
+    }
+
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                ETHTOOL_A_FEATURES_UNSPEC
+            }
+            Self::Header(attrs) => {
+                attrs.iter().for_each(|a| a.serialize_into(buffer));
+                ETHTOOL_A_FEATURES_HEADER | libc::NLA_F_NESTED as u16
+            }
+            Self::Hardware(bitset) => {
+                bitset.iter().for_each(|a| a.serialize_into(buffer));
+                ETHTOOL_A_FEATURES_HW | libc::NLA_F_NESTED as u16
+            }
+            Self::Wanted(bitset) => {
+                bitset.iter().for_each(|a| a.serialize_into(buffer));
+                ETHTOOL_A_FEATURES_WANTED | libc::NLA_F_NESTED as u16
+            }
+            Self::Active(bitset) => {
+                bitset.iter().for_each(|a| a.serialize_into(buffer));
+                ETHTOOL_A_FEATURES_ACTIVE | libc::NLA_F_NESTED as u16
+            }
+            Self::NoChange(bitset) => {
+                bitset.iter().for_each(|a| a.serialize_into(buffer));
+                ETHTOOL_A_FEATURES_NOCHANGE | libc::NLA_F_NESTED as u16
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for EthtoolFeaturesAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let bitset = || -> Option<Vec<EthtoolBitsetAttribute>> {
+            RawAttributeIter::new(content.iter().cloned())
+                .map(EthtoolBitsetAttribute::from_raw)
+                .try_collect()
+        };
+
+        let attr = match attr_type {
+            ETHTOOL_A_FEATURES_HEADER => {
+                let attrs = RawAttributeIter::new(content.iter().cloned())
+                    .map(EthtoolHeaderAttribute::from_raw)
+                    .try_collect()?;
+
+                Self::Header(attrs)
+            }
+            ETHTOOL_A_FEATURES_HW => Self::Hardware(bitset()?),
+            ETHTOOL_A_FEATURES_WANTED => Self::Wanted(bitset()?),
+            ETHTOOL_A_FEATURES_ACTIVE => Self::Active(bitset()?),
+            ETHTOOL_A_FEATURES_NOCHANGE => Self::NoChange(bitset()?),
+            ETHTOOL_A_FEATURES_UNSPEC => Self::Unspecified(content),
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+/// Turns the named feature of a device on or off, like `ethtool -K`, e.g.
+/// `FEATURE_GRO`. The id of the ethtool family is resolved on the socket if it
+/// is not known yet.
+///
+/// Fails with `Unsupported` if the feature is unknown, and with `InvalidInput`
+/// if it cannot be changed on this device.
+pub fn set_feature(
+    socket: &mut Socket,
+    interface_index: u32,
+    name: &str,
+    enabled: bool,
+) -> IoResult<()> {
+    let family_id = match socket.context().family_id(ETHTOOL_GENL_NAME) {
+        Some(id) => id,
+        None => resolve_family_id(socket, ETHTOOL_GENL_NAME)?,
+    };
+
+    let attributes = vec![
+        EthtoolFeaturesAttribute::Header(vec![EthtoolHeaderAttribute::DeviceIndex(
+            interface_index,
+        )]),
+        EthtoolFeaturesAttribute::wanted(name, enabled),
+    ];
+
+    let request = EthtoolRequest::new(
+        family_id,
+        EthtoolCommand::SetFeatures,
+        EthtoolAttributes::Features(attributes),
+    );
+    socket.send_message(&Message::new(Flags::Request | Flags::Ack, request))?;
+
+    // The reply lists the wanted features that could not be changed, and is
+    // followed by the acknowledgement
+    let mut result = Ok(());
+    loop {
+        match socket.receive_message::<EthtoolReply>()? {
+            ReceivedMessage::Message(reply) => {
+                let EthtoolAttributes::Features(attrs) = reply.payload().attributes() else {
+                    continue;
+                };
+
+                let unchanged = attrs.iter().any(|attr| match attr {
+                    EthtoolFeaturesAttribute::Wanted(bitset) => bitset.iter().any(|attr| {
+                        matches!(attr, EthtoolBitsetAttribute::Bits(bits) if !bits.is_empty())
+                    }),
+                    _ => false,
+                });
+
+                if unchanged {
+                    result = Err(IoError::new(
+                        IoErrorKind::InvalidInput,
+                        "the feature cannot be changed",
+                    ));
+                }
+            }
+            ReceivedMessage::Error(error) if error.payload().error_code() == 0 => return result,
+            ReceivedMessage::Error(error) => {
+                return Err(IoError::from_raw_os_error(-error.payload().error_code()))
+            }
+            ReceivedMessage::Multipart(_) => return Err(IoErrorKind::InvalidData.into()),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::netlink::{Attribute, RawAttribute};
+use crate::utils;
+
+use bitflags::bitflags;
+
+pub const ETHTOOL_A_HEADER_UNSPEC: u16 = 0;
+pub const ETHTOOL_A_HEADER_DEV_INDEX: u16 = 1;
+pub const ETHTOOL_A_HEADER_DEV_NAME: u16 = 2;
+pub const ETHTOOL_A_HEADER_FLAGS: u16 = 3;
+
+pub const ETHTOOL_FLAG_COMPACT_BITSETS: u32 = 1 << 0;
+pub const ETHTOOL_FLAG_OMIT_REPLY: u32 = 1 << 1;
+pub const ETHTOOL_FLAG_STATS: u32 = 1 << 2;
+
+bitflags! {
+    /// A bitfield of flags of an ethtool request header.
+    ///
+    /// It corresponds to `ETHTOOL_FLAG_*` in <linux/ethtool_netlink.h>.
+    #[doc(alias("ETHTOOL_FLAG_"))]
+    #[derive(Copy, Debug, Clone, PartialEq, Eq)]
+    pub struct EthtoolHeaderFlags: u32 {
+        /// Reply with bitmaps instead of lists of named bits
+        const CompactBitsets = ETHTOOL_FLAG_COMPACT_BITSETS;
+        /// Do not send a reply to a `Set*` request
+        const OmitReply = ETHTOOL_FLAG_OMIT_REPLY;
+        /// Include statistics in the reply
+        const Stats = ETHTOOL_FLAG_STATS;
+    }
+}
+
+/// Attributes of the header nested in every ethtool message, which
+/// identifies the device. They correspond to `ETHTOOL_A_HEADER_*` in
+/// <linux/ethtool_netlink.h>.
+///
+/// Requests carry either `DeviceIndex`, e.g. from `resolve_interface_index`,
+/// or `DeviceName`; replies carry both.
+#[doc(alias("ETHTOOL_A_HEADER_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EthtoolHeaderAttribute {
+    /// `ETHTOOL_A_HEADER_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `ETHTOOL_A_HEADER_DEV_INDEX`
+    DeviceIndex(u32),
+
+    /// `ETHTOOL_A_HEADER_DEV_NAME`
+    DeviceName(String),
+
+    /// `ETHTOOL_A_HEADER_FLAGS`
+    Flags(EthtoolHeaderFlags),
+
+    Other(u16, Vec<u8>),
+}
+
+impl EthtoolHeaderAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                ETHTOOL_A_HEADER_UNSPEC
+            }
+            Self::DeviceIndex(index) => {
+                buffer.extend(index.to_ne_bytes());
+                ETHTOOL_A_HEADER_DEV_INDEX
+            }
+            Self::DeviceName(name) => {
+                buffer.extend(name.bytes());
+                buffer.push(0u8); // zero-terminated string
+                ETHTOOL_A_HEADER_DEV_NAME
+            }
+            Self::Flags(flags) => {
+                buffer.extend(flags.bits().to_ne_bytes());
+                ETHTOOL_A_HEADER_FLAGS
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for EthtoolHeaderAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            ETHTOOL_A_HEADER_UNSPEC => Self::Unspecified(content),
+            ETHTOOL_A_HEADER_DEV_INDEX => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::DeviceIndex(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_HEADER_DEV_NAME => {
+                let mut content = String::from_utf8(content).ok()?;
+                if content.ends_with('\0') {
+                    content.pop();
+                }
+                Self::DeviceName(content)
+            }
+            ETHTOOL_A_HEADER_FLAGS => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Flags(EthtoolHeaderFlags::from_bits_retain(u32::from_ne_bytes(content)))
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
@@ -0,0 +1,279 @@
+use crate::netlink::{Attribute, RawAttribute, RawAttributeIter};
+use crate::utils;
+
+use super::{EthtoolBitsetAttribute, EthtoolHeaderAttribute};
+
+pub const ETHTOOL_A_LINKINFO_UNSPEC: u16 = 0;
+pub const ETHTOOL_A_LINKINFO_HEADER: u16 = 1;
+pub const ETHTOOL_A_LINKINFO_PORT: u16 = 2;
+pub const ETHTOOL_A_LINKINFO_PHYADDR: u16 = 3;
+pub const ETHTOOL_A_LINKINFO_TP_MDIX: u16 = 4;
+pub const ETHTOOL_A_LINKINFO_TP_MDIX_CTRL: u16 = 5;
+pub const ETHTOOL_A_LINKINFO_TRANSCEIVER: u16 = 6;
+
+pub const ETHTOOL_A_LINKMODES_UNSPEC: u16 = 0;
+pub const ETHTOOL_A_LINKMODES_HEADER: u16 = 1;
+pub const ETHTOOL_A_LINKMODES_AUTONEG: u16 = 2;
+pub const ETHTOOL_A_LINKMODES_OURS: u16 = 3;
+pub const ETHTOOL_A_LINKMODES_PEER: u16 = 4;
+pub const ETHTOOL_A_LINKMODES_SPEED: u16 = 5;
+pub const ETHTOOL_A_LINKMODES_DUPLEX: u16 = 6;
+pub const ETHTOOL_A_LINKMODES_MASTER_SLAVE_CFG: u16 = 7;
+pub const ETHTOOL_A_LINKMODES_MASTER_SLAVE_STATE: u16 = 8;
+pub const ETHTOOL_A_LINKMODES_LANES: u16 = 9;
+pub const ETHTOOL_A_LINKMODES_RATE_MATCHING: u16 = 10;
+
+/// The speed reported when it is not known, e.g. for virtual devices.
+pub const SPEED_UNKNOWN: u32 = u32::MAX;
+
+pub const DUPLEX_HALF: u8 = 0x00;
+pub const DUPLEX_FULL: u8 = 0x01;
+pub const DUPLEX_UNKNOWN: u8 = 0xFF;
+
+/// Attributes of `GetLinkInfo` requests and replies. They correspond to
+/// `ETHTOOL_A_LINKINFO_*` in <linux/ethtool_netlink.h>.
+///
+/// The values are the `PORT_*`, `XCVR_*` and `ETH_TP_MDI*` constants of
+/// <linux/ethtool.h>.
+#[doc(alias("ETHTOOL_A_LINKINFO_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EthtoolLinkInfoAttribute {
+    /// `ETHTOOL_A_LINKINFO_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `ETHTOOL_A_LINKINFO_HEADER`
+    Header(Vec<EthtoolHeaderAttribute>),
+
+    /// `ETHTOOL_A_LINKINFO_PORT` - the physical connector type, e.g.
+    /// `PORT_TP` or `PORT_FIBRE`.
+    Port(u8),
+
+    /// `ETHTOOL_A_LINKINFO_PHYADDR` - the MDIO address of the PHY.
+    PhyAddress(u8),
+
+    /// `ETHTOOL_A_LINKINFO_TP_MDIX` - the MDI(-X) status of twisted pair
+    /// ports.
+    TpMdix(u8),
+
+    /// `ETHTOOL_A_LINKINFO_TP_MDIX_CTRL` - the MDI(-X) control of twisted
+    /// pair ports.
+    TpMdixControl(u8),
+
+    /// `ETHTOOL_A_LINKINFO_TRANSCEIVER`
+    Transceiver(u8),
+
+    Other(u16, Vec<u8>),
+}
+
+/// Attributes of `GetLinkModes` requests and replies. They correspond to
+/// `ETHTOOL_A_LINKMODES_*` in <linux/ethtool_netlink.h>.
+#[doc(alias("ETHTOOL_A_LINKMODES_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EthtoolLinkModesAttribute {
+    /// `ETHTOOL_A_LINKMODES_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `ETHTOOL_A_LINKMODES_HEADER`
+    Header(Vec<EthtoolHeaderAttribute>),
+
+    /// `ETHTOOL_A_LINKMODES_AUTONEG` - whether autonegotiation is enabled.
+    Autonegotiation(bool),
+
+    /// `ETHTOOL_A_LINKMODES_OURS` - the advertised link modes, with the
+    /// supported ones as the mask.
+    Ours(Vec<EthtoolBitsetAttribute>),
+
+    /// `ETHTOOL_A_LINKMODES_PEER` - the link modes advertised by the link
+    /// partner.
+    Peer(Vec<EthtoolBitsetAttribute>),
+
+    /// `ETHTOOL_A_LINKMODES_SPEED` - in Mb/s, or `SPEED_UNKNOWN`.
+    Speed(u32),
+
+    /// `ETHTOOL_A_LINKMODES_DUPLEX` - one of `DUPLEX_*`.
+    Duplex(u8),
+
+    /// `ETHTOOL_A_LINKMODES_MASTER_SLAVE_CFG`
+    MasterSlaveConfiguration(u8),
+
+    /// `ETHTOOL_A_LINKMODES_MASTER_SLAVE_STATE`
+    MasterSlaveState(u8),
+
+    /// `ETHTOOL_A_LINKMODES_LANES`
+    Lanes(u32),
+
+    /// `ETHTOOL_A_LINKMODES_RATE_MATCHING`
+    RateMatching(u8),
+
+    Other(u16, Vec<u8>),
+}
+
+impl EthtoolLinkInfoAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                ETHTOOL_A_LINKINFO_UNSPEC
+            }
+            Self::Header(attrs) => {
+                attrs.iter().for_each(|a| a.serialize_into(buffer));
+                ETHTOOL_A_LINKINFO_HEADER | libc::NLA_F_NESTED as u16
+            }
+            Self::Port(port) => {
+                buffer.push(*port);
+                ETHTOOL_A_LINKINFO_PORT
+            }
+            Self::PhyAddress(address) => {
+                buffer.push(*address);
+                ETHTOOL_A_LINKINFO_PHYADDR
+            }
+            Self::TpMdix(mdix) => {
+                buffer.push(*mdix);
+                ETHTOOL_A_LINKINFO_TP_MDIX
+            }
+            Self::TpMdixControl(mdix) => {
+                buffer.push(*mdix);
+                ETHTOOL_A_LINKINFO_TP_MDIX_CTRL
+            }
+            Self::Transceiver(transceiver) => {
+                buffer.push(*transceiver);
+                ETHTOOL_A_LINKINFO_TRANSCEIVER
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for EthtoolLinkInfoAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            ETHTOOL_A_LINKINFO_UNSPEC => Self::Unspecified(content),
+            ETHTOOL_A_LINKINFO_HEADER => {
+                let attrs = RawAttributeIter::new(content.iter().cloned())
+                    .map(EthtoolHeaderAttribute::from_raw)
+                    .try_collect()?;
+
+                Self::Header(attrs)
+            }
+            ETHTOOL_A_LINKINFO_PORT => Self::Port(*content.first()?),
+            ETHTOOL_A_LINKINFO_PHYADDR => Self::PhyAddress(*content.first()?),
+            ETHTOOL_A_LINKINFO_TP_MDIX => Self::TpMdix(*content.first()?),
+            ETHTOOL_A_LINKINFO_TP_MDIX_CTRL => Self::TpMdixControl(*content.first()?),
+            ETHTOOL_A_LINKINFO_TRANSCEIVER => Self::Transceiver(*content.first()?),
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+impl EthtoolLinkModesAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                ETHTOOL_A_LINKMODES_UNSPEC
+            }
+            Self::Header(attrs) => {
+                attrs.iter().for_each(|a| a.serialize_into(buffer));
+                ETHTOOL_A_LINKMODES_HEADER | libc::NLA_F_NESTED as u16
+            }
+            Self::Autonegotiation(enabled) => {
+                buffer.push(*enabled as u8);
+                ETHTOOL_A_LINKMODES_AUTONEG
+            }
+            Self::Ours(bitset) => {
+                bitset.iter().for_each(|a| a.serialize_into(buffer));
+                ETHTOOL_A_LINKMODES_OURS | libc::NLA_F_NESTED as u16
+            }
+            Self::Peer(bitset) => {
+                bitset.iter().for_each(|a| a.serialize_into(buffer));
+                ETHTOOL_A_LINKMODES_PEER | libc::NLA_F_NESTED as u16
+            }
+            Self::Speed(speed) => {
+                buffer.extend(speed.to_ne_bytes());
+                ETHTOOL_A_LINKMODES_SPEED
+            }
+            Self::Duplex(duplex) => {
+                buffer.push(*duplex);
+                ETHTOOL_A_LINKMODES_DUPLEX
+            }
+            Self::MasterSlaveConfiguration(config) => {
+                buffer.push(*config);
+                ETHTOOL_A_LINKMODES_MASTER_SLAVE_CFG
+            }
+            Self::MasterSlaveState(state) => {
+                buffer.push(*state);
+                ETHTOOL_A_LINKMODES_MASTER_SLAVE_STATE
+            }
+            Self::Lanes(lanes) => {
+                buffer.extend(lanes.to_ne_bytes());
+                ETHTOOL_A_LINKMODES_LANES
+            }
+            Self::RateMatching(rate_matching) => {
+                buffer.push(*rate_matching);
+                ETHTOOL_A_LINKMODES_RATE_MATCHING
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for EthtoolLinkModesAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            ETHTOOL_A_LINKMODES_UNSPEC => Self::Unspecified(content),
+            ETHTOOL_A_LINKMODES_HEADER => {
+                let attrs = RawAttributeIter::new(content.iter().cloned())
+                    .map(EthtoolHeaderAttribute::from_raw)
+                    .try_collect()?;
+
+                Self::Header(attrs)
+            }
+            ETHTOOL_A_LINKMODES_AUTONEG => Self::Autonegotiation(*content.first()? != 0),
+            ETHTOOL_A_LINKMODES_OURS => {
+                let bitset = RawAttributeIter::new(content.iter().cloned())
+                    .map(EthtoolBitsetAttribute::from_raw)
+                    .try_collect()?;
+
+                Self::Ours(bitset)
+            }
+            ETHTOOL_A_LINKMODES_PEER => {
+                let bitset = RawAttributeIter::new(content.iter().cloned())
+                    .map(EthtoolBitsetAttribute::from_raw)
+                    .try_collect()?;
+
+                Self::Peer(bitset)
+            }
+            ETHTOOL_A_LINKMODES_SPEED => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Speed(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_LINKMODES_DUPLEX => Self::Duplex(*content.first()?),
+            ETHTOOL_A_LINKMODES_MASTER_SLAVE_CFG => {
+                Self::MasterSlaveConfiguration(*content.first()?)
+            }
+            ETHTOOL_A_LINKMODES_MASTER_SLAVE_STATE => Self::MasterSlaveState(*content.first()?),
+            ETHTOOL_A_LINKMODES_LANES => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Lanes(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_LINKMODES_RATE_MATCHING => Self::RateMatching(*content.first()?),
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
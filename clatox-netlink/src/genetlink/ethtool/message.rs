@@ -0,0 +1,379 @@
+use crate::genetlink::{GenericHeader, GENL_HDRLEN};
+use crate::netlink::{Attribute, MessageContext, Payload, RawAttributeIter, Type};
+
+use super::{
+    EthtoolChannelsAttribute, EthtoolFeaturesAttribute, EthtoolLinkInfoAttribute,
+    EthtoolLinkModesAttribute, EthtoolRingsAttribute, EthtoolStatsAttribute,
+};
+
+/// The name of the ethtool generic netlink family, to be passed to
+/// `resolve_family_id`.
+pub const ETHTOOL_GENL_NAME: &str = "ethtool";
+pub const ETHTOOL_GENL_VERSION: u8 = 1;
+
+pub const ETHTOOL_MSG_LINKINFO_GET: u8 = 2;
+pub const ETHTOOL_MSG_LINKMODES_GET: u8 = 4;
+pub const ETHTOOL_MSG_FEATURES_GET: u8 = 11;
+pub const ETHTOOL_MSG_FEATURES_SET: u8 = 12;
+pub const ETHTOOL_MSG_RINGS_GET: u8 = 15;
+pub const ETHTOOL_MSG_RINGS_SET: u8 = 16;
+pub const ETHTOOL_MSG_CHANNELS_GET: u8 = 17;
+pub const ETHTOOL_MSG_CHANNELS_SET: u8 = 18;
+pub const ETHTOOL_MSG_STATS_GET: u8 = 32;
+
+pub const ETHTOOL_MSG_LINKINFO_GET_REPLY: u8 = 2;
+pub const ETHTOOL_MSG_LINKINFO_NTF: u8 = 3;
+pub const ETHTOOL_MSG_LINKMODES_GET_REPLY: u8 = 4;
+pub const ETHTOOL_MSG_LINKMODES_NTF: u8 = 5;
+pub const ETHTOOL_MSG_FEATURES_GET_REPLY: u8 = 11;
+pub const ETHTOOL_MSG_FEATURES_SET_REPLY: u8 = 12;
+pub const ETHTOOL_MSG_FEATURES_NTF: u8 = 13;
+pub const ETHTOOL_MSG_RINGS_GET_REPLY: u8 = 16;
+pub const ETHTOOL_MSG_RINGS_NTF: u8 = 17;
+pub const ETHTOOL_MSG_CHANNELS_GET_REPLY: u8 = 18;
+pub const ETHTOOL_MSG_CHANNELS_NTF: u8 = 19;
+pub const ETHTOOL_MSG_STATS_GET_REPLY: u8 = 33;
+
+/// The commands of ethtool requests. They correspond to the user space
+/// `ETHTOOL_MSG_*` in <linux/ethtool_netlink.h>.
+///
+/// The `Get*` commands query one device, or all of them if the request has
+/// the `Dump` flag set and no header.
+#[doc(alias("ETHTOOL_MSG_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum EthtoolCommand {
+    GetLinkInfo = ETHTOOL_MSG_LINKINFO_GET,
+    GetLinkModes = ETHTOOL_MSG_LINKMODES_GET,
+    GetFeatures = ETHTOOL_MSG_FEATURES_GET,
+    SetFeatures = ETHTOOL_MSG_FEATURES_SET,
+    GetRings = ETHTOOL_MSG_RINGS_GET,
+    SetRings = ETHTOOL_MSG_RINGS_SET,
+    GetChannels = ETHTOOL_MSG_CHANNELS_GET,
+    SetChannels = ETHTOOL_MSG_CHANNELS_SET,
+    GetStats = ETHTOOL_MSG_STATS_GET,
+}
+
+impl EthtoolCommand {
+    pub const fn raw_value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub const fn from_raw_value(value: u8) -> Option<Self> {
+        match value {
+            ETHTOOL_MSG_LINKINFO_GET => Some(Self::GetLinkInfo),
+            ETHTOOL_MSG_LINKMODES_GET => Some(Self::GetLinkModes),
+            ETHTOOL_MSG_FEATURES_GET => Some(Self::GetFeatures),
+            ETHTOOL_MSG_FEATURES_SET => Some(Self::SetFeatures),
+            ETHTOOL_MSG_RINGS_GET => Some(Self::GetRings),
+            ETHTOOL_MSG_RINGS_SET => Some(Self::SetRings),
+            ETHTOOL_MSG_CHANNELS_GET => Some(Self::GetChannels),
+            ETHTOOL_MSG_CHANNELS_SET => Some(Self::SetChannels),
+            ETHTOOL_MSG_STATS_GET => Some(Self::GetStats),
+            _ => None,
+        }
+    }
+
+    /// The command of the reply to a request of this command.
+    pub const fn reply_command(&self) -> EthtoolReplyCommand {
+        match self {
+            Self::GetLinkInfo => EthtoolReplyCommand::LinkInfo,
+            Self::GetLinkModes => EthtoolReplyCommand::LinkModes,
+            Self::GetFeatures => EthtoolReplyCommand::Features,
+            Self::SetFeatures => EthtoolReplyCommand::SetFeatures,
+            Self::GetRings | Self::SetRings => EthtoolReplyCommand::Rings,
+            Self::GetChannels | Self::SetChannels => EthtoolReplyCommand::Channels,
+            Self::GetStats => EthtoolReplyCommand::Stats,
+        }
+    }
+}
+
+/// The commands of ethtool replies and notifications. They correspond to the
+/// kernel space `ETHTOOL_MSG_*` in <linux/ethtool_netlink.h>, which are
+/// numbered differently from the requests.
+#[doc(alias("ETHTOOL_MSG_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum EthtoolReplyCommand {
+    LinkInfo = ETHTOOL_MSG_LINKINFO_GET_REPLY,
+    LinkInfoNotification = ETHTOOL_MSG_LINKINFO_NTF,
+    LinkModes = ETHTOOL_MSG_LINKMODES_GET_REPLY,
+    LinkModesNotification = ETHTOOL_MSG_LINKMODES_NTF,
+    Features = ETHTOOL_MSG_FEATURES_GET_REPLY,
+    SetFeatures = ETHTOOL_MSG_FEATURES_SET_REPLY,
+    FeaturesNotification = ETHTOOL_MSG_FEATURES_NTF,
+    Rings = ETHTOOL_MSG_RINGS_GET_REPLY,
+    RingsNotification = ETHTOOL_MSG_RINGS_NTF,
+    Channels = ETHTOOL_MSG_CHANNELS_GET_REPLY,
+    ChannelsNotification = ETHTOOL_MSG_CHANNELS_NTF,
+    Stats = ETHTOOL_MSG_STATS_GET_REPLY,
+}
+
+impl EthtoolReplyCommand {
+    pub const fn raw_value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub const fn from_raw_value(value: u8) -> Option<Self> {
+        match value {
+            ETHTOOL_MSG_LINKINFO_GET_REPLY => Some(Self::LinkInfo),
+            ETHTOOL_MSG_LINKINFO_NTF => Some(Self::LinkInfoNotification),
+            ETHTOOL_MSG_LINKMODES_GET_REPLY => Some(Self::LinkModes),
+            ETHTOOL_MSG_LINKMODES_NTF => Some(Self::LinkModesNotification),
+            ETHTOOL_MSG_FEATURES_GET_REPLY => Some(Self::Features),
+            ETHTOOL_MSG_FEATURES_SET_REPLY => Some(Self::SetFeatures),
+            ETHTOOL_MSG_FEATURES_NTF => Some(Self::FeaturesNotification),
+            ETHTOOL_MSG_RINGS_GET_REPLY => Some(Self::Rings),
+            ETHTOOL_MSG_RINGS_NTF => Some(Self::RingsNotification),
+            ETHTOOL_MSG_CHANNELS_GET_REPLY => Some(Self::Channels),
+            ETHTOOL_MSG_CHANNELS_NTF => Some(Self::ChannelsNotification),
+            ETHTOOL_MSG_STATS_GET_REPLY => Some(Self::Stats),
+            _ => None,
+        }
+    }
+}
+
+/// The attributes of an ethtool message, whose kind depends on the command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EthtoolAttributes {
+    LinkInfo(Vec<EthtoolLinkInfoAttribute>),
+    LinkModes(Vec<EthtoolLinkModesAttribute>),
+    Features(Vec<EthtoolFeaturesAttribute>),
+    Rings(Vec<EthtoolRingsAttribute>),
+    Channels(Vec<EthtoolChannelsAttribute>),
+    Stats(Vec<EthtoolStatsAttribute>),
+}
+
+impl EthtoolAttributes {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        match self {
+            Self::LinkInfo(attrs) => attrs.iter().for_each(|a| a.serialize_into(buffer)),
+            Self::LinkModes(attrs) => attrs.iter().for_each(|a| a.serialize_into(buffer)),
+            Self::Features(attrs) => attrs.iter().for_each(|a| a.serialize_into(buffer)),
+            Self::Rings(attrs) => attrs.iter().for_each(|a| a.serialize_into(buffer)),
+            Self::Channels(attrs) => attrs.iter().for_each(|a| a.serialize_into(buffer)),
+            Self::Stats(attrs) => attrs.iter().for_each(|a| a.serialize_into(buffer)),
+        }
+    }
+
+    fn deserialize(command: EthtoolReplyCommand, bytes: &[u8]) -> Option<Self> {
+        use EthtoolReplyCommand::*;
+
+        let iter = RawAttributeIter::new(bytes.iter().cloned());
+
+        let attrs = match command {
+            LinkInfo | LinkInfoNotification => {
+                Self::LinkInfo(iter.map(EthtoolLinkInfoAttribute::from_raw).try_collect()?)
+            }
+            LinkModes | LinkModesNotification => {
+                Self::LinkModes(iter.map(EthtoolLinkModesAttribute::from_raw).try_collect()?)
+            }
+            Features | SetFeatures | FeaturesNotification => {
+                Self::Features(iter.map(EthtoolFeaturesAttribute::from_raw).try_collect()?)
+            }
+            Rings | RingsNotification => {
+                Self::Rings(iter.map(EthtoolRingsAttribute::from_raw).try_collect()?)
+            }
+            Channels | ChannelsNotification => {
+                Self::Channels(iter.map(EthtoolChannelsAttribute::from_raw).try_collect()?)
+            }
+            Stats => Self::Stats(iter.map(EthtoolStatsAttribute::from_raw).try_collect()?),
+        };
+
+        Some(attrs)
+    }
+}
+
+/// A request of the ethtool generic netlink family.
+///
+/// The attributes must be of the kind of the command, e.g.
+/// `EthtoolAttributes::Features` for `GetFeatures` and `SetFeatures`, and
+/// start with a `Header` identifying the device. The kernel answers with an
+/// `EthtoolReply`.
+///
+/// The `family_id` is the id of the family as returned by
+/// `resolve_family_id`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EthtoolRequest {
+    family_id: u16,
+    command: EthtoolCommand,
+    attributes: EthtoolAttributes,
+}
+
+impl EthtoolRequest {
+    pub const fn new(family_id: u16, command: EthtoolCommand, attributes: EthtoolAttributes) -> Self {
+        Self {
+            family_id,
+            command,
+            attributes,
+        }
+    }
+
+    pub const fn family_id(&self) -> u16 {
+        self.family_id
+    }
+
+    pub const fn command(&self) -> EthtoolCommand {
+        self.command
+    }
+
+    pub const fn attributes(&self) -> &EthtoolAttributes {
+        &self.attributes
+    }
+}
+
+impl Payload for EthtoolRequest {
+    // The real type is only known at runtime, see dynamic_message_type()
+    fn message_type() -> Type {
+        Type::Generic(0)
+    }
+
+    fn dynamic_message_type(&self) -> Type {
+        Type::Generic(self.family_id)
+    }
+
+    // Only messages of the family id resolved on the socket are accepted
+    fn parse_message_type(context: &MessageContext, value: u16) -> Option<Type> {
+        (context.family_id(ETHTOOL_GENL_NAME) == Some(value)).then_some(Type::Generic(value))
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        let mut buffer = Vec::with_capacity(GENL_HDRLEN);
+        GenericHeader::new(self.command.raw_value(), ETHTOOL_GENL_VERSION)
+            .serialize_into(&mut buffer);
+
+        self.attributes.serialize_into(&mut buffer);
+
+        buffer.into_boxed_slice()
+    }
+
+    // The family id is not part of the payload, see deserialize_in()
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        let header = GenericHeader::deserialize(bytes)?;
+        let command = EthtoolCommand::from_raw_value(header.command)?;
+        let attributes =
+            EthtoolAttributes::deserialize(command.reply_command(), &bytes[GENL_HDRLEN..])?;
+
+        Some(Self {
+            family_id: 0,
+            command,
+            attributes,
+        })
+    }
+
+    fn deserialize_in(context: &MessageContext, bytes: &[u8]) -> Option<Self> {
+        Some(Self {
+            family_id: context.family_id(ETHTOOL_GENL_NAME)?,
+            ..Self::deserialize(bytes)?
+        })
+    }
+}
+
+/// A reply or a notification of the ethtool generic netlink family. Replies
+/// are only received on a socket that knows the id of the family, see
+/// `resolve_family_id`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EthtoolReply {
+    command: EthtoolReplyCommand,
+    attributes: EthtoolAttributes,
+}
+
+impl EthtoolReply {
+    pub const fn command(&self) -> EthtoolReplyCommand {
+        self.command
+    }
+
+    pub const fn attributes(&self) -> &EthtoolAttributes {
+        &self.attributes
+    }
+
+    pub fn into_attributes(self) -> EthtoolAttributes {
+        self.attributes
+    }
+}
+
+impl Payload for EthtoolReply {
+    // The real type is only known at runtime
+    fn message_type() -> Type {
+        Type::Generic(0)
+    }
+
+    // Only messages of the family id resolved on the socket are accepted
+    fn parse_message_type(context: &MessageContext, value: u16) -> Option<Type> {
+        (context.family_id(ETHTOOL_GENL_NAME) == Some(value)).then_some(Type::Generic(value))
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        let mut buffer = Vec::with_capacity(GENL_HDRLEN);
+        GenericHeader::new(self.command.raw_value(), ETHTOOL_GENL_VERSION)
+            .serialize_into(&mut buffer);
+
+        self.attributes.serialize_into(&mut buffer);
+
+        buffer.into_boxed_slice()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        let header = GenericHeader::deserialize(bytes)?;
+        let command = EthtoolReplyCommand::from_raw_value(header.command)?;
+        let attributes = EthtoolAttributes::deserialize(command, &bytes[GENL_HDRLEN..])?;
+
+        Some(Self {
+            command,
+            attributes,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genetlink::{EthtoolBit, EthtoolBitsetAttribute, EthtoolHeaderAttribute};
+    use crate::netlink::{Flags, Message, Protocol};
+
+    #[test]
+    fn request_round_trip() {
+        let mut context = MessageContext::new(Protocol::Generic);
+        context.add_family(ETHTOOL_GENL_NAME, 21);
+
+        let request = EthtoolRequest::new(
+            21,
+            EthtoolCommand::SetFeatures,
+            EthtoolAttributes::Features(vec![
+                EthtoolFeaturesAttribute::Header(vec![EthtoolHeaderAttribute::DeviceIndex(6)]),
+                EthtoolFeaturesAttribute::wanted(crate::genetlink::FEATURE_GRO, false),
+                EthtoolFeaturesAttribute::Active(vec![
+                    EthtoolBitsetAttribute::NoMask,
+                    EthtoolBitsetAttribute::Size(64),
+                    EthtoolBitsetAttribute::Bits(vec![EthtoolBit::indexed(14, true)]),
+                ]),
+            ]),
+        );
+        let message = Message::new(Flags::Request, request);
+        let bytes = message.serialize();
+
+        let decoded = Message::<EthtoolRequest>::deserialize_in(&context, &bytes).unwrap();
+        assert_eq!(decoded.payload(), message.payload());
+
+        // Messages of other families are not taken for ethtool ones
+        let other = MessageContext::new(Protocol::Generic);
+        assert_eq!(Message::<EthtoolRequest>::deserialize_in(&other, &bytes), None);
+    }
+
+    #[test]
+    fn compact_bitset_round_trip() {
+        let attrs = vec![
+            EthtoolBitsetAttribute::Size(20),
+            EthtoolBitsetAttribute::Value(vec![0x01, 0x02, 0x00, 0x00]),
+            EthtoolBitsetAttribute::Mask(vec![0x03, 0x02, 0x00, 0x00]),
+        ];
+
+        let mut buffer = Vec::new();
+        attrs.iter().for_each(|attr| attr.serialize_into(&mut buffer));
+
+        let decoded: Option<Vec<_>> = RawAttributeIter::new(buffer.into_iter())
+            .map(EthtoolBitsetAttribute::from_raw)
+            .collect();
+        assert_eq!(decoded, Some(attrs));
+    }
+}
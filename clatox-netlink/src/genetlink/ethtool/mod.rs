@@ -0,0 +1,28 @@
+mod bitset;
+mod features;
+mod header;
+mod link;
+mod message;
+mod rings;
+mod stats;
+
+#[doc(inline)]
+pub use bitset::*;
+
+#[doc(inline)]
+pub use features::*;
+
+#[doc(inline)]
+pub use header::*;
+
+#[doc(inline)]
+pub use link::*;
+
+#[doc(inline)]
+pub use message::*;
+
+#[doc(inline)]
+pub use rings::*;
+
+#[doc(inline)]
+pub use stats::*;
@@ -0,0 +1,392 @@
+use crate::netlink::{Attribute, RawAttribute, RawAttributeIter};
+use crate::utils;
+
+use super::EthtoolHeaderAttribute;
+
+pub const ETHTOOL_A_RINGS_UNSPEC: u16 = 0;
+pub const ETHTOOL_A_RINGS_HEADER: u16 = 1;
+pub const ETHTOOL_A_RINGS_RX_MAX: u16 = 2;
+pub const ETHTOOL_A_RINGS_RX_MINI_MAX: u16 = 3;
+pub const ETHTOOL_A_RINGS_RX_JUMBO_MAX: u16 = 4;
+pub const ETHTOOL_A_RINGS_TX_MAX: u16 = 5;
+pub const ETHTOOL_A_RINGS_RX: u16 = 6;
+pub const ETHTOOL_A_RINGS_RX_MINI: u16 = 7;
+pub const ETHTOOL_A_RINGS_RX_JUMBO: u16 = 8;
+pub const ETHTOOL_A_RINGS_TX: u16 = 9;
+pub const ETHTOOL_A_RINGS_RX_BUF_LEN: u16 = 10;
+pub const ETHTOOL_A_RINGS_TCP_DATA_SPLIT: u16 = 11;
+pub const ETHTOOL_A_RINGS_CQE_SIZE: u16 = 12;
+pub const ETHTOOL_A_RINGS_TX_PUSH: u16 = 13;
+pub const ETHTOOL_A_RINGS_RX_PUSH: u16 = 14;
+pub const ETHTOOL_A_RINGS_TX_PUSH_BUF_LEN: u16 = 15;
+pub const ETHTOOL_A_RINGS_TX_PUSH_BUF_LEN_MAX: u16 = 16;
+
+/// Attributes of `GetRings` and `SetRings` requests and replies, i.e. the
+/// sizes of the descriptor rings as in `ethtool -g`. They correspond to
+/// `ETHTOOL_A_RINGS_*` in <linux/ethtool_netlink.h>.
+///
+/// The `*Max` attributes are read-only.
+#[doc(alias("ETHTOOL_A_RINGS_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EthtoolRingsAttribute {
+    /// `ETHTOOL_A_RINGS_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `ETHTOOL_A_RINGS_HEADER`
+    Header(Vec<EthtoolHeaderAttribute>),
+
+    /// `ETHTOOL_A_RINGS_RX_MAX`
+    RxMax(u32),
+
+    /// `ETHTOOL_A_RINGS_RX_MINI_MAX`
+    RxMiniMax(u32),
+
+    /// `ETHTOOL_A_RINGS_RX_JUMBO_MAX`
+    RxJumboMax(u32),
+
+    /// `ETHTOOL_A_RINGS_TX_MAX`
+    TxMax(u32),
+
+    /// `ETHTOOL_A_RINGS_RX` - the number of RX ring entries.
+    Rx(u32),
+
+    /// `ETHTOOL_A_RINGS_RX_MINI`
+    RxMini(u32),
+
+    /// `ETHTOOL_A_RINGS_RX_JUMBO`
+    RxJumbo(u32),
+
+    /// `ETHTOOL_A_RINGS_TX` - the number of TX ring entries.
+    Tx(u32),
+
+    /// `ETHTOOL_A_RINGS_RX_BUF_LEN`
+    RxBufferLength(u32),
+
+    /// `ETHTOOL_A_RINGS_TCP_DATA_SPLIT`
+    TcpDataSplit(u8),
+
+    /// `ETHTOOL_A_RINGS_CQE_SIZE`
+    CompletionQueueEntrySize(u32),
+
+    /// `ETHTOOL_A_RINGS_TX_PUSH`
+    TxPush(bool),
+
+    /// `ETHTOOL_A_RINGS_RX_PUSH`
+    RxPush(bool),
+
+    /// `ETHTOOL_A_RINGS_TX_PUSH_BUF_LEN`
+    TxPushBufferLength(u32),
+
+    /// `ETHTOOL_A_RINGS_TX_PUSH_BUF_LEN_MAX`
+    TxPushBufferLengthMax(u32),
+
+    Other(u16, Vec<u8>),
+}
+
+impl EthtoolRingsAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                ETHTOOL_A_RINGS_UNSPEC
+            }
+            Self::Header(attrs) => {
+                attrs.iter().for_each(|a| a.serialize_into(buffer));
+                ETHTOOL_A_RINGS_HEADER | libc::NLA_F_NESTED as u16
+            }
+            Self::RxMax(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_RINGS_RX_MAX
+            }
+            Self::RxMiniMax(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_RINGS_RX_MINI_MAX
+            }
+            Self::RxJumboMax(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_RINGS_RX_JUMBO_MAX
+            }
+            Self::TxMax(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_RINGS_TX_MAX
+            }
+            Self::Rx(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_RINGS_RX
+            }
+            Self::RxMini(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_RINGS_RX_MINI
+            }
+            Self::RxJumbo(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_RINGS_RX_JUMBO
+            }
+            Self::Tx(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_RINGS_TX
+            }
+            Self::RxBufferLength(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_RINGS_RX_BUF_LEN
+            }
+            Self::TcpDataSplit(value) => {
+                buffer.push(*value);
+                ETHTOOL_A_RINGS_TCP_DATA_SPLIT
+            }
+            Self::CompletionQueueEntrySize(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_RINGS_CQE_SIZE
+            }
+            Self::TxPush(value) => {
+                buffer.push(*value as u8);
+                ETHTOOL_A_RINGS_TX_PUSH
+            }
+            Self::RxPush(value) => {
+                buffer.push(*value as u8);
+                ETHTOOL_A_RINGS_RX_PUSH
+            }
+            Self::TxPushBufferLength(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_RINGS_TX_PUSH_BUF_LEN
+            }
+            Self::TxPushBufferLengthMax(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_RINGS_TX_PUSH_BUF_LEN_MAX
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for EthtoolRingsAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            ETHTOOL_A_RINGS_UNSPEC => Self::Unspecified(content),
+            ETHTOOL_A_RINGS_HEADER => {
+                let attrs = RawAttributeIter::new(content.iter().cloned())
+                    .map(EthtoolHeaderAttribute::from_raw)
+                    .try_collect()?;
+
+                Self::Header(attrs)
+            }
+            ETHTOOL_A_RINGS_RX_MAX => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::RxMax(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_RINGS_RX_MINI_MAX => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::RxMiniMax(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_RINGS_RX_JUMBO_MAX => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::RxJumboMax(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_RINGS_TX_MAX => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::TxMax(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_RINGS_RX => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Rx(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_RINGS_RX_MINI => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::RxMini(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_RINGS_RX_JUMBO => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::RxJumbo(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_RINGS_TX => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Tx(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_RINGS_RX_BUF_LEN => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::RxBufferLength(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_RINGS_TCP_DATA_SPLIT => Self::TcpDataSplit(*content.first()?),
+            ETHTOOL_A_RINGS_CQE_SIZE => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::CompletionQueueEntrySize(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_RINGS_TX_PUSH => Self::TxPush(*content.first()? != 0),
+            ETHTOOL_A_RINGS_RX_PUSH => Self::RxPush(*content.first()? != 0),
+            ETHTOOL_A_RINGS_TX_PUSH_BUF_LEN => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::TxPushBufferLength(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_RINGS_TX_PUSH_BUF_LEN_MAX => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::TxPushBufferLengthMax(u32::from_ne_bytes(content))
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+pub const ETHTOOL_A_CHANNELS_UNSPEC: u16 = 0;
+pub const ETHTOOL_A_CHANNELS_HEADER: u16 = 1;
+pub const ETHTOOL_A_CHANNELS_RX_MAX: u16 = 2;
+pub const ETHTOOL_A_CHANNELS_TX_MAX: u16 = 3;
+pub const ETHTOOL_A_CHANNELS_OTHER_MAX: u16 = 4;
+pub const ETHTOOL_A_CHANNELS_COMBINED_MAX: u16 = 5;
+pub const ETHTOOL_A_CHANNELS_RX_COUNT: u16 = 6;
+pub const ETHTOOL_A_CHANNELS_TX_COUNT: u16 = 7;
+pub const ETHTOOL_A_CHANNELS_OTHER_COUNT: u16 = 8;
+pub const ETHTOOL_A_CHANNELS_COMBINED_COUNT: u16 = 9;
+
+/// Attributes of `GetChannels` and `SetChannels` requests and replies, i.e.
+/// the number of queues as in `ethtool -l`. They correspond to
+/// `ETHTOOL_A_CHANNELS_*` in <linux/ethtool_netlink.h>.
+///
+/// The `*Max` attributes are read-only.
+#[doc(alias("ETHTOOL_A_CHANNELS_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EthtoolChannelsAttribute {
+    /// `ETHTOOL_A_CHANNELS_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `ETHTOOL_A_CHANNELS_HEADER`
+    Header(Vec<EthtoolHeaderAttribute>),
+
+    /// `ETHTOOL_A_CHANNELS_RX_MAX`
+    RxMax(u32),
+
+    /// `ETHTOOL_A_CHANNELS_TX_MAX`
+    TxMax(u32),
+
+    /// `ETHTOOL_A_CHANNELS_OTHER_MAX`
+    OtherMax(u32),
+
+    /// `ETHTOOL_A_CHANNELS_COMBINED_MAX`
+    CombinedMax(u32),
+
+    /// `ETHTOOL_A_CHANNELS_RX_COUNT` - the number of RX-only channels.
+    RxCount(u32),
+
+    /// `ETHTOOL_A_CHANNELS_TX_COUNT` - the number of TX-only channels.
+    TxCount(u32),
+
+    /// `ETHTOOL_A_CHANNELS_OTHER_COUNT`
+    OtherCount(u32),
+
+    /// `ETHTOOL_A_CHANNELS_COMBINED_COUNT` - the number of channels with both
+    /// an RX and a TX queue.
+    CombinedCount(u32),
+
+    Other(u16, Vec<u8>),
+}
+
+impl EthtoolChannelsAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                ETHTOOL_A_CHANNELS_UNSPEC
+            }
+            Self::Header(attrs) => {
+                attrs.iter().for_each(|a| a.serialize_into(buffer));
+                ETHTOOL_A_CHANNELS_HEADER | libc::NLA_F_NESTED as u16
+            }
+            Self::RxMax(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_CHANNELS_RX_MAX
+            }
+            Self::TxMax(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_CHANNELS_TX_MAX
+            }
+            Self::OtherMax(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_CHANNELS_OTHER_MAX
+            }
+            Self::CombinedMax(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_CHANNELS_COMBINED_MAX
+            }
+            Self::RxCount(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_CHANNELS_RX_COUNT
+            }
+            Self::TxCount(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_CHANNELS_TX_COUNT
+            }
+            Self::OtherCount(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_CHANNELS_OTHER_COUNT
+            }
+            Self::CombinedCount(value) => {
+                buffer.extend(value.to_ne_bytes());
+                ETHTOOL_A_CHANNELS_COMBINED_COUNT
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for EthtoolChannelsAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            ETHTOOL_A_CHANNELS_UNSPEC => Self::Unspecified(content),
+            ETHTOOL_A_CHANNELS_HEADER => {
+                let attrs = RawAttributeIter::new(content.iter().cloned())
+                    .map(EthtoolHeaderAttribute::from_raw)
+                    .try_collect()?;
+
+                Self::Header(attrs)
+            }
+            ETHTOOL_A_CHANNELS_RX_MAX => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::RxMax(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_CHANNELS_TX_MAX => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::TxMax(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_CHANNELS_OTHER_MAX => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::OtherMax(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_CHANNELS_COMBINED_MAX => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::CombinedMax(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_CHANNELS_RX_COUNT => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::RxCount(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_CHANNELS_TX_COUNT => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::TxCount(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_CHANNELS_OTHER_COUNT => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::OtherCount(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_CHANNELS_COMBINED_COUNT => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::CombinedCount(u32::from_ne_bytes(content))
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
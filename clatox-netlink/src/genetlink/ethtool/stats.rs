@@ -0,0 +1,290 @@
+use crate::netlink::{Attribute, RawAttribute, RawAttributeIter};
+use crate::utils;
+
+use super::{EthtoolBitsetAttribute, EthtoolHeaderAttribute};
+
+pub const ETHTOOL_A_STATS_UNSPEC: u16 = 0;
+pub const ETHTOOL_A_STATS_PAD: u16 = 1;
+pub const ETHTOOL_A_STATS_HEADER: u16 = 2;
+pub const ETHTOOL_A_STATS_GROUPS: u16 = 3;
+pub const ETHTOOL_A_STATS_GRP: u16 = 4;
+pub const ETHTOOL_A_STATS_SRC: u16 = 5;
+
+pub const ETHTOOL_A_STATS_GRP_UNSPEC: u16 = 0;
+pub const ETHTOOL_A_STATS_GRP_PAD: u16 = 1;
+pub const ETHTOOL_A_STATS_GRP_ID: u16 = 2;
+pub const ETHTOOL_A_STATS_GRP_SS_ID: u16 = 3;
+pub const ETHTOOL_A_STATS_GRP_STAT: u16 = 4;
+pub const ETHTOOL_A_STATS_GRP_HIST_RX: u16 = 5;
+pub const ETHTOOL_A_STATS_GRP_HIST_TX: u16 = 6;
+
+pub const ETHTOOL_STATS_ETH_PHY: u32 = 0;
+pub const ETHTOOL_STATS_ETH_MAC: u32 = 1;
+pub const ETHTOOL_STATS_ETH_CTRL: u32 = 2;
+pub const ETHTOOL_STATS_RMON: u32 = 3;
+
+/// The standard statistics groups. They correspond to `ETHTOOL_STATS_*` in
+/// <linux/ethtool.h>, and are named `eth-phy`, `eth-mac`, `eth-ctrl` and
+/// `rmon` in the `Groups` bitset of a request.
+#[doc(alias("ETHTOOL_STATS_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EthtoolStatsGroup {
+    /// IEEE 802.3 PHY statistics
+    EthPhy,
+
+    /// IEEE 802.3 MAC statistics
+    EthMac,
+
+    /// IEEE 802.3 MAC control statistics
+    EthCtrl,
+
+    /// RMON (RFC 2819) statistics
+    Rmon,
+
+    /// Any other value
+    Other(u32),
+}
+
+impl EthtoolStatsGroup {
+    pub const fn raw_value(&self) -> u32 {
+        match self {
+            Self::EthPhy => ETHTOOL_STATS_ETH_PHY,
+            Self::EthMac => ETHTOOL_STATS_ETH_MAC,
+            Self::EthCtrl => ETHTOOL_STATS_ETH_CTRL,
+            Self::Rmon => ETHTOOL_STATS_RMON,
+            Self::Other(x) => *x,
+        }
+    }
+
+    pub const fn from_raw_value(value: u32) -> Self {
+        match value {
+            ETHTOOL_STATS_ETH_PHY => Self::EthPhy,
+            ETHTOOL_STATS_ETH_MAC => Self::EthMac,
+            ETHTOOL_STATS_ETH_CTRL => Self::EthCtrl,
+            ETHTOOL_STATS_RMON => Self::Rmon,
+            x => Self::Other(x),
+        }
+    }
+
+    /// The name of the group in the `Groups` bitset. Returns `None` for
+    /// `Other`.
+    pub const fn name(&self) -> Option<&'static str> {
+        match self {
+            Self::EthPhy => Some("eth-phy"),
+            Self::EthMac => Some("eth-mac"),
+            Self::EthCtrl => Some("eth-ctrl"),
+            Self::Rmon => Some("rmon"),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+/// Attributes of `GetStats` requests and replies. They correspond to
+/// `ETHTOOL_A_STATS_*` in <linux/ethtool_netlink.h>.
+///
+/// A request selects the groups with the `Groups` bitset, and the reply
+/// carries one `Group` per group that the driver supports.
+#[doc(alias("ETHTOOL_A_STATS_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EthtoolStatsAttribute {
+    /// `ETHTOOL_A_STATS_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `ETHTOOL_A_STATS_PAD`
+    Pad(Vec<u8>),
+
+    /// `ETHTOOL_A_STATS_HEADER`
+    Header(Vec<EthtoolHeaderAttribute>),
+
+    /// `ETHTOOL_A_STATS_GROUPS`
+    Groups(Vec<EthtoolBitsetAttribute>),
+
+    /// `ETHTOOL_A_STATS_GRP`
+    Group(Vec<EthtoolStatsGroupAttribute>),
+
+    /// `ETHTOOL_A_STATS_SRC` - whether to report the statistics of the MAC,
+    /// of the pluggable MAC merge layer, or their aggregate.
+    Source(u32),
+
+    Other(u16, Vec<u8>),
+}
+
+/// Attributes nested in `ETHTOOL_A_STATS_GRP`. They correspond to
+/// `ETHTOOL_A_STATS_GRP_*` in <linux/ethtool_netlink.h>.
+#[doc(alias("ETHTOOL_A_STATS_GRP_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EthtoolStatsGroupAttribute {
+    /// `ETHTOOL_A_STATS_GRP_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `ETHTOOL_A_STATS_GRP_PAD`
+    Pad(Vec<u8>),
+
+    /// `ETHTOOL_A_STATS_GRP_ID`
+    Id(EthtoolStatsGroup),
+
+    /// `ETHTOOL_A_STATS_GRP_SS_ID` - the id of the string set naming the
+    /// statistics of the group.
+    StringSetId(u32),
+
+    /// `ETHTOOL_A_STATS_GRP_STAT` - a statistic, given by its index in the
+    /// string set, and its value.
+    Stat(u16, u64),
+
+    /// `ETHTOOL_A_STATS_GRP_HIST_RX`
+    RxHistogram(Vec<u8>),
+
+    /// `ETHTOOL_A_STATS_GRP_HIST_TX`
+    TxHistogram(Vec<u8>),
+
+    Other(u16, Vec<u8>),
+}
+
+impl EthtoolStatsAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                ETHTOOL_A_STATS_UNSPEC
+            }
+            Self::Pad(content) => {
+                buffer.extend(content.iter());
+                ETHTOOL_A_STATS_PAD
+            }
+            Self::Header(attrs) => {
+                attrs.iter().for_each(|a| a.serialize_into(buffer));
+                ETHTOOL_A_STATS_HEADER | libc::NLA_F_NESTED as u16
+            }
+            Self::Groups(bitset) => {
+                bitset.iter().for_each(|a| a.serialize_into(buffer));
+                ETHTOOL_A_STATS_GROUPS | libc::NLA_F_NESTED as u16
+            }
+            Self::Group(attrs) => {
+                attrs.iter().for_each(|a| a.serialize_into(buffer));
+                ETHTOOL_A_STATS_GRP | libc::NLA_F_NESTED as u16
+            }
+            Self::Source(source) => {
+                buffer.extend(source.to_ne_bytes());
+                ETHTOOL_A_STATS_SRC
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for EthtoolStatsAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            ETHTOOL_A_STATS_UNSPEC => Self::Unspecified(content),
+            ETHTOOL_A_STATS_PAD => Self::Pad(content),
+            ETHTOOL_A_STATS_HEADER => {
+                let attrs = RawAttributeIter::new(content.iter().cloned())
+                    .map(EthtoolHeaderAttribute::from_raw)
+                    .try_collect()?;
+
+                Self::Header(attrs)
+            }
+            ETHTOOL_A_STATS_GROUPS => {
+                let bitset = RawAttributeIter::new(content.iter().cloned())
+                    .map(EthtoolBitsetAttribute::from_raw)
+                    .try_collect()?;
+
+                Self::Groups(bitset)
+            }
+            ETHTOOL_A_STATS_GRP => {
+                let attrs = RawAttributeIter::new(content.iter().cloned())
+                    .map(EthtoolStatsGroupAttribute::from_raw)
+                    .try_collect()?;
+
+                Self::Group(attrs)
+            }
+            ETHTOOL_A_STATS_SRC => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Source(u32::from_ne_bytes(content))
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+impl EthtoolStatsGroupAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                ETHTOOL_A_STATS_GRP_UNSPEC
+            }
+            Self::Pad(content) => {
+                buffer.extend(content.iter());
+                ETHTOOL_A_STATS_GRP_PAD
+            }
+            Self::Id(id) => {
+                buffer.extend(id.raw_value().to_ne_bytes());
+                ETHTOOL_A_STATS_GRP_ID
+            }
+            Self::StringSetId(id) => {
+                buffer.extend(id.to_ne_bytes());
+                ETHTOOL_A_STATS_GRP_SS_ID
+            }
+            Self::Stat(index, value) => {
+                utils::serialize_attribute_into(buffer, |buffer| {
+                    buffer.extend(value.to_ne_bytes());
+                    *index
+                });
+                ETHTOOL_A_STATS_GRP_STAT | libc::NLA_F_NESTED as u16
+            }
+            Self::RxHistogram(content) => {
+                buffer.extend(content.iter());
+                ETHTOOL_A_STATS_GRP_HIST_RX | libc::NLA_F_NESTED as u16
+            }
+            Self::TxHistogram(content) => {
+                buffer.extend(content.iter());
+                ETHTOOL_A_STATS_GRP_HIST_TX | libc::NLA_F_NESTED as u16
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for EthtoolStatsGroupAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            ETHTOOL_A_STATS_GRP_UNSPEC => Self::Unspecified(content),
+            ETHTOOL_A_STATS_GRP_PAD => Self::Pad(content),
+            ETHTOOL_A_STATS_GRP_ID => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Id(EthtoolStatsGroup::from_raw_value(u32::from_ne_bytes(content)))
+            }
+            ETHTOOL_A_STATS_GRP_SS_ID => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::StringSetId(u32::from_ne_bytes(content))
+            }
+            ETHTOOL_A_STATS_GRP_STAT => {
+                let stat = RawAttributeIter::new(content.iter().cloned()).next()?;
+                let index = stat.attr_type();
+                let value = <[u8; 8]>::try_from(stat.into_payload()).ok()?;
+
+                Self::Stat(index, u64::from_ne_bytes(value))
+            }
+            ETHTOOL_A_STATS_GRP_HIST_RX => Self::RxHistogram(content),
+            ETHTOOL_A_STATS_GRP_HIST_TX => Self::TxHistogram(content),
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
@@ -0,0 +1,35 @@
+/// The length of `GenericHeader` on the wire.
+pub const GENL_HDRLEN: usize = 4;
+
+/// The header that follows the Netlink header in every generic netlink
+/// message. It corresponds to the `genlmsghdr` struct in libc.
+#[doc(alias("genlmsghdr"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenericHeader {
+    /// The family-specific command of the message.
+    pub command: u8,
+
+    /// The version of the family's interface.
+    pub version: u8,
+}
+
+impl GenericHeader {
+    pub const fn new(command: u8, version: u8) -> Self {
+        Self { command, version }
+    }
+
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        buffer.push(self.command);
+        buffer.push(self.version);
+        buffer.extend(0u16.to_ne_bytes()); // reserved
+    }
+
+    pub fn deserialize(bytes: &[u8]) -> Option<Self> {
+        let bytes = bytes.get(..GENL_HDRLEN)?;
+
+        Some(Self {
+            command: bytes[0],
+            version: bytes[1],
+        })
+    }
+}
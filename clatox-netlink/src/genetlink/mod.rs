@@ -0,0 +1,16 @@
+mod controller;
+mod ethtool;
+mod header;
+mod wireguard;
+
+#[doc(inline)]
+pub use controller::*;
+
+#[doc(inline)]
+pub use ethtool::*;
+
+#[doc(inline)]
+pub use header::*;
+
+#[doc(inline)]
+pub use wireguard::*;
@@ -0,0 +1,602 @@
+use libc::{AF_INET, AF_INET6};
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::netlink::{Attribute, RawAttribute, RawAttributeIter};
+use crate::utils;
+
+use super::{WireguardKey, WG_KEY_LEN};
+
+use bitflags::bitflags;
+
+pub const WGDEVICE_A_UNSPEC: u16 = 0;
+pub const WGDEVICE_A_IFINDEX: u16 = 1;
+pub const WGDEVICE_A_IFNAME: u16 = 2;
+pub const WGDEVICE_A_PRIVATE_KEY: u16 = 3;
+pub const WGDEVICE_A_PUBLIC_KEY: u16 = 4;
+pub const WGDEVICE_A_FLAGS: u16 = 5;
+pub const WGDEVICE_A_LISTEN_PORT: u16 = 6;
+pub const WGDEVICE_A_FWMARK: u16 = 7;
+pub const WGDEVICE_A_PEERS: u16 = 8;
+
+pub const WGPEER_A_UNSPEC: u16 = 0;
+pub const WGPEER_A_PUBLIC_KEY: u16 = 1;
+pub const WGPEER_A_PRESHARED_KEY: u16 = 2;
+pub const WGPEER_A_FLAGS: u16 = 3;
+pub const WGPEER_A_ENDPOINT: u16 = 4;
+pub const WGPEER_A_PERSISTENT_KEEPALIVE_INTERVAL: u16 = 5;
+pub const WGPEER_A_LAST_HANDSHAKE_TIME: u16 = 6;
+pub const WGPEER_A_RX_BYTES: u16 = 7;
+pub const WGPEER_A_TX_BYTES: u16 = 8;
+pub const WGPEER_A_ALLOWEDIPS: u16 = 9;
+pub const WGPEER_A_PROTOCOL_VERSION: u16 = 10;
+
+pub const WGALLOWEDIP_A_UNSPEC: u16 = 0;
+pub const WGALLOWEDIP_A_FAMILY: u16 = 1;
+pub const WGALLOWEDIP_A_IPADDR: u16 = 2;
+pub const WGALLOWEDIP_A_CIDR_MASK: u16 = 3;
+
+pub const WGDEVICE_F_REPLACE_PEERS: u32 = 1 << 0;
+
+pub const WGPEER_F_REMOVE_ME: u32 = 1 << 0;
+pub const WGPEER_F_REPLACE_ALLOWEDIPS: u32 = 1 << 1;
+pub const WGPEER_F_UPDATE_ONLY: u32 = 1 << 2;
+
+bitflags! {
+    /// A bitfield of flags of a `SetDevice` request.
+    ///
+    /// It corresponds to `WGDEVICE_F_*` in <linux/wireguard.h>.
+    #[doc(alias("WGDEVICE_F_"))]
+    #[derive(Copy, Debug, Clone, PartialEq, Eq)]
+    pub struct WireguardDeviceFlags: u32 {
+        /// Remove all peers that are not part of the request
+        const ReplacePeers = WGDEVICE_F_REPLACE_PEERS;
+    }
+}
+
+bitflags! {
+    /// A bitfield of flags of a peer in a `SetDevice` request.
+    ///
+    /// It corresponds to `WGPEER_F_*` in <linux/wireguard.h>.
+    #[doc(alias("WGPEER_F_"))]
+    #[derive(Copy, Debug, Clone, PartialEq, Eq)]
+    pub struct WireguardPeerFlags: u32 {
+        /// Remove the peer
+        const RemoveMe = WGPEER_F_REMOVE_ME;
+        /// Remove the allowed IPs that are not part of the request
+        const ReplaceAllowedIps = WGPEER_F_REPLACE_ALLOWEDIPS;
+        /// Only update the peer if it exists, instead of adding it
+        const UpdateOnly = WGPEER_F_UPDATE_ONLY;
+    }
+}
+
+/// Attributes of a WireGuard device. They correspond to `WGDEVICE_A_*` in
+/// <linux/wireguard.h>.
+///
+/// A `GetDevice` request carries either `InterfaceIndex` or `InterfaceName`;
+/// a `SetDevice` request carries one of them too, along with the settings to
+/// change.
+#[doc(alias("WGDEVICE_A_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WireguardDeviceAttribute {
+    /// `WGDEVICE_A_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `WGDEVICE_A_IFINDEX`
+    InterfaceIndex(u32),
+
+    /// `WGDEVICE_A_IFNAME`
+    InterfaceName(String),
+
+    /// `WGDEVICE_A_PRIVATE_KEY` - only reported to privileged users. Set an
+    /// all-zero key to remove it.
+    PrivateKey(WireguardKey),
+
+    /// `WGDEVICE_A_PUBLIC_KEY` - derived from the private key, read-only.
+    PublicKey(WireguardKey),
+
+    /// `WGDEVICE_A_FLAGS`
+    Flags(WireguardDeviceFlags),
+
+    /// `WGDEVICE_A_LISTEN_PORT` - the UDP port to listen on, or 0 to pick a
+    /// random one.
+    ListenPort(u16),
+
+    /// `WGDEVICE_A_FWMARK` - the firewall mark of the outgoing packets, or 0
+    /// to disable it.
+    FirewallMark(u32),
+
+    /// `WGDEVICE_A_PEERS` - the peers of the device, each given as a list of
+    /// attributes. Large peer lists are split over multiple messages of a
+    /// dump.
+    Peers(Vec<Vec<WireguardPeerAttribute>>),
+
+    Other(u16, Vec<u8>),
+}
+
+/// Attributes of a WireGuard peer, nested in `WGDEVICE_A_PEERS`. They
+/// correspond to `WGPEER_A_*` in <linux/wireguard.h>.
+#[doc(alias("WGPEER_A_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WireguardPeerAttribute {
+    /// `WGPEER_A_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `WGPEER_A_PUBLIC_KEY` - identifies the peer, required in requests.
+    PublicKey(WireguardKey),
+
+    /// `WGPEER_A_PRESHARED_KEY` - set an all-zero key to remove it.
+    PresharedKey(WireguardKey),
+
+    /// `WGPEER_A_FLAGS`
+    Flags(WireguardPeerFlags),
+
+    /// `WGPEER_A_ENDPOINT`
+    Endpoint(SocketAddr),
+
+    /// `WGPEER_A_PERSISTENT_KEEPALIVE_INTERVAL` - in seconds, or 0 to disable.
+    PersistentKeepaliveInterval(u16),
+
+    /// `WGPEER_A_LAST_HANDSHAKE_TIME` - the time of the last completed
+    /// handshake, or `UNIX_EPOCH` if there was none. Read-only.
+    LastHandshakeTime(SystemTime),
+
+    /// `WGPEER_A_RX_BYTES` - read-only.
+    RxBytes(u64),
+
+    /// `WGPEER_A_TX_BYTES` - read-only.
+    TxBytes(u64),
+
+    /// `WGPEER_A_ALLOWEDIPS` - the networks routed to the peer, and accepted
+    /// as source addresses from it.
+    AllowedIps(Vec<WireguardAllowedIp>),
+
+    /// `WGPEER_A_PROTOCOL_VERSION` - must be 1 if given.
+    ProtocolVersion(u32),
+
+    Other(u16, Vec<u8>),
+}
+
+/// An allowed IP network of a WireGuard peer, nested in
+/// `WGPEER_A_ALLOWEDIPS` as `WGALLOWEDIP_A_*` attributes.
+#[doc(alias("WGALLOWEDIP_A_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WireguardAllowedIp {
+    pub address: IpAddr,
+    pub prefix_len: u8,
+}
+
+impl WireguardAllowedIp {
+    pub const fn new(address: IpAddr, prefix_len: u8) -> Self {
+        Self {
+            address,
+            prefix_len,
+        }
+    }
+
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| {
+            let (family, address) = match self.address {
+                IpAddr::V4(address) => (AF_INET, address.octets().to_vec()),
+                IpAddr::V6(address) => (AF_INET6, address.octets().to_vec()),
+            };
+
+            utils::serialize_attribute_into(buffer, |buffer| {
+                buffer.extend((family as u16).to_ne_bytes());
+                WGALLOWEDIP_A_FAMILY
+            });
+            utils::serialize_attribute_into(buffer, |buffer| {
+                buffer.extend(address.iter());
+                WGALLOWEDIP_A_IPADDR
+            });
+            utils::serialize_attribute_into(buffer, |buffer| {
+                buffer.push(self.prefix_len);
+                WGALLOWEDIP_A_CIDR_MASK
+            });
+
+            // Each allowed IP is nested in an attribute of type 0
+            libc::NLA_F_NESTED as u16
+        });
+    }
+
+    /// Deserializes the attributes of one allowed IP. Returns `None` if the
+    /// address or the prefix length is missing.
+    pub fn deserialize(bytes: &[u8]) -> Option<Self> {
+        let mut address = None;
+        let mut prefix_len = None;
+
+        for attr in RawAttributeIter::new(bytes.iter().cloned()) {
+            match attr.attr_type() {
+                WGALLOWEDIP_A_IPADDR => {
+                    address = match attr.payload().len() {
+                        4 => Some(IpAddr::from(<[u8; 4]>::try_from(attr.payload()).ok()?)),
+                        16 => Some(IpAddr::from(<[u8; 16]>::try_from(attr.payload()).ok()?)),
+                        _ => return None,
+                    }
+                }
+                WGALLOWEDIP_A_CIDR_MASK => prefix_len = Some(*attr.payload().first()?),
+                _ => {}
+            }
+        }
+
+        Some(Self::new(address?, prefix_len?))
+    }
+}
+
+fn serialize_endpoint(endpoint: &SocketAddr, buffer: &mut Vec<u8>) {
+    // struct sockaddr_in or struct sockaddr_in6
+    match endpoint {
+        SocketAddr::V4(endpoint) => {
+            buffer.extend((AF_INET as u16).to_ne_bytes());
+            buffer.extend(endpoint.port().to_be_bytes());
+            buffer.extend(endpoint.ip().octets());
+            buffer.extend([0u8; 8]); // sin_zero
+        }
+        SocketAddr::V6(endpoint) => {
+            buffer.extend((AF_INET6 as u16).to_ne_bytes());
+            buffer.extend(endpoint.port().to_be_bytes());
+            buffer.extend(endpoint.flowinfo().to_be_bytes());
+            buffer.extend(endpoint.ip().octets());
+            buffer.extend(endpoint.scope_id().to_ne_bytes());
+        }
+    }
+}
+
+fn deserialize_endpoint(bytes: &[u8]) -> Option<SocketAddr> {
+    let family = u16::from_ne_bytes([*bytes.first()?, *bytes.get(1)?]);
+    let port = u16::from_be_bytes([*bytes.get(2)?, *bytes.get(3)?]);
+
+    let endpoint = match family as i32 {
+        AF_INET => {
+            let address = <[u8; 4]>::try_from(bytes.get(4..8)?).ok()?;
+            SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::from(address), port))
+        }
+        AF_INET6 => {
+            let flowinfo = u32::from_be_bytes(bytes.get(4..8)?.try_into().ok()?);
+            let address = <[u8; 16]>::try_from(bytes.get(8..24)?).ok()?;
+            let scope_id = u32::from_ne_bytes(bytes.get(24..28)?.try_into().ok()?);
+            SocketAddr::V6(SocketAddrV6::new(Ipv6Addr::from(address), port, flowinfo, scope_id))
+        }
+        _ => return None,
+    };
+
+    Some(endpoint)
+}
+
+/// Interprets a `struct __kernel_timespec`. Returns `None` for times which are
+/// not representable, e.g. before the epoch or with too many nanoseconds.
+fn deserialize_timespec(bytes: &[u8; 16]) -> Option<SystemTime> {
+    let secs = i64::from_ne_bytes(bytes[..8].try_into().unwrap());
+    let nanos = i64::from_ne_bytes(bytes[8..].try_into().unwrap());
+
+    let secs = u64::try_from(secs).ok()?;
+    let nanos = u32::try_from(nanos).ok().filter(|x| *x < 1_000_000_000)?;
+    UNIX_EPOCH.checked_add(Duration::new(secs, nanos))
+}
+
+impl WireguardDeviceAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                WGDEVICE_A_UNSPEC
+            }
+            Self::InterfaceIndex(index) => {
+                buffer.extend(index.to_ne_bytes());
+                WGDEVICE_A_IFINDEX
+            }
+            Self::InterfaceName(name) => {
+                buffer.extend(name.bytes());
+                buffer.push(0u8); // zero-terminated string
+                WGDEVICE_A_IFNAME
+            }
+            Self::PrivateKey(key) => {
+                buffer.extend(key.as_bytes());
+                WGDEVICE_A_PRIVATE_KEY
+            }
+            Self::PublicKey(key) => {
+                buffer.extend(key.as_bytes());
+                WGDEVICE_A_PUBLIC_KEY
+            }
+            Self::Flags(flags) => {
+                buffer.extend(flags.bits().to_ne_bytes());
+                WGDEVICE_A_FLAGS
+            }
+            Self::ListenPort(port) => {
+                buffer.extend(port.to_ne_bytes());
+                WGDEVICE_A_LISTEN_PORT
+            }
+            Self::FirewallMark(mark) => {
+                buffer.extend(mark.to_ne_bytes());
+                WGDEVICE_A_FWMARK
+            }
+            Self::Peers(peers) => {
+                for peer in peers {
+                    // Each peer is nested in an attribute of type 0
+                    utils::serialize_attribute_into(buffer, |buffer| {
+                        peer.iter().for_each(|a| a.serialize_into(buffer));
+                        libc::NLA_F_NESTED as u16
+                    });
+                }
+                WGDEVICE_A_PEERS | libc::NLA_F_NESTED as u16
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for WireguardDeviceAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            WGDEVICE_A_UNSPEC => Self::Unspecified(content),
+            WGDEVICE_A_IFINDEX => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::InterfaceIndex(u32::from_ne_bytes(content))
+            }
+            WGDEVICE_A_IFNAME => {
+                let mut content = String::from_utf8(content).ok()?;
+                if content.ends_with('\0') {
+                    content.pop();
+                }
+                Self::InterfaceName(content)
+            }
+            WGDEVICE_A_PRIVATE_KEY => {
+                let content = <[u8; WG_KEY_LEN]>::try_from(content).ok()?;
+                Self::PrivateKey(WireguardKey(content))
+            }
+            WGDEVICE_A_PUBLIC_KEY => {
+                let content = <[u8; WG_KEY_LEN]>::try_from(content).ok()?;
+                Self::PublicKey(WireguardKey(content))
+            }
+            WGDEVICE_A_FLAGS => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Flags(WireguardDeviceFlags::from_bits_retain(u32::from_ne_bytes(content)))
+            }
+            WGDEVICE_A_LISTEN_PORT => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::ListenPort(u16::from_ne_bytes(content))
+            }
+            WGDEVICE_A_FWMARK => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::FirewallMark(u32::from_ne_bytes(content))
+            }
+            WGDEVICE_A_PEERS => {
+                let peers = RawAttributeIter::new(content.iter().cloned())
+                    .map(|peer| {
+                        RawAttributeIter::new(peer.into_payload().into_iter())
+                            .map(WireguardPeerAttribute::from_raw)
+                            .try_collect()
+                    })
+                    .try_collect()?;
+
+                Self::Peers(peers)
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+impl WireguardPeerAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                WGPEER_A_UNSPEC
+            }
+            Self::PublicKey(key) => {
+                buffer.extend(key.as_bytes());
+                WGPEER_A_PUBLIC_KEY
+            }
+            Self::PresharedKey(key) => {
+                buffer.extend(key.as_bytes());
+                WGPEER_A_PRESHARED_KEY
+            }
+            Self::Flags(flags) => {
+                buffer.extend(flags.bits().to_ne_bytes());
+                WGPEER_A_FLAGS
+            }
+            Self::Endpoint(endpoint) => {
+                serialize_endpoint(endpoint, buffer);
+                WGPEER_A_ENDPOINT
+            }
+            Self::PersistentKeepaliveInterval(interval) => {
+                buffer.extend(interval.to_ne_bytes());
+                WGPEER_A_PERSISTENT_KEEPALIVE_INTERVAL
+            }
+            Self::LastHandshakeTime(time) => {
+                // struct __kernel_timespec
+                let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+                buffer.extend((since_epoch.as_secs() as i64).to_ne_bytes());
+                buffer.extend((since_epoch.subsec_nanos() as i64).to_ne_bytes());
+                WGPEER_A_LAST_HANDSHAKE_TIME
+            }
+            Self::RxBytes(bytes) => {
+                buffer.extend(bytes.to_ne_bytes());
+                WGPEER_A_RX_BYTES
+            }
+            Self::TxBytes(bytes) => {
+                buffer.extend(bytes.to_ne_bytes());
+                WGPEER_A_TX_BYTES
+            }
+            Self::AllowedIps(ips) => {
+                ips.iter().for_each(|ip| ip.serialize_into(buffer));
+                WGPEER_A_ALLOWEDIPS | libc::NLA_F_NESTED as u16
+            }
+            Self::ProtocolVersion(version) => {
+                buffer.extend(version.to_ne_bytes());
+                WGPEER_A_PROTOCOL_VERSION
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for WireguardPeerAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            WGPEER_A_UNSPEC => Self::Unspecified(content),
+            WGPEER_A_PUBLIC_KEY => {
+                let content = <[u8; WG_KEY_LEN]>::try_from(content).ok()?;
+                Self::PublicKey(WireguardKey(content))
+            }
+            WGPEER_A_PRESHARED_KEY => {
+                let content = <[u8; WG_KEY_LEN]>::try_from(content).ok()?;
+                Self::PresharedKey(WireguardKey(content))
+            }
+            WGPEER_A_FLAGS => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Flags(WireguardPeerFlags::from_bits_retain(u32::from_ne_bytes(content)))
+            }
+            WGPEER_A_ENDPOINT => Self::Endpoint(deserialize_endpoint(&content)?),
+            WGPEER_A_PERSISTENT_KEEPALIVE_INTERVAL => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::PersistentKeepaliveInterval(u16::from_ne_bytes(content))
+            }
+            WGPEER_A_LAST_HANDSHAKE_TIME => {
+                let content = <[u8; 16]>::try_from(content).ok()?;
+                match deserialize_timespec(&content) {
+                    Some(time) => Self::LastHandshakeTime(time),
+                    None => Self::Other(attr_type, content.to_vec()),
+                }
+            }
+            WGPEER_A_RX_BYTES => {
+                let content = <[u8; 8]>::try_from(content).ok()?;
+                Self::RxBytes(u64::from_ne_bytes(content))
+            }
+            WGPEER_A_TX_BYTES => {
+                let content = <[u8; 8]>::try_from(content).ok()?;
+                Self::TxBytes(u64::from_ne_bytes(content))
+            }
+            WGPEER_A_ALLOWEDIPS => {
+                let ips = RawAttributeIter::new(content.iter().cloned())
+                    .map(|ip| WireguardAllowedIp::deserialize(ip.payload()))
+                    .try_collect()?;
+
+                Self::AllowedIps(ips)
+            }
+            WGPEER_A_PROTOCOL_VERSION => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::ProtocolVersion(u32::from_ne_bytes(content))
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem::size_of;
+
+    fn as_bytes<T>(value: &T) -> &[u8] {
+        // SAFETY: The socket addresses are plain-old-data structs, which are
+        //         fully initialized by the tests.
+        unsafe { std::slice::from_raw_parts(value as *const T as *const u8, size_of::<T>()) }
+    }
+
+    #[test]
+    fn endpoint_layout() {
+        let endpoint: SocketAddr = "192.0.2.1:51820".parse().unwrap();
+        let mut sockaddr: libc::sockaddr_in = unsafe { std::mem::zeroed() };
+        sockaddr.sin_family = AF_INET as libc::sa_family_t;
+        sockaddr.sin_port = 51820u16.to_be();
+        sockaddr.sin_addr.s_addr = u32::from_ne_bytes([192, 0, 2, 1]);
+
+        let mut buffer = Vec::new();
+        serialize_endpoint(&endpoint, &mut buffer);
+        assert_eq!(buffer, as_bytes(&sockaddr));
+        assert_eq!(deserialize_endpoint(&buffer), Some(endpoint));
+
+        let address: Ipv6Addr = "2001:db8::1".parse().unwrap();
+        let endpoint = SocketAddr::V6(SocketAddrV6::new(address, 51820, 0x12345, 3));
+        let mut sockaddr: libc::sockaddr_in6 = unsafe { std::mem::zeroed() };
+        sockaddr.sin6_family = AF_INET6 as libc::sa_family_t;
+        sockaddr.sin6_port = 51820u16.to_be();
+        sockaddr.sin6_flowinfo = 0x12345u32.to_be();
+        sockaddr.sin6_addr.s6_addr = address.octets();
+        sockaddr.sin6_scope_id = 3;
+
+        let mut buffer = Vec::new();
+        serialize_endpoint(&endpoint, &mut buffer);
+        assert_eq!(buffer, as_bytes(&sockaddr));
+        assert_eq!(deserialize_endpoint(&buffer), Some(endpoint));
+
+        assert_eq!(deserialize_endpoint(&buffer[..20]), None);
+    }
+
+    #[test]
+    fn allowed_ips() {
+        // An allowed IP as sent by the kernel, with the attributes in another order
+        let mut content = Vec::new();
+        utils::serialize_attribute_into(&mut content, |buffer| {
+            buffer.push(64);
+            WGALLOWEDIP_A_CIDR_MASK
+        });
+        utils::serialize_attribute_into(&mut content, |buffer| {
+            buffer.extend("fd00::".parse::<Ipv6Addr>().unwrap().octets());
+            WGALLOWEDIP_A_IPADDR
+        });
+        utils::serialize_attribute_into(&mut content, |buffer| {
+            buffer.extend((AF_INET6 as u16).to_ne_bytes());
+            WGALLOWEDIP_A_FAMILY
+        });
+        let expected = WireguardAllowedIp::new("fd00::".parse().unwrap(), 64);
+        assert_eq!(WireguardAllowedIp::deserialize(&content), Some(expected));
+
+        let ip = WireguardAllowedIp::new("10.0.0.0".parse().unwrap(), 8);
+        let mut buffer = Vec::new();
+        ip.serialize_into(&mut buffer);
+        let nested = RawAttributeIter::new(buffer.into_iter()).next().unwrap();
+        let attrs: Vec<_> = RawAttributeIter::new(nested.payload().iter().cloned()).collect();
+        assert_eq!(attrs[0].payload(), &(AF_INET as u16).to_ne_bytes());
+        assert_eq!(attrs[1].payload(), &[10, 0, 0, 0]);
+        assert_eq!(attrs[2].payload(), &[8]);
+
+        // The prefix length is mandatory
+        assert_eq!(WireguardAllowedIp::deserialize(&content[8..]), None);
+    }
+
+    #[test]
+    fn last_handshake_time() {
+        // struct __kernel_timespec
+        let mut content = Vec::new();
+        content.extend(1000i64.to_ne_bytes());
+        content.extend(500i64.to_ne_bytes());
+
+        let raw = RawAttribute::new(WGPEER_A_LAST_HANDSHAKE_TIME, content.clone());
+        let time = UNIX_EPOCH + Duration::new(1000, 500);
+        assert_eq!(WireguardPeerAttribute::from_raw(raw), Some(WireguardPeerAttribute::LastHandshakeTime(time)));
+
+        let mut buffer = Vec::new();
+        WireguardPeerAttribute::LastHandshakeTime(time).serialize_into(&mut buffer);
+        assert_eq!(&buffer[4..], &content[..]);
+
+        // Times which are not representable are kept as they are
+        for (secs, nanos) in [(1000i64, 1_000_000_000i64), (1000, -1), (-1, 0)] {
+            let mut content = Vec::new();
+            content.extend(secs.to_ne_bytes());
+            content.extend(nanos.to_ne_bytes());
+
+            let raw = RawAttribute::new(WGPEER_A_LAST_HANDSHAKE_TIME, content.clone());
+            let attr = WireguardPeerAttribute::Other(WGPEER_A_LAST_HANDSHAKE_TIME, content);
+            assert_eq!(WireguardPeerAttribute::from_raw(raw), Some(attr));
+        }
+    }
+}
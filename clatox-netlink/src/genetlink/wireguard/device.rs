@@ -0,0 +1,214 @@
+use std::io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult};
+
+use crate::genetlink::resolve_family_id;
+use crate::netlink::{Flags, Message, ReceivedMessage, Socket};
+
+use super::{
+    WireguardCommand, WireguardDeviceAttribute, WireguardKey, WireguardMessage,
+    WireguardPeerAttribute, WG_GENL_NAME,
+};
+
+/// The interface of a WireGuard device, given either by its index or by its
+/// name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WireguardInterface {
+    Index(u32),
+    Name(String),
+}
+
+impl WireguardInterface {
+    fn attribute(&self) -> WireguardDeviceAttribute {
+        match self {
+            Self::Index(index) => WireguardDeviceAttribute::InterfaceIndex(*index),
+            Self::Name(name) => WireguardDeviceAttribute::InterfaceName(name.clone()),
+        }
+    }
+}
+
+/// Gets the configuration and the peers of a WireGuard device, like `wg show`
+/// does. The id of the WireGuard family is resolved on the socket if it is not
+/// known yet.
+///
+/// The kernel splits large peer lists over multiple messages. They are merged
+/// here, so that the attributes hold every peer exactly once.
+///
+/// Fails with `ENODEV` if there is no such interface, and with `NotFound` if
+/// the WireGuard module is not loaded.
+pub fn get_wireguard_device(
+    socket: &mut Socket,
+    interface: &WireguardInterface,
+) -> IoResult<Vec<WireguardDeviceAttribute>> {
+    let family_id = family_id(socket)?;
+
+    let request = WireguardMessage::new(
+        family_id,
+        WireguardCommand::GetDevice,
+        vec![interface.attribute()],
+    );
+    socket.send_message(&Message::new(Flags::Request | Flags::Dump, request))?;
+
+    let messages = match socket.receive_message::<WireguardMessage>()? {
+        ReceivedMessage::Multipart(messages) => messages,
+        ReceivedMessage::Message(message) => vec![message],
+        ReceivedMessage::Error(error) => {
+            return Err(IoError::from_raw_os_error(-error.payload().error_code()))
+        }
+    };
+
+    let messages = messages
+        .into_iter()
+        .map(|x| x.into_payload().into_attributes());
+    merge_device_attributes(messages).ok_or_else(|| IoErrorKind::InvalidData.into())
+}
+
+/// Changes the configuration or the peers of a WireGuard device, like
+/// `wg set` does. Settings which are not given are left as they are, unless
+/// `WireguardDeviceFlags::ReplacePeers` is set. The id of the WireGuard family
+/// is resolved on the socket if it is not known yet.
+///
+/// The whole configuration is sent in a single message, so very large peer
+/// lists have to be split over multiple calls by the caller.
+pub fn set_wireguard_device(
+    socket: &mut Socket,
+    interface: &WireguardInterface,
+    attributes: Vec<WireguardDeviceAttribute>,
+) -> IoResult<()> {
+    let family_id = family_id(socket)?;
+
+    let attributes = std::iter::once(interface.attribute())
+        .chain(attributes)
+        .collect();
+    let request = WireguardMessage::new(family_id, WireguardCommand::SetDevice, attributes);
+    socket.send_acknowledged(Flags::empty(), request)
+}
+
+fn family_id(socket: &mut Socket) -> IoResult<u16> {
+    match socket.context().family_id(WG_GENL_NAME) {
+        Some(id) => Ok(id),
+        None => resolve_family_id(socket, WG_GENL_NAME),
+    }
+}
+
+/// Merges the attributes of the messages of a `GetDevice` dump. The device
+/// attributes are only sent in the first message; the following ones only
+/// carry more peers. A peer whose allowed IPs did not fit into a message is
+/// continued in the next one, with its public key repeated.
+fn merge_device_attributes(
+    messages: impl IntoIterator<Item = Vec<WireguardDeviceAttribute>>,
+) -> Option<Vec<WireguardDeviceAttribute>> {
+    let mut messages = messages.into_iter();
+    let mut attributes = messages.next()?;
+
+    let mut peers = Vec::new();
+    for attr in attributes.iter_mut() {
+        if let WireguardDeviceAttribute::Peers(list) = attr {
+            peers.append(list);
+        }
+    }
+    attributes.retain(|x| !matches!(x, WireguardDeviceAttribute::Peers(_)));
+
+    let more_peers = messages.flatten().filter_map(|attr| match attr {
+        WireguardDeviceAttribute::Peers(list) => Some(list),
+        _ => None,
+    });
+
+    for peer in more_peers.flatten() {
+        let continued = peers
+            .last_mut()
+            .filter(|last| public_key(last).is_some() && public_key(last) == public_key(&peer));
+
+        match continued {
+            Some(last) => {
+                for attr in peer {
+                    if let WireguardPeerAttribute::AllowedIps(ips) = attr {
+                        allowed_ips(last).extend(ips);
+                    }
+                }
+            }
+            None => peers.push(peer),
+        }
+    }
+
+    if !peers.is_empty() {
+        attributes.push(WireguardDeviceAttribute::Peers(peers));
+    }
+
+    Some(attributes)
+}
+
+fn public_key(peer: &[WireguardPeerAttribute]) -> Option<&WireguardKey> {
+    peer.iter().find_map(|x| match x {
+        WireguardPeerAttribute::PublicKey(key) => Some(key),
+        _ => None,
+    })
+}
+
+fn allowed_ips(peer: &mut Vec<WireguardPeerAttribute>) -> &mut Vec<super::WireguardAllowedIp> {
+    let position = peer
+        .iter()
+        .position(|x| matches!(x, WireguardPeerAttribute::AllowedIps(_)));
+
+    let position = position.unwrap_or_else(|| {
+        peer.push(WireguardPeerAttribute::AllowedIps(Vec::new()));
+        peer.len() - 1
+    });
+
+    match &mut peer[position] {
+        WireguardPeerAttribute::AllowedIps(ips) => ips,
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genetlink::{WireguardAllowedIp, WG_KEY_LEN};
+
+    fn peer(key: u8, ips: &[&str]) -> Vec<WireguardPeerAttribute> {
+        let ips = ips
+            .iter()
+            .map(|x| WireguardAllowedIp::new(x.parse().unwrap(), 32))
+            .collect();
+
+        vec![
+            WireguardPeerAttribute::PublicKey(WireguardKey([key; WG_KEY_LEN])),
+            WireguardPeerAttribute::AllowedIps(ips),
+        ]
+    }
+
+    #[test]
+    fn merge_split_dump() {
+        let messages = vec![
+            vec![
+                WireguardDeviceAttribute::InterfaceIndex(5),
+                WireguardDeviceAttribute::ListenPort(51820),
+                WireguardDeviceAttribute::Peers(vec![
+                    peer(1, &["10.0.0.1"]),
+                    peer(2, &["10.0.0.2"]),
+                ]),
+            ],
+            // The allowed IPs of the second peer are continued, and followed
+            // by another peer
+            vec![
+                WireguardDeviceAttribute::InterfaceIndex(5),
+                WireguardDeviceAttribute::Peers(vec![peer(2, &["10.0.0.3"]), peer(3, &[])]),
+            ],
+        ];
+
+        let attributes = merge_device_attributes(messages).unwrap();
+        assert_eq!(
+            attributes,
+            [
+                WireguardDeviceAttribute::InterfaceIndex(5),
+                WireguardDeviceAttribute::ListenPort(51820),
+                WireguardDeviceAttribute::Peers(vec![
+                    peer(1, &["10.0.0.1"]),
+                    peer(2, &["10.0.0.2", "10.0.0.3"]),
+                    peer(3, &[]),
+                ]),
+            ]
+        );
+
+        assert_eq!(merge_device_attributes(Vec::new()), None);
+    }
+}
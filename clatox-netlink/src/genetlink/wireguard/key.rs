@@ -0,0 +1,110 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// The length of WireGuard keys.
+pub const WG_KEY_LEN: usize = 32;
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// A Curve25519 private or public key, or a preshared key, of WireGuard.
+///
+/// It is displayed and parsed in base64, like the `wg` tool does.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WireguardKey(pub [u8; WG_KEY_LEN]);
+
+impl WireguardKey {
+    pub const fn as_bytes(&self) -> &[u8; WG_KEY_LEN] {
+        &self.0
+    }
+
+    /// Whether all bytes of the key are zero. The kernel reports such keys
+    /// for unset private keys and preshared keys.
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().all(|&x| x == 0)
+    }
+}
+
+// Keys are secrets more often than not, so they are not printed in debug
+// output.
+impl fmt::Debug for WireguardKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("WireguardKey(..)")
+    }
+}
+
+impl fmt::Display for WireguardKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut encoded = String::with_capacity(44);
+
+        for chunk in self.0.chunks(3) {
+            let bits = chunk
+                .iter()
+                .enumerate()
+                .fold(0u32, |acc, (i, &x)| acc | (x as u32) << (16 - 8 * i));
+
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    let index = (bits >> (18 - 6 * i)) & 0x3F;
+                    encoded.push(BASE64_ALPHABET[index as usize] as char);
+                } else {
+                    encoded.push('=');
+                }
+            }
+        }
+
+        f.write_str(&encoded)
+    }
+}
+
+/// The error returned when parsing a `WireguardKey` fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WireguardKeyParseError;
+
+impl fmt::Display for WireguardKeyParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid base64-encoded WireGuard key")
+    }
+}
+
+impl std::error::Error for WireguardKeyParseError {}
+
+impl FromStr for WireguardKey {
+    type Err = WireguardKeyParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // 32 bytes are encoded as 43 characters and a single padding
+        let s = s.strip_suffix('=').ok_or(WireguardKeyParseError)?;
+        if s.len() != 43 {
+            return Err(WireguardKeyParseError);
+        }
+
+        let mut bits = 0u32;
+        let mut bit_count = 0;
+        let mut key = [0u8; WG_KEY_LEN];
+        let mut len = 0;
+
+        for c in s.bytes() {
+            let value = BASE64_ALPHABET
+                .iter()
+                .position(|&x| x == c)
+                .ok_or(WireguardKeyParseError)?;
+
+            bits = bits << 6 | value as u32;
+            bit_count += 6;
+
+            if bit_count >= 8 {
+                bit_count -= 8;
+                key[len] = (bits >> bit_count) as u8;
+                len += 1;
+            }
+        }
+
+        // The 2 bits left over must be zero in a canonical encoding
+        if bits & ((1 << bit_count) - 1) != 0 {
+            return Err(WireguardKeyParseError);
+        }
+
+        Ok(Self(key))
+    }
+}
@@ -0,0 +1,137 @@
+use crate::genetlink::{GenericHeader, GENL_HDRLEN};
+use crate::netlink::{Attribute, MessageContext, Payload, RawAttributeIter, Type};
+
+use super::WireguardDeviceAttribute;
+
+/// The name of the WireGuard generic netlink family, to be passed to
+/// `resolve_family_id`.
+pub const WG_GENL_NAME: &str = "wireguard";
+pub const WG_GENL_VERSION: u8 = 1;
+
+pub const WG_CMD_GET_DEVICE: u8 = 0;
+pub const WG_CMD_SET_DEVICE: u8 = 1;
+
+/// The commands of the WireGuard family. They correspond to `WG_CMD_*` in
+/// <linux/wireguard.h>.
+#[doc(alias("WG_CMD_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum WireguardCommand {
+    /// Dumps the configuration and the peers of a device. The request must
+    /// have the `Dump` flag set.
+    GetDevice = WG_CMD_GET_DEVICE,
+
+    /// Changes the configuration or the peers of a device.
+    SetDevice = WG_CMD_SET_DEVICE,
+}
+
+impl WireguardCommand {
+    pub const fn raw_value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub const fn from_raw_value(value: u8) -> Option<Self> {
+        match value {
+            WG_CMD_GET_DEVICE => Some(Self::GetDevice),
+            WG_CMD_SET_DEVICE => Some(Self::SetDevice),
+            _ => None,
+        }
+    }
+}
+
+/// A message of the WireGuard generic netlink family.
+///
+/// WireGuard devices are created and deleted like other links, by sending a
+/// `NewLink` request with the `Create` flag and `LinkInfoData::Wireguard`,
+/// and are configured with this message afterwards. `get_wireguard_device`
+/// and `set_wireguard_device` send it for you.
+///
+/// The `family_id` is the id of the family as returned by
+/// `resolve_family_id`. Messages are only received on a socket that knows the
+/// id, either because it was resolved on it or recorded with
+/// `Socket::add_family`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WireguardMessage {
+    family_id: u16,
+    command: WireguardCommand,
+    attributes: Vec<WireguardDeviceAttribute>,
+}
+
+impl WireguardMessage {
+    pub const fn new(
+        family_id: u16,
+        command: WireguardCommand,
+        attributes: Vec<WireguardDeviceAttribute>,
+    ) -> Self {
+        Self {
+            family_id,
+            command,
+            attributes,
+        }
+    }
+
+    pub const fn family_id(&self) -> u16 {
+        self.family_id
+    }
+
+    pub const fn command(&self) -> WireguardCommand {
+        self.command
+    }
+
+    pub fn attributes(&self) -> &[WireguardDeviceAttribute] {
+        &self.attributes
+    }
+
+    pub fn into_attributes(self) -> Vec<WireguardDeviceAttribute> {
+        self.attributes
+    }
+}
+
+impl Payload for WireguardMessage {
+    // The real type is only known at runtime, see dynamic_message_type()
+    fn message_type() -> Type {
+        Type::Generic(0)
+    }
+
+    fn dynamic_message_type(&self) -> Type {
+        Type::Generic(self.family_id)
+    }
+
+    // Only messages of the family id resolved on the socket are accepted
+    fn parse_message_type(context: &MessageContext, value: u16) -> Option<Type> {
+        (context.family_id(WG_GENL_NAME) == Some(value)).then_some(Type::Generic(value))
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        let mut buffer = Vec::with_capacity(GENL_HDRLEN);
+        GenericHeader::new(self.command.raw_value(), WG_GENL_VERSION).serialize_into(&mut buffer);
+
+        for attr in &self.attributes {
+            attr.serialize_into(&mut buffer)
+        }
+
+        buffer.into_boxed_slice()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        let header = GenericHeader::deserialize(bytes)?;
+        let command = WireguardCommand::from_raw_value(header.command)?;
+        let attributes = RawAttributeIter::new(bytes[GENL_HDRLEN..].iter().cloned())
+            .map(WireguardDeviceAttribute::from_raw)
+            .try_collect()?;
+
+        Some(Self {
+            family_id: 0,
+            command,
+            attributes,
+        })
+    }
+
+    // The family id is not part of the payload
+    fn deserialize_in(context: &MessageContext, bytes: &[u8]) -> Option<Self> {
+        Some(Self {
+            family_id: context.family_id(WG_GENL_NAME)?,
+            ..Self::deserialize(bytes)?
+        })
+    }
+}
@@ -0,0 +1,16 @@
+mod attribute;
+mod device;
+mod key;
+mod message;
+
+#[doc(inline)]
+pub use attribute::*;
+
+#[doc(inline)]
+pub use device::*;
+
+#[doc(inline)]
+pub use key::*;
+
+#[doc(inline)]
+pub use message::*;
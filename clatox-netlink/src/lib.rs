@@ -1,6 +1,8 @@
 #![feature(iterator_try_collect)]
 
+pub mod genetlink;
 pub mod netlink;
 pub mod rtnetlink;
+pub mod tun;
 mod utils;
 
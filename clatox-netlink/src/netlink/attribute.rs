@@ -1,3 +1,5 @@
+use libc::{NLA_F_NESTED, NLA_TYPE_MASK};
+
 use crate::utils::{read_u16, align_attribute_len};
 
 /// A trait implemented by all Netlink message attributes.
@@ -41,9 +43,18 @@ impl RawAttribute {
         self.attr_len
     }
 
-    /// The type of an attribute.
+    /// The type of an attribute, with the `NLA_F_NESTED` and
+    /// `NLA_F_NET_BYTEORDER` flags stripped.
     pub fn attr_type(&self) -> u16 {
-        self.attr_type
+        self.attr_type & NLA_TYPE_MASK as u16
+    }
+
+    /// Whether the attribute is marked as containing nested attributes.
+    ///
+    /// Note that the kernel does not set `NLA_F_NESTED` on every nested
+    /// attribute, mostly for the sake of backwards compatibility.
+    pub fn is_nested(&self) -> bool {
+        self.attr_type & NLA_F_NESTED as u16 != 0
     }
 
     /// The payload of an attribute.
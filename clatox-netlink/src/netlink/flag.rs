@@ -12,6 +12,10 @@ bitflags! {
         const Multi = NLM_F_MULTI as u16;
         const Ack = NLM_F_ACK as u16;
         const Echo = NLM_F_ECHO as u16;
+        /// The dump was inconsistent due to a change during the dump
+        const DumpInterrupted = NLM_F_DUMP_INTR as u16;
+        /// The dump was filtered as requested
+        const DumpFiltered = NLM_F_DUMP_FILTERED as u16;
         
         // Get flags
         const Root = NLM_F_ROOT as u16;
@@ -1,4 +1,4 @@
-use super::{Flags, Type};
+use super::{Flags, Protocol, Type};
 
 /// The header of a Netlink message. It is equivalent to a Netlink message
 /// without a payload.
@@ -28,9 +28,68 @@ pub struct Message<T: Sized> {
 pub trait Payload: Sized {
     fn message_type() -> Type;
 
+    /// The type of the message carrying this payload. Only payloads whose
+    /// message type is assigned at runtime, like those of generic netlink
+    /// families, need to override it.
+    fn dynamic_message_type(&self) -> Type {
+        Self::message_type()
+    }
+
+    /// Interprets the type of a received message, returning `None` if the
+    /// message does not carry this kind of payload.
+    fn parse_message_type(context: &MessageContext, value: u16) -> Option<Type> {
+        Some(context.message_type(value)).filter(|x| *x == Self::message_type())
+    }
+
     fn serialize(&self) -> Box<[u8]>;
 
     fn deserialize(bytes: &[u8]) -> Option<Self>;
+
+    /// Deserializes a payload received in the given context. Only payloads
+    /// that embed message types, like `ErrorMessage`, need to override it.
+    fn deserialize_in(_context: &MessageContext, bytes: &[u8]) -> Option<Self> {
+        Self::deserialize(bytes)
+    }
+}
+
+/// What the type of a received message is interpreted against: the protocol of
+/// the socket it is received on and, for generic netlink, the ids of the
+/// families resolved on that socket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageContext {
+    protocol: Protocol,
+    families: Vec<(String, u16)>,
+}
+
+impl MessageContext {
+    pub const fn new(protocol: Protocol) -> Self {
+        Self {
+            protocol,
+            families: Vec::new(),
+        }
+    }
+
+    pub const fn protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    /// The id of the given generic netlink family, if it was resolved.
+    pub fn family_id(&self, name: &str) -> Option<u16> {
+        self.families
+            .iter()
+            .find_map(|(family, id)| (family == name).then_some(*id))
+    }
+
+    /// Records the id of a generic netlink family, so that the messages of the
+    /// family are recognized.
+    pub fn add_family(&mut self, name: &str, id: u16) {
+        self.families.retain(|(family, _)| family != name);
+        self.families.push((name.to_owned(), id));
+    }
+
+    pub fn message_type(&self, value: u16) -> Type {
+        Type::from_protocol_value(self.protocol, value)
+    }
 }
 
 /// A Netlink error message.
@@ -72,6 +131,12 @@ impl Payload for ErrorMessage {
     }
 
     fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Self::deserialize_in(&MessageContext::new(Protocol::Route), bytes)
+    }
+
+    // The type in the original header is interpreted like the one of the
+    // error message itself
+    fn deserialize_in(context: &MessageContext, bytes: &[u8]) -> Option<Self> {
         let mut bytes = bytes.iter();
 
         let error_code = i32::from_ne_bytes([
@@ -80,7 +145,7 @@ impl Payload for ErrorMessage {
             *bytes.next()?,
             *bytes.next()?,
         ]);
-        let original_header = Message::<()>::deserialize(bytes.as_slice())?;
+        let original_header = Message::<()>::deserialize_in(context, bytes.as_slice())?;
 
         Some(Self {
             error_code,
@@ -98,6 +163,12 @@ impl Payload for () {
         Box::new([])
     }
 
+    // Headers are read before knowing the payload, so every type is accepted.
+    // Types that are unknown to the protocol are kept as `Type::Other`.
+    fn parse_message_type(context: &MessageContext, value: u16) -> Option<Type> {
+        Some(context.message_type(value))
+    }
+
     fn deserialize(_bytes: &[u8]) -> Option<Self> {
         Some(())
     }
@@ -117,7 +188,7 @@ impl<T: Payload> Message<T> {
     pub fn new(flags: Flags, payload: T) -> Self {
         Self {
             length: 16,
-            message_type: T::message_type(),
+            message_type: payload.dynamic_message_type(),
             flags: flags,
             seq: 0,
             pid: 0,
@@ -144,7 +215,15 @@ impl<T: Payload> Message<T> {
         buffer.into_boxed_slice()
     }
 
+    /// Deserializes a message, interpreting its type as one of the Route
+    /// protocol. Use `deserialize_in` for messages of other protocols.
     pub fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Self::deserialize_in(&MessageContext::new(Protocol::Route), bytes)
+    }
+
+    /// Deserializes a message received in the given context, see
+    /// `Socket::context`.
+    pub fn deserialize_in(context: &MessageContext, bytes: &[u8]) -> Option<Self> {
         fn read_u16<I: Iterator<Item = u8>>(mut iter: I) -> Option<u16> {
             let bytes = [iter.next()?, iter.next()?];
 
@@ -164,7 +243,6 @@ impl<T: Payload> Message<T> {
         }
 
         let mut iter = bytes.iter();
-        let header_only = std::mem::size_of::<T>() == 0;
 
         let length = read_u32(iter.by_ref().cloned()).unwrap();
         let message_type = read_u16(iter.by_ref().cloned()).unwrap();
@@ -173,16 +251,11 @@ impl<T: Payload> Message<T> {
         let seq = read_u32(iter.by_ref().cloned()).unwrap();
         let pid = read_u32(iter.by_ref().cloned()).unwrap();
 
-        let message_type = Type::from_raw_value(message_type)?;
+        let message_type = T::parse_message_type(context, message_type)?;
         let flags = Flags::from_bits(flags)?;
 
-        // The `()` "payload" means that we get only the headers.
-        if !header_only && message_type != T::message_type() {
-            return None;
-        }
-
         let the_rest = iter.as_slice();
-        let payload = T::deserialize(&the_rest)?;
+        let payload = T::deserialize_in(context, the_rest)?;
 
         Some(Message {
             length: length,
@@ -219,3 +292,41 @@ impl<T: Payload> Message<T> {
         self.payload
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::netlink::RouteType;
+
+    #[test]
+    fn type_depends_on_protocol() {
+        let header = Message::new(Flags::Request, ());
+        let mut bytes = header.serialize().into_vec();
+        bytes[4..6].copy_from_slice(&21u16.to_ne_bytes());
+
+        let route = Message::<()>::deserialize(&bytes).unwrap();
+        assert_eq!(route.message_type(), Type::Route(RouteType::DeleteAddress));
+
+        let context = MessageContext::new(Protocol::Generic);
+        let generic = Message::<()>::deserialize_in(&context, &bytes).unwrap();
+        assert_eq!(generic.message_type(), Type::Generic(21));
+
+        bytes[4..6].copy_from_slice(&0x1234u16.to_ne_bytes());
+        let unknown = Message::<()>::deserialize(&bytes).unwrap();
+        assert_eq!(unknown.message_type(), Type::Other(0x1234));
+    }
+
+    #[test]
+    fn error_round_trip() {
+        let context = MessageContext::new(Protocol::Generic);
+        let mut original = Message::new(Flags::Request, ()).serialize().into_vec();
+        original[4..6].copy_from_slice(&21u16.to_ne_bytes());
+        let original_header = Message::<()>::deserialize_in(&context, &original).unwrap();
+
+        let error = Message::new(Flags::empty(), ErrorMessage::new(-22, original_header.clone()));
+        let decoded = Message::<ErrorMessage>::deserialize_in(&context, &error.serialize()).unwrap();
+
+        assert_eq!(decoded.payload().error_code(), -22);
+        assert_eq!(decoded.payload().original_header(), &original_header);
+    }
+}
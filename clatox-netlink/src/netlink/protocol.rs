@@ -1,8 +1,13 @@
 use libc::*;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Protocol {
     Route,
 
+    /// Generic netlink, which multiplexes the families of many subsystems,
+    /// e.g. WireGuard.
+    Generic,
+
     // Other protocols are TODO
 }
 
@@ -12,6 +17,15 @@ impl Protocol {
 
         match self {
             Route => NETLINK_ROUTE,
+            Generic => NETLINK_GENERIC,
+        }
+    }
+
+    pub const fn from_raw_value(value: c_int) -> Option<Self> {
+        match value {
+            NETLINK_ROUTE => Some(Self::Route),
+            NETLINK_GENERIC => Some(Self::Generic),
+            _ => None,
         }
     }
 }
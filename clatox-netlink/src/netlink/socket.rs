@@ -3,15 +3,18 @@ use socket2::{Domain, Protocol as RawProtocol, SockAddr, Socket as RawSocket, Ty
 
 use std::io::{ErrorKind as IoErrorKind, IoSlice, IoSliceMut, Read, Result as IoResult, Write};
 use std::os::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, RawFd};
+use std::time::Duration;
 
-use super::{Message, Payload, Protocol, Flags, types, ErrorMessage};
+use super::{Message, MessageContext, Payload, Protocol, Flags, types, ErrorMessage};
 
 /// This corresponds to an opened socket which is bound to a `SocketAddr`.
 /// Right now, `SocketAddr` is not implemented and `Socket` is hardcoded to
-/// bind to `(pid: 0, groups: 0)`.
+/// bind to `(pid: 0, groups: 0)`. Multicast groups can be joined afterwards
+/// with `add_membership`.
 #[derive(Debug)]
 pub struct Socket {
     socket: RawSocket,
+    context: MessageContext,
 }
 
 /// Received message(s) or error from the socket.
@@ -65,7 +68,104 @@ impl Socket {
         socket.set_send_buffer_size(1 << 16)?;
         socket.set_recv_buffer_size(1 << 18)?; // 256KB ought to be enough!! or not...
 
-        Ok(Self { socket })
+        Ok(Self {
+            socket,
+            context: MessageContext::new(protocol),
+        })
+    }
+
+    pub const fn protocol(&self) -> Protocol {
+        self.context.protocol()
+    }
+
+    /// The context that the types of the messages received on this socket are
+    /// interpreted in.
+    pub const fn context(&self) -> &MessageContext {
+        &self.context
+    }
+
+    /// Records the id of a generic netlink family resolved on another socket.
+    /// Messages of a generic netlink family are only recognized once its id is
+    /// known, see `resolve_family_id`.
+    pub fn add_family(&mut self, name: &str, id: u16) {
+        self.context.add_family(name, id)
+    }
+
+    /// Enables or disables strict checking of the requests sent on this
+    /// socket (`NETLINK_GET_STRICT_CHK`).
+    ///
+    /// With strict checking, the kernel rejects malformed headers and unknown
+    /// attributes in dump requests instead of ignoring them, and honours the
+    /// filters given in the header or the attributes of some dumps, e.g. the
+    /// interface index of a `GetAddress` dump.
+    pub fn set_strict_checking(&mut self, enabled: bool) -> IoResult<()> {
+        self.set_int_option(NETLINK_GET_STRICT_CHK, enabled as c_int)
+    }
+
+    /// Whether strict checking is enabled on this socket, see
+    /// `set_strict_checking`.
+    pub fn strict_checking(&self) -> IoResult<bool> {
+        Ok(self.int_option(NETLINK_GET_STRICT_CHK)? != 0)
+    }
+
+    /// Joins a multicast group, e.g. `RTNLGRP_IPV6_IFADDR`, after which the
+    /// notifications sent to the group are received on this socket.
+    pub fn add_membership(&mut self, group: u32) -> IoResult<()> {
+        self.set_int_option(NETLINK_ADD_MEMBERSHIP, group as c_int)
+    }
+
+    /// Leaves a multicast group joined with `add_membership`.
+    pub fn drop_membership(&mut self, group: u32) -> IoResult<()> {
+        self.set_int_option(NETLINK_DROP_MEMBERSHIP, group as c_int)
+    }
+
+    /// Sets the timeout of receiving messages. Once it expires, receiving
+    /// fails with `WouldBlock`. `None` waits indefinitely.
+    pub fn set_read_timeout(&mut self, timeout: Option<Duration>) -> IoResult<()> {
+        self.socket.set_read_timeout(timeout)
+    }
+
+    fn set_int_option(&mut self, name: c_int, value: c_int) -> IoResult<()> {
+        // SAFETY: The options of SOL_NETLINK take an int, whose size is passed
+        //         along with the pointer to it.
+        let result = unsafe {
+            setsockopt(
+                self.socket.as_raw_fd(),
+                SOL_NETLINK,
+                name,
+                &value as *const c_int as *const c_void,
+                std::mem::size_of::<c_int>() as socklen_t,
+            )
+        };
+
+        if result < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+
+    fn int_option(&self, name: c_int) -> IoResult<c_int> {
+        let mut value: c_int = 0;
+        let mut len = std::mem::size_of::<c_int>() as socklen_t;
+
+        // SAFETY: The options of SOL_NETLINK are written to an int, whose size
+        //         is passed along with the pointer to it.
+        let result = unsafe {
+            getsockopt(
+                self.socket.as_raw_fd(),
+                SOL_NETLINK,
+                name,
+                &mut value as *mut c_int as *mut c_void,
+                &mut len,
+            )
+        };
+
+        if result < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        Ok(value)
     }
 
     pub fn receive_message<T>(&mut self) -> IoResult<ReceivedMessage<T>>
@@ -88,7 +188,7 @@ impl Socket {
             buffer.set_len(size);
         }
 
-        let first_header = match Message::<()>::deserialize(&buffer[..size]) {
+        let first_header = match Message::<()>::deserialize_in(&self.context, &buffer[..size]) {
             Some(header) => header,
             None => Err(IoErrorKind::InvalidInput)?,
         };
@@ -109,20 +209,30 @@ impl Socket {
                     let current_buf = &buffer[last_used_buffer..used_buffer];
                     let next_buf = &buffer[used_buffer..];
 
-                    let message = match Message::<T>::deserialize(current_buf) {
+                    let message = match Message::<T>::deserialize_in(&self.context, current_buf) {
                         Some(msg) => msg,
                         None => Err(IoErrorKind::InvalidInput)?,
                     };
 
                     messages.push(message);
 
-                    header = match Message::<()>::deserialize(next_buf) {
+                    header = match Message::<()>::deserialize_in(&self.context, next_buf) {
                         Some(header) => header,
                         None if used_buffer < buffer.len() => Err(IoErrorKind::InvalidInput)?,
                         None => break,
                     };
 
                     last_used_buffer = used_buffer;
+
+                    // Small dumps fit in a single read, the terminating
+                    // NLMSG_DONE included
+                    if header.message_type() == types::Type::Done {
+                        break;
+                    }
+                }
+
+                if header.message_type() == types::Type::Done {
+                    break;
                 }
 
                 // SAFETY: The buffer is extended to its original capacity, and recvmsg()
@@ -132,7 +242,7 @@ impl Socket {
                     let size = self.socket.read(buffer.as_mut_slice())?;
                     buffer.set_len(size);
 
-                    header = match Message::<()>::deserialize(&buffer) {
+                    header = match Message::<()>::deserialize_in(&self.context, &buffer) {
                         Some(header) => header,
                         None => Err(IoErrorKind::InvalidInput)?,
                     };
@@ -141,12 +251,12 @@ impl Socket {
 
             Ok(ReceivedMessage::Multipart(messages))
         } else if is_error {
-            match Message::<ErrorMessage>::deserialize(&buffer[..size]) {
+            match Message::<ErrorMessage>::deserialize_in(&self.context, &buffer[..size]) {
                 Some(msg) => Ok(ReceivedMessage::Error(msg)),
                 None => Err(IoErrorKind::InvalidInput)?,
             }
         } else {
-            match Message::<T>::deserialize(&buffer[..size]) {
+            match Message::<T>::deserialize_in(&self.context, &buffer[..size]) {
                 Some(msg) => Ok(ReceivedMessage::Message(msg)),
                 None => Err(IoErrorKind::InvalidInput)?,
             }
@@ -159,6 +269,25 @@ impl Socket {
     {
         self.socket.write(msg.serialize().as_ref())
     }
+
+    /// Sends a request with the `Ack` flag set and waits for the kernel to
+    /// acknowledge it. An error reported by the kernel is returned as the
+    /// corresponding `io::Error`, e.g. `EEXIST` for an existing object.
+    pub fn send_acknowledged<T>(&mut self, flags: Flags, payload: T) -> IoResult<()>
+    where
+        T: Payload,
+    {
+        let message = Message::new(flags | Flags::Request | Flags::Ack, payload);
+        self.send_message(&message)?;
+
+        match self.receive_message::<()>()? {
+            ReceivedMessage::Error(error) if error.payload().error_code() == 0 => Ok(()),
+            ReceivedMessage::Error(error) => {
+                Err(std::io::Error::from_raw_os_error(-error.payload().error_code()))
+            }
+            _ => Err(IoErrorKind::InvalidData)?,
+        }
+    }
 }
 
 impl AsFd for Socket {
@@ -174,9 +303,27 @@ impl AsRawFd for Socket {
 }
 
 impl FromRawFd for Socket {
+    // The protocol is read back from the socket, assuming the Route protocol
+    // if it is unknown
     unsafe fn from_raw_fd(fd: RawFd) -> Self {
+        let mut protocol: c_int = 0;
+        let mut len = std::mem::size_of::<c_int>() as socklen_t;
+        let result = getsockopt(
+            fd,
+            SOL_SOCKET,
+            SO_PROTOCOL,
+            &mut protocol as *mut c_int as *mut c_void,
+            &mut len,
+        );
+
+        let protocol = match result {
+            0 => Protocol::from_raw_value(protocol).unwrap_or(Protocol::Route),
+            _ => Protocol::Route,
+        };
+
         Self {
             socket: RawSocket::from_raw_fd(fd),
+            context: MessageContext::new(protocol),
         }
     }
 }
@@ -1,5 +1,17 @@
 use libc::*;
 
+use super::Protocol;
+
+pub const RTM_NEWLINKPROP: u16 = 108;
+pub const RTM_DELLINKPROP: u16 = 109;
+pub const RTM_NEWMULTICAST: u16 = 56;
+pub const RTM_DELMULTICAST: u16 = 57;
+pub const RTM_NEWANYCAST: u16 = 60;
+pub const RTM_DELANYCAST: u16 = 61;
+pub const RTM_NEWNEXTHOP: u16 = 104;
+pub const RTM_DELNEXTHOP: u16 = 105;
+pub const RTM_GETNEXTHOP: u16 = 106;
+
 /// Message types in Netlink.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u16)]
@@ -10,6 +22,13 @@ pub enum Type {
     Overrun,
 
     Route(RouteType),
+
+    /// A message of a generic netlink family, identified by the id the kernel
+    /// assigned to the family. See `resolve_family_id`.
+    Generic(u16),
+
+    /// A message type that is unknown to the protocol it was received on.
+    Other(u16),
 }
 
 impl Type {
@@ -22,6 +41,8 @@ impl Type {
             Done => NLMSG_DONE,
             Overrun => NLMSG_OVERRUN,
             Route(r) => r.raw_value() as i32,
+            Generic(id) => *id as i32,
+            Other(x) => *x as i32,
         };
 
         value as u16
@@ -55,6 +76,22 @@ impl Type {
 
         result
     }
+
+    /// Interprets a message type according to the protocol of the socket it is
+    /// received on, as the values above `NLMSG_MIN_TYPE` are shared by all
+    /// protocols. Unknown types are kept as `Other`.
+    pub fn from_protocol_value(protocol: Protocol, value: u16) -> Self {
+        if value < NLMSG_MIN_TYPE as u16 {
+            return Self::from_raw_value(value).unwrap_or(Self::Other(value));
+        }
+
+        match protocol {
+            Protocol::Route => RouteType::from_raw_value(value)
+                .map(Self::Route)
+                .unwrap_or(Self::Other(value)),
+            Protocol::Generic => Self::Generic(value),
+        }
+    }
 }
 
 /// Messsage types in the Route protocol.
@@ -66,10 +103,11 @@ impl Type {
 ///
 /// The expansion rules are listed below:
 ///
-/// ```
+/// ```text
 /// Del => Delete
 /// Addr => Address
 /// Neigh => Neighbor
+/// Prop => Property
 /// T => Traffic
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -77,11 +115,27 @@ pub enum RouteType {
     NewLink,
     DeleteLink,
     GetLink,
+    SetLink,
+
+    NewLinkProperty,
+    DeleteLinkProperty,
 
     NewAddress,
     DeleteAddress,
     GetAddress,
 
+    NewMulticast,
+    DeleteMulticast,
+    GetMulticast,
+
+    NewAnycast,
+    DeleteAnycast,
+    GetAnycast,
+
+    NewAddressLabel,
+    DeleteAddressLabel,
+    GetAddressLabel,
+
     NewRoute,
     DeleteRoute,
     GetRoute,
@@ -94,6 +148,10 @@ pub enum RouteType {
     DeleteRule,
     GetRule,
 
+    NewNextHop,
+    DeleteNextHop,
+    GetNextHop,
+
     NewTrafficClass,
     DeleteTrafficClass,
     GetTrafficClass,
@@ -101,6 +159,9 @@ pub enum RouteType {
     NewTrafficFilter,
     DeleteTrafficFilter,
     GetTrafficFilter,
+
+    NewStats,
+    GetStats,
 }
 
 impl RouteType {
@@ -111,15 +172,35 @@ impl RouteType {
             NewLink => RTM_NEWLINK,
             DeleteLink => RTM_DELLINK,
             GetLink => RTM_GETLINK,
+            SetLink => RTM_SETLINK,
+
+            NewLinkProperty => RTM_NEWLINKPROP,
+            DeleteLinkProperty => RTM_DELLINKPROP,
 
             NewAddress => RTM_NEWADDR,
             DeleteAddress => RTM_DELADDR,
             GetAddress => RTM_GETADDR,
 
+            NewMulticast => RTM_NEWMULTICAST,
+            DeleteMulticast => RTM_DELMULTICAST,
+            GetMulticast => RTM_GETMULTICAST,
+
+            NewAnycast => RTM_NEWANYCAST,
+            DeleteAnycast => RTM_DELANYCAST,
+            GetAnycast => RTM_GETANYCAST,
+
+            NewAddressLabel => RTM_NEWADDRLABEL,
+            DeleteAddressLabel => RTM_DELADDRLABEL,
+            GetAddressLabel => RTM_GETADDRLABEL,
+
             NewRoute => RTM_NEWROUTE,
             DeleteRoute => RTM_DELROUTE,
             GetRoute => RTM_GETROUTE,
 
+            NewNextHop => RTM_NEWNEXTHOP,
+            DeleteNextHop => RTM_DELNEXTHOP,
+            GetNextHop => RTM_GETNEXTHOP,
+
             NewNeighbor => RTM_NEWNEIGH,
             DeleteNeighbor => RTM_DELNEIGH,
             GetNeighbor => RTM_GETNEIGH,
@@ -135,6 +216,9 @@ impl RouteType {
             NewTrafficFilter => RTM_NEWTFILTER,
             DeleteTrafficFilter => RTM_DELTFILTER,
             GetTrafficFilter => RTM_GETTFILTER,
+
+            NewStats => RTM_NEWSTATS,
+            GetStats => RTM_GETSTATS,
         };
 
         value as u16
@@ -145,15 +229,35 @@ impl RouteType {
             RTM_NEWLINK => Some(RouteType::NewLink),
             RTM_DELLINK => Some(RouteType::DeleteLink),
             RTM_GETLINK => Some(RouteType::GetLink),
+            RTM_SETLINK => Some(RouteType::SetLink),
+
+            RTM_NEWLINKPROP => Some(RouteType::NewLinkProperty),
+            RTM_DELLINKPROP => Some(RouteType::DeleteLinkProperty),
 
             RTM_NEWADDR => Some(RouteType::NewAddress),
             RTM_DELADDR => Some(RouteType::DeleteAddress),
             RTM_GETADDR => Some(RouteType::GetAddress),
 
+            RTM_NEWMULTICAST => Some(RouteType::NewMulticast),
+            RTM_DELMULTICAST => Some(RouteType::DeleteMulticast),
+            RTM_GETMULTICAST => Some(RouteType::GetMulticast),
+
+            RTM_NEWANYCAST => Some(RouteType::NewAnycast),
+            RTM_DELANYCAST => Some(RouteType::DeleteAnycast),
+            RTM_GETANYCAST => Some(RouteType::GetAnycast),
+
+            RTM_NEWADDRLABEL => Some(RouteType::NewAddressLabel),
+            RTM_DELADDRLABEL => Some(RouteType::DeleteAddressLabel),
+            RTM_GETADDRLABEL => Some(RouteType::GetAddressLabel),
+
             RTM_NEWROUTE => Some(RouteType::NewRoute),
             RTM_DELROUTE => Some(RouteType::DeleteRoute),
             RTM_GETROUTE => Some(RouteType::GetRoute),
 
+            RTM_NEWNEXTHOP => Some(RouteType::NewNextHop),
+            RTM_DELNEXTHOP => Some(RouteType::DeleteNextHop),
+            RTM_GETNEXTHOP => Some(RouteType::GetNextHop),
+
             RTM_NEWNEIGH => Some(RouteType::NewNeighbor),
             RTM_DELNEIGH => Some(RouteType::DeleteNeighbor),
             RTM_GETNEIGH => Some(RouteType::GetNeighbor),
@@ -170,6 +274,9 @@ impl RouteType {
             RTM_DELTFILTER => Some(RouteType::DeleteTrafficFilter),
             RTM_GETTFILTER => Some(RouteType::GetTrafficFilter),
 
+            RTM_NEWSTATS => Some(RouteType::NewStats),
+            RTM_GETSTATS => Some(RouteType::GetStats),
+
             _ => None,
         }
     }
@@ -5,12 +5,18 @@ use libc::*;
 use crate::netlink::{Attribute, RawAttribute};
 use crate::utils;
 
-use super::{AddressCacheInfo, AddressFlags};
+use crate::rtnetlink::{AddressAttribute, NetworkAddress};
+
+use super::{AddressCacheInfo, AddressFlags, AddressProtocol};
 
 pub const IFA_RT_PRIORITY: u16 = 9;
 pub const IFA_TARGET_NETNSID: u16 = 10;
 pub const IFA_PROTO: u16 = 11;
 
+/// Attributes of an address message. They correspond to `IFA_*` in libc.
+///
+/// The addresses are interpreted according to the address family of the
+/// message; on their own, they are decoded as `NetworkAddress::Other`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[doc(alias("ifaddrmsg"))]
 pub enum InterfaceAddressAttribute {
@@ -18,25 +24,25 @@ pub enum InterfaceAddressAttribute {
     Unspecified(Vec<u8>),
 
     /// `IFA_ADDRESS`
-    Address(Vec<u8>),
+    Address(NetworkAddress),
 
     /// `IFA_LOCAL`
-    Local(Vec<u8>),
+    Local(NetworkAddress),
 
     /// `IFA_LABEL`
     Label(String),
 
     /// `IFA_BROADCAST`
-    Broadcast(Vec<u8>),
+    Broadcast(NetworkAddress),
 
     /// `IFA_ANYCAST`
-    Anycast(Vec<u8>),
+    Anycast(NetworkAddress),
 
     /// `IFA_CACHEINFO`
     CacheInfo(AddressCacheInfo),
 
     /// `IFA_MULTICAST`
-    Multicast(Vec<u8>),
+    Multicast(NetworkAddress),
 
     /// `IFA_FLAGS`
     Flags(AddressFlags),
@@ -48,7 +54,7 @@ pub enum InterfaceAddressAttribute {
     TargetNetNamespaceId(Vec<u8>),
 
     /// `IFA_PROTO`
-    Protocol(u8),
+    Protocol(AddressProtocol),
 
     Other(u16, Vec<u8>),
 }
@@ -60,12 +66,12 @@ impl InterfaceAddressAttribute {
                 buffer.extend(content.iter());
                 IFA_UNSPEC
             }
-            InterfaceAddressAttribute::Address(content) => {
-                buffer.extend(content.iter());
+            InterfaceAddressAttribute::Address(address) => {
+                buffer.extend(address.to_bytes());
                 IFA_ADDRESS
             }
-            InterfaceAddressAttribute::Local(content) => {
-                buffer.extend(content.iter());
+            InterfaceAddressAttribute::Local(address) => {
+                buffer.extend(address.to_bytes());
                 IFA_LOCAL
             }
             InterfaceAddressAttribute::Label(label) => {
@@ -73,12 +79,12 @@ impl InterfaceAddressAttribute {
                 buffer.push(0u8); // zero-terminated string
                 IFA_LABEL
             }
-            InterfaceAddressAttribute::Broadcast(content) => {
-                buffer.extend(content.iter());
+            InterfaceAddressAttribute::Broadcast(address) => {
+                buffer.extend(address.to_bytes());
                 IFA_BROADCAST
             }
-            InterfaceAddressAttribute::Anycast(content) => {
-                buffer.extend(content.iter());
+            InterfaceAddressAttribute::Anycast(address) => {
+                buffer.extend(address.to_bytes());
                 IFA_ANYCAST
             }
             InterfaceAddressAttribute::CacheInfo(cache) => {
@@ -92,8 +98,8 @@ impl InterfaceAddressAttribute {
                 buffer.extend(bytes.iter().cloned());
                 IFA_CACHEINFO
             }
-            InterfaceAddressAttribute::Multicast(content) => {
-                buffer.extend(content.iter());
+            InterfaceAddressAttribute::Multicast(address) => {
+                buffer.extend(address.to_bytes());
                 IFA_MULTICAST
             }
             InterfaceAddressAttribute::Flags(flag) => {
@@ -109,7 +115,7 @@ impl InterfaceAddressAttribute {
                 IFA_TARGET_NETNSID
             }
             InterfaceAddressAttribute::Protocol(prot) => {
-                buffer.push(*prot);
+                buffer.push(prot.raw_value());
                 IFA_PROTO
             }
             InterfaceAddressAttribute::Other(typ, content) => {
@@ -127,16 +133,16 @@ impl Attribute for InterfaceAddressAttribute {
 
         let attr = match attr_type {
             IFA_UNSPEC => InterfaceAddressAttribute::Unspecified(content),
-            IFA_ADDRESS => InterfaceAddressAttribute::Address(content),
-            IFA_LOCAL => InterfaceAddressAttribute::Local(content),
+            IFA_ADDRESS => InterfaceAddressAttribute::Address(NetworkAddress::Other(content)),
+            IFA_LOCAL => InterfaceAddressAttribute::Local(NetworkAddress::Other(content)),
             IFA_LABEL => {
                 let mut content = String::from_utf8(content).ok()?;
                 let popped = content.pop();
                 debug_assert!(popped == Some('\0'));
                 InterfaceAddressAttribute::Label(content)
             }
-            IFA_BROADCAST => InterfaceAddressAttribute::Broadcast(content),
-            IFA_ANYCAST => InterfaceAddressAttribute::Anycast(content),
+            IFA_BROADCAST => InterfaceAddressAttribute::Broadcast(NetworkAddress::Other(content)),
+            IFA_ANYCAST => InterfaceAddressAttribute::Anycast(NetworkAddress::Other(content)),
             IFA_CACHEINFO => {
                 let content = <[u8; size_of::<AddressCacheInfo>()]>::try_from(content).ok()?;
                 // SAFETY: AddressCacheInfo is a plain-old-data struct and contains no
@@ -147,7 +153,7 @@ impl Attribute for InterfaceAddressAttribute {
                 };
                 InterfaceAddressAttribute::CacheInfo(cache)
             }
-            IFA_MULTICAST => InterfaceAddressAttribute::Multicast(content),
+            IFA_MULTICAST => InterfaceAddressAttribute::Multicast(NetworkAddress::Other(content)),
             IFA_FLAGS => {
                 let content = <[u8; 4]>::try_from(content).ok()?;
                 let flag = AddressFlags::from_bits_truncate(u32::from_ne_bytes(content));
@@ -160,7 +166,7 @@ impl Attribute for InterfaceAddressAttribute {
             IFA_TARGET_NETNSID => InterfaceAddressAttribute::TargetNetNamespaceId(content),
             IFA_PROTO => {
                 let content = *content.get(0)?;
-                InterfaceAddressAttribute::Protocol(content)
+                InterfaceAddressAttribute::Protocol(AddressProtocol::from_raw_value(content))
             }
             typ => InterfaceAddressAttribute::Other(typ, content),
         };
@@ -168,3 +174,16 @@ impl Attribute for InterfaceAddressAttribute {
         Some(attr)
     }
 }
+
+impl AddressAttribute for InterfaceAddressAttribute {
+    fn address_mut(&mut self) -> Option<&mut NetworkAddress> {
+        match self {
+            Self::Address(address)
+            | Self::Local(address)
+            | Self::Broadcast(address)
+            | Self::Anycast(address)
+            | Self::Multicast(address) => Some(address),
+            _ => None,
+        }
+    }
+}
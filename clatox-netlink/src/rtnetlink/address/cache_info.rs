@@ -1,3 +1,6 @@
+/// The lifetime of an address that never expires, in `AddressCacheInfo`.
+pub const INFINITY_LIFE_TIME: u32 = u32::MAX;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[doc(alias("ifa_cacheinfo"))]
 #[repr(C)]
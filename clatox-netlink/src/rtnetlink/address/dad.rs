@@ -0,0 +1,169 @@
+use std::io::{Error as IoError, ErrorKind as IoErrorKind, Read, Result as IoResult};
+use std::net::{IpAddr, Ipv6Addr};
+use std::time::{Duration, Instant};
+
+use libc::RTNLGRP_IPV6_IFADDR;
+
+use crate::netlink::{Message, Protocol, RouteType, Socket, Type};
+use crate::rtnetlink::{AddressFamily, NetworkAddress};
+use crate::utils::align_attribute_len;
+
+use super::{
+    dump_addresses, AddressFlags, AddressRequest, DeleteAddress, InterfaceAddressAttribute,
+    InterfaceAddressMessage, NewAddress,
+};
+
+/// Waits until duplicate address detection (DAD) of an IPv6 address of the
+/// interface of the given index completes, i.e. until the address is no
+/// longer `AddressFlags::Tentative`. Addresses added with
+/// `AddressFlags::Optimistic`, which the kernel honours only if the
+/// `optimistic_dad` sysctl of the interface is enabled, are usable during DAD,
+/// so they are waited for only until they appear.
+///
+/// The address does not need to exist yet. The wait fails with `AddrInUse` if
+/// DAD detects a duplicate, with `AddrNotAvailable` if the address is removed,
+/// and with `TimedOut` once the timeout expires.
+///
+/// The current state of the address is dumped on the given socket, which has
+/// strict checking enabled by `dump_addresses`. The notifications are received
+/// on a socket of its own.
+pub fn wait_for_dad(
+    socket: &mut Socket,
+    index: u32,
+    address: Ipv6Addr,
+    timeout: Duration,
+) -> IoResult<()> {
+    AddressWatcher::new()?.wait(socket, index, address, timeout)
+}
+
+impl AddressRequest {
+    /// Adds the address like `add`, and waits for DAD to complete like
+    /// `wait_for_dad` if it is an IPv6 address without `AddressFlags::NoDad`.
+    pub fn add_and_wait(&self, socket: &mut Socket, timeout: Duration) -> IoResult<()> {
+        let address = match self.address() {
+            IpAddr::V6(address) if !self.flags().contains(AddressFlags::NoDad) => address,
+            _ => return self.add(socket),
+        };
+
+        // Join the group first, so that no notification is missed
+        let mut watcher = AddressWatcher::new()?;
+        self.add(socket)?;
+        watcher.wait(socket, self.index(), address, timeout)
+    }
+}
+
+/// A socket receiving the notifications about IPv6 addresses.
+struct AddressWatcher {
+    socket: Socket,
+}
+
+impl AddressWatcher {
+    fn new() -> IoResult<Self> {
+        let mut socket = Socket::connect_to_kernel(Protocol::Route)?;
+        socket.add_membership(RTNLGRP_IPV6_IFADDR)?;
+
+        Ok(Self { socket })
+    }
+
+    fn wait(
+        &mut self,
+        socket: &mut Socket,
+        index: u32,
+        address: Ipv6Addr,
+        timeout: Duration,
+    ) -> IoResult<()> {
+        let deadline = Instant::now() + timeout;
+
+        // DAD may have completed before the group was joined
+        let current = dump_addresses(socket, AddressFamily::Inet6, index)?;
+        if let Some(message) = current.iter().find(|x| is_address(x, index, address)) {
+            if dad_completed(message)? {
+                return Ok(());
+            }
+        }
+
+        let mut buffer = vec![0u8; 1 << 16];
+
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(IoError::new(IoErrorKind::TimedOut, "DAD did not complete in time"));
+            }
+
+            self.socket.set_read_timeout(Some(remaining))?;
+            let size = match self.socket.read(&mut buffer) {
+                Ok(size) => size,
+                Err(e) if matches!(e.kind(), IoErrorKind::WouldBlock | IoErrorKind::TimedOut) => {
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+
+            // A datagram may carry more than one message
+            let mut bytes = &buffer[..size];
+            while let Some(header) = Message::<()>::deserialize(bytes) {
+                let length = header.length() as usize;
+                if length < 16 || length > bytes.len() {
+                    break;
+                }
+
+                let message = &bytes[..length];
+                match header.message_type() {
+                    Type::Route(RouteType::NewAddress) => {
+                        if let Some(message) = Message::<NewAddress>::deserialize(message) {
+                            let message = &message.payload().0;
+                            if is_address(message, index, address) && dad_completed(message)? {
+                                return Ok(());
+                            }
+                        }
+                    }
+                    Type::Route(RouteType::DeleteAddress) => {
+                        if let Some(message) = Message::<DeleteAddress>::deserialize(message) {
+                            if is_address(&message.payload().0, index, address) {
+                                return Err(IoError::new(
+                                    IoErrorKind::AddrNotAvailable,
+                                    "the address was removed during DAD",
+                                ));
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+
+                let length = (align_attribute_len(length as i32) as usize).min(bytes.len());
+                bytes = &bytes[length..];
+            }
+        }
+    }
+}
+
+fn is_address(message: &InterfaceAddressMessage, index: u32, address: Ipv6Addr) -> bool {
+    let address = NetworkAddress::Ipv6(address);
+
+    message.index() == index
+        && message.family() == AddressFamily::Inet6
+        && message.attributes().iter().any(|x| match x {
+            InterfaceAddressAttribute::Address(x) | InterfaceAddressAttribute::Local(x) => {
+                *x == address
+            }
+            _ => false,
+        })
+}
+
+/// Whether the address can be used, failing if DAD found a duplicate.
+fn dad_completed(message: &InterfaceAddressMessage) -> IoResult<bool> {
+    let flags = message
+        .attributes()
+        .iter()
+        .find_map(|x| match x {
+            InterfaceAddressAttribute::Flags(flags) => Some(*flags),
+            _ => None,
+        })
+        .unwrap_or(message.flags());
+
+    if flags.contains(AddressFlags::DadFailed) {
+        return Err(IoError::new(IoErrorKind::AddrInUse, "DAD detected a duplicate address"));
+    }
+
+    Ok(!flags.contains(AddressFlags::Tentative) || flags.contains(AddressFlags::Optimistic))
+}
@@ -0,0 +1,183 @@
+use std::io::{Error as IoError, Result as IoResult};
+use std::net::IpAddr;
+
+use crate::netlink::{Flags, Message, Payload, ReceivedMessage, Socket};
+use crate::rtnetlink::{AddressFamily, RouteScope};
+
+use super::{
+    AddressFlags, GetAddress, GetAnycast, GetMulticast, InterfaceAddressAttribute,
+    InterfaceAddressMessage, NewAddress,
+};
+
+/// A multicast group joined by an interface, or an anycast address of an
+/// interface, as reported by `dump_multicast_groups` and
+/// `dump_anycast_addresses`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GroupAddress {
+    /// The index of the interface.
+    pub index: u32,
+    pub address: IpAddr,
+}
+
+/// Dumps the addresses of the given family, or of all families if it is
+/// `AddressFamily::Unspecified`, on the interface of the given index, or on all
+/// interfaces if it is 0.
+///
+/// The filtering is done by the kernel, which requires strict checking, so it
+/// is enabled on the socket for the duration of the dump. The previous setting
+/// is restored afterwards.
+pub fn dump_addresses(
+    socket: &mut Socket,
+    family: AddressFamily,
+    index: u32,
+) -> IoResult<Vec<InterfaceAddressMessage>> {
+    dump(socket, GetAddress(filter(family, index)), |x: NewAddress| x.0)
+}
+
+/// Dumps the multicast groups joined by the interface of the given index, or
+/// by all interfaces if it is 0, like `ip maddress` does. The family is either
+/// `AddressFamily::Inet`, `AddressFamily::Inet6` or `AddressFamily::Unspecified`
+/// for both.
+///
+/// IPv4 groups can only be dumped since Linux 6.13; older kernels fail with
+/// `EOPNOTSUPP`. With `AddressFamily::Unspecified`, only the IPv6 groups are
+/// returned by them.
+///
+/// Like `dump_addresses`, this enables strict checking on the socket for the
+/// duration of the dump.
+pub fn dump_multicast_groups(
+    socket: &mut Socket,
+    family: AddressFamily,
+    index: u32,
+) -> IoResult<Vec<GroupAddress>> {
+    // The kernel has no handler dumping the groups of all families at once
+    let families = match family {
+        AddressFamily::Unspecified => vec![AddressFamily::Inet, AddressFamily::Inet6],
+        family => vec![family],
+    };
+
+    let all_families = families.len() > 1;
+
+    let mut messages = Vec::new();
+    for family in families {
+        // The kernel replies to the dump with messages of the request type
+        let request = GetMulticast(filter(family, index));
+        match dump(socket, request, |x: GetMulticast| x.0) {
+            Ok(dumped) => messages.extend(dumped),
+            Err(error)
+                if all_families
+                    && family == AddressFamily::Inet
+                    && error.raw_os_error() == Some(libc::EOPNOTSUPP) => {}
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(group_addresses(messages, |x| match x {
+        InterfaceAddressAttribute::Multicast(address) => address.ip(),
+        _ => None,
+    }))
+}
+
+/// Dumps the IPv6 anycast addresses of the interface of the given index, or of
+/// all interfaces if it is 0.
+///
+/// Like `dump_addresses`, this enables strict checking on the socket for the
+/// duration of the dump.
+pub fn dump_anycast_addresses(socket: &mut Socket, index: u32) -> IoResult<Vec<GroupAddress>> {
+    let request = GetAnycast(filter(AddressFamily::Inet6, index));
+    let messages = dump(socket, request, |x: GetAnycast| x.0)?;
+
+    Ok(group_addresses(messages, |x| match x {
+        InterfaceAddressAttribute::Anycast(address) => address.ip(),
+        _ => None,
+    }))
+}
+
+fn filter(family: AddressFamily, index: u32) -> InterfaceAddressMessage {
+    InterfaceAddressMessage::new(
+        family,
+        0,
+        AddressFlags::empty(),
+        RouteScope::Universe,
+        index,
+        vec![],
+    )
+}
+
+fn dump<T, R>(
+    socket: &mut Socket,
+    request: T,
+    into_message: fn(R) -> InterfaceAddressMessage,
+) -> IoResult<Vec<InterfaceAddressMessage>>
+where
+    T: Payload,
+    R: Payload + std::fmt::Debug,
+{
+    let strict_checking = socket.strict_checking()?;
+    socket.set_strict_checking(true)?;
+
+    let result = socket
+        .send_message(&Message::new(Flags::Request | Flags::Dump, request))
+        .and_then(|_| socket.receive_message::<R>());
+
+    // The socket is handed back the way it was given, even if the dump failed
+    if !strict_checking {
+        socket.set_strict_checking(false)?;
+    }
+
+    match result? {
+        ReceivedMessage::Multipart(messages) => {
+            Ok(messages.into_iter().map(|x| into_message(x.into_payload())).collect())
+        }
+        ReceivedMessage::Message(message) => Ok(vec![into_message(message.into_payload())]),
+        ReceivedMessage::Error(error) => {
+            Err(IoError::from_raw_os_error(-error.payload().error_code()))
+        }
+    }
+}
+
+fn group_addresses(
+    messages: Vec<InterfaceAddressMessage>,
+    address: fn(&InterfaceAddressAttribute) -> Option<IpAddr>,
+) -> Vec<GroupAddress> {
+    messages
+        .iter()
+        .filter_map(|message| {
+            let address = message.attributes().iter().find_map(address)?;
+            Some(GroupAddress {
+                index: message.index(),
+                address,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn groups_of_dumped_messages() {
+        // struct ifaddrmsg for interface 3 followed by IFA_MULTICAST, as
+        // replied by the kernel to RTM_GETMULTICAST
+        let mut bytes = vec![libc::AF_INET as u8, 32, 0, 0];
+        bytes.extend(3u32.to_ne_bytes());
+        bytes.extend(8u16.to_ne_bytes());
+        bytes.extend(libc::IFA_MULTICAST.to_ne_bytes());
+        bytes.extend([224, 0, 0, 251]);
+
+        let messages = vec![
+            InterfaceAddressMessage::deserialize(&bytes).unwrap(),
+            InterfaceAddressMessage::deserialize(&bytes[..8]).unwrap(),
+        ];
+        let groups = group_addresses(messages, |x| match x {
+            InterfaceAddressAttribute::Multicast(address) => address.ip(),
+            _ => None,
+        });
+
+        // Messages without a group address are left out
+        let address = IpAddr::V4(Ipv4Addr::new(224, 0, 0, 251));
+        assert_eq!(groups, [GroupAddress { index: 3, address }]);
+    }
+}
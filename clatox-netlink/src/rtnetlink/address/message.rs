@@ -2,20 +2,24 @@ use libc::*;
 
 use crate::netlink::{Attribute, RawAttributeIter};
 use crate::netlink::{Payload, RouteType, Type};
-use crate::rtnetlink::AddressFamily;
+use crate::rtnetlink::{resolve_addresses, AddressFamily, RouteScope};
 use crate::utils::{align_attribute_len, read_u32};
 
 use super::{AddressFlags, InterfaceAddressAttribute};
 
 /// The message on which the `GetAddress`, `AddAddress`, `DeletAddress` requests
 /// are based on. It corresponds to the `ifaddrmsg` struct in libc.
+///
+/// For `GetAddress` dumps on a socket with strict checking enabled, a non-zero
+/// index only dumps the addresses of that interface; the prefix length, flags
+/// and scope must then be zero.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[doc(alias("ifaddrmsg"))]
 pub struct InterfaceAddressMessage {
     family: AddressFamily,
     prefixlen: u8,
     flags: AddressFlags,
-    scope: u8,
+    scope: RouteScope,
     index: u32,
     attributes: Vec<InterfaceAddressAttribute>,
 }
@@ -25,14 +29,16 @@ impl InterfaceAddressMessage {
         family: AddressFamily,
         prefixlen: u8,
         flags: AddressFlags,
+        scope: RouteScope,
+        index: u32,
         attributes: Vec<InterfaceAddressAttribute>,
     ) -> Self {
         Self {
             family: family,
             prefixlen,
             flags: flags,
-            scope: 0,
-            index: 0,
+            scope,
+            index,
             attributes,
         }
     }
@@ -41,6 +47,15 @@ impl InterfaceAddressMessage {
         self.family
     }
 
+    pub const fn prefix_len(&self) -> u8 {
+        self.prefixlen
+    }
+
+    pub const fn scope(&self) -> &RouteScope {
+        &self.scope
+    }
+
+    /// The index of the interface the address belongs to.
     pub const fn index(&self) -> u32 {
         self.index
     }
@@ -60,11 +75,10 @@ impl InterfaceAddressMessage {
         buffer.push(self.family.raw_value());
         buffer.push(self.prefixlen);
 
-        // If we have way too many flags in our hands, ignore them, make a new
-        // attribute carrying the flags instead
-        if self.flags.bits() <= u8::MAX as u32 {
-            buffer.push(self.flags.bits() as u8);
-        } else {
+        // If we have way too many flags in our hands, keep the ones that fit
+        // and make a new attribute carrying all of the flags instead
+        buffer.push(self.flags.bits() as u8);
+        if self.flags.bits() > u8::MAX as u32 {
             let has_flags = self
                 .attributes
                 .iter()
@@ -75,7 +89,7 @@ impl InterfaceAddressMessage {
                 serialize_flag = Some(self.flags);
             }
         }
-        buffer.push(self.scope);
+        buffer.push(self.scope.raw_value());
         buffer.extend(self.index.to_ne_bytes().into_iter());
 
         let aligned_len = (buffer.len() as i32 + NLA_ALIGNTO - 1) & !(NLA_ALIGNTO - 1);
@@ -112,6 +126,7 @@ impl InterfaceAddressMessage {
 
         let family = AddressFamily::from_raw_value(family)?;
         let flags = AddressFlags::from_bits(flags as u32)?;
+        let scope = RouteScope::from_raw_value(scope);
 
         // We have read 8 bytes so far. Align it to NLA_ALIGNTO bytes and start
         // deserializing the attributes.
@@ -120,10 +135,12 @@ impl InterfaceAddressMessage {
             iter.next()?;
         }
 
-        let attributes = RawAttributeIter::new(iter)
+        let mut attributes: Vec<_> = RawAttributeIter::new(iter)
             .map(InterfaceAddressAttribute::from_raw)
             .try_collect()?;
 
+        resolve_addresses(family, &mut attributes);
+
         Some(InterfaceAddressMessage {
             family,
             prefixlen,
@@ -188,3 +205,138 @@ impl Payload for DeleteAddress {
         Some(Self(InterfaceAddressMessage::deserialize(bytes)?))
     }
 }
+
+/// A message that is of the `RTM_GETMULTICAST` type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetMulticast(pub InterfaceAddressMessage);
+
+impl Payload for GetMulticast {
+    fn message_type() -> Type {
+        Type::Route(RouteType::GetMulticast)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(InterfaceAddressMessage::deserialize(bytes)?))
+    }
+}
+
+/// A message that is of the `RTM_NEWMULTICAST` type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewMulticast(pub InterfaceAddressMessage);
+
+impl Payload for NewMulticast {
+    fn message_type() -> Type {
+        Type::Route(RouteType::NewMulticast)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(InterfaceAddressMessage::deserialize(bytes)?))
+    }
+}
+
+/// A message that is of the `RTM_GETANYCAST` type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetAnycast(pub InterfaceAddressMessage);
+
+impl Payload for GetAnycast {
+    fn message_type() -> Type {
+        Type::Route(RouteType::GetAnycast)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(InterfaceAddressMessage::deserialize(bytes)?))
+    }
+}
+
+/// A message that is of the `RTM_NEWANYCAST` type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewAnycast(pub InterfaceAddressMessage);
+
+impl Payload for NewAnycast {
+    fn message_type() -> Type {
+        Type::Route(RouteType::NewAnycast)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(InterfaceAddressMessage::deserialize(bytes)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rtnetlink::{AddressProtocol, NetworkAddress, IFAPROT_KERNEL_RA, IFA_PROTO};
+    use std::net::Ipv6Addr;
+
+    #[test]
+    fn addresses_follow_the_family() {
+        let address: Ipv6Addr = "2001:db8::1".parse().unwrap();
+
+        // struct ifaddrmsg of 2001:db8::1/64 on interface 2, followed by
+        // IFA_ADDRESS
+        let mut bytes = vec![AF_INET6 as u8, 64, 0, RT_SCOPE_UNIVERSE];
+        bytes.extend(2u32.to_ne_bytes());
+        bytes.extend(20u16.to_ne_bytes());
+        bytes.extend(IFA_ADDRESS.to_ne_bytes());
+        bytes.extend(address.octets());
+
+        let message = InterfaceAddressMessage::deserialize(&bytes).unwrap();
+        assert_eq!(message.family(), AddressFamily::Inet6);
+        assert_eq!(message.index(), 2);
+        assert_eq!(
+            message.attributes(),
+            [InterfaceAddressAttribute::Address(NetworkAddress::Ipv6(address))]
+        );
+        assert_eq!(&message.serialize()[..], &bytes[..]);
+    }
+
+    #[test]
+    fn unknown_scope() {
+        // struct ifaddrmsg with a scope of /etc/iproute2/rt_scopes
+        let mut bytes = vec![AF_INET as u8, 24, 0, 100];
+        bytes.extend(2u32.to_ne_bytes());
+
+        let message = InterfaceAddressMessage::deserialize(&bytes).unwrap();
+        assert_eq!(message.scope(), &RouteScope::Other(100));
+        assert_eq!(&message.serialize()[..], &bytes[..]);
+    }
+
+    #[test]
+    fn address_protocols() {
+        // struct ifaddrmsg followed by IFA_PROTO of a router advertisement,
+        // and by one of /etc/iproute2/rt_addrprotos, padded to 4 bytes
+        let mut bytes = vec![AF_INET6 as u8, 64, 0, RT_SCOPE_UNIVERSE];
+        bytes.extend(2u32.to_ne_bytes());
+        for protocol in [IFAPROT_KERNEL_RA, 77] {
+            bytes.extend(5u16.to_ne_bytes());
+            bytes.extend(IFA_PROTO.to_ne_bytes());
+            bytes.extend([protocol, 0, 0, 0]);
+        }
+
+        let message = InterfaceAddressMessage::deserialize(&bytes).unwrap();
+        assert_eq!(
+            message.attributes(),
+            [
+                InterfaceAddressAttribute::Protocol(AddressProtocol::KernelRouterAdvertisement),
+                InterfaceAddressAttribute::Protocol(AddressProtocol::Other(77)),
+            ]
+        );
+        assert_eq!(&message.serialize()[..], &bytes[..]);
+    }
+}
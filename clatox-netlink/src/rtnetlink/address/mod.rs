@@ -1,7 +1,11 @@
 mod attribute;
 mod cache_info;
+mod dad;
+mod dump;
 mod flag;
 mod message;
+mod protocol;
+mod request;
 
 #[doc(inline)]
 pub use attribute::*;
@@ -9,8 +13,20 @@ pub use attribute::*;
 #[doc(inline)]
 pub use cache_info::*;
 
+#[doc(inline)]
+pub use dad::*;
+
+#[doc(inline)]
+pub use dump::*;
+
 #[doc(inline)]
 pub use flag::*;
 
 #[doc(inline)]
 pub use message::*;
+
+#[doc(inline)]
+pub use protocol::*;
+
+#[doc(inline)]
+pub use request::*;
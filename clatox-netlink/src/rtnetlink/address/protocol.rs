@@ -0,0 +1,51 @@
+pub const IFAPROT_UNSPEC: u8 = 0;
+pub const IFAPROT_KERNEL_LO: u8 = 1;
+pub const IFAPROT_KERNEL_RA: u8 = 2;
+pub const IFAPROT_KERNEL_LL: u8 = 3;
+
+/// The protocol which installed an address, as sent in `IFA_PROTO`.
+#[doc(alias("IFA_PROTO", "IFAPROT_"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AddressProtocol {
+    /// `IFAPROT_UNSPEC`
+    Unspecified,
+
+    /// `IFAPROT_KERNEL_LO`, the loopback address
+    KernelLoopback,
+
+    /// `IFAPROT_KERNEL_RA`, an address configured from a router advertisement
+    KernelRouterAdvertisement,
+
+    /// `IFAPROT_KERNEL_LL`, a link-local address
+    KernelLinkLocal,
+
+    /// A protocol without a name of its own, e.g. one of those named in
+    /// `/etc/iproute2/rt_addrprotos`.
+    Other(u8),
+}
+
+impl AddressProtocol {
+    pub fn from_raw_value(value: u8) -> Self {
+        use AddressProtocol::*;
+
+        match value {
+            IFAPROT_UNSPEC => Unspecified,
+            IFAPROT_KERNEL_LO => KernelLoopback,
+            IFAPROT_KERNEL_RA => KernelRouterAdvertisement,
+            IFAPROT_KERNEL_LL => KernelLinkLocal,
+            x => Other(x),
+        }
+    }
+
+    pub fn raw_value(&self) -> u8 {
+        use AddressProtocol::*;
+
+        match self {
+            Unspecified => IFAPROT_UNSPEC,
+            KernelLoopback => IFAPROT_KERNEL_LO,
+            KernelRouterAdvertisement => IFAPROT_KERNEL_RA,
+            KernelLinkLocal => IFAPROT_KERNEL_LL,
+            Other(x) => *x,
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use std::io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult};
+use std::net::{IpAddr, Ipv4Addr};
+use std::time::Duration;
+
+use crate::netlink::{Flags, Socket};
+use crate::rtnetlink::{AddressFamily, RouteScope};
+
+use super::{
+    AddressCacheInfo, AddressFlags, AddressProtocol, DeleteAddress, InterfaceAddressAttribute,
+    InterfaceAddressMessage, NewAddress, INFINITY_LIFE_TIME,
+};
+
+/// An address to be added to, replaced on or deleted from an interface.
+///
+/// The `IFA_LOCAL`, `IFA_ADDRESS` and `IFA_BROADCAST` attributes are derived
+/// from the address like `ip address add` does: all addresses get `IFA_LOCAL`
+/// and `IFA_ADDRESS`, and IPv4 addresses also get `IFA_BROADCAST`, the last
+/// address of the prefix (except for /31 and /32 prefixes).
+///
+/// By default, the address never expires.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressRequest {
+    index: u32,
+    address: IpAddr,
+    prefix_len: u8,
+    flags: AddressFlags,
+    scope: RouteScope,
+    valid_lifetime: Option<Duration>,
+    preferred_lifetime: Option<Duration>,
+    route_priority: Option<u32>,
+    protocol: Option<AddressProtocol>,
+    label: Option<String>,
+}
+
+impl AddressRequest {
+    pub const fn new(index: u32, address: IpAddr, prefix_len: u8) -> Self {
+        Self {
+            index,
+            address,
+            prefix_len,
+            flags: AddressFlags::empty(),
+            scope: RouteScope::Universe,
+            valid_lifetime: None,
+            preferred_lifetime: None,
+            route_priority: None,
+            protocol: None,
+            label: None,
+        }
+    }
+
+    /// Sets the flags of the address, e.g. `AddressFlags::NoDad` or
+    /// `AddressFlags::NoPrefixRoute`.
+    pub fn with_flags(mut self, flags: AddressFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Sets the scope of the address. The default is `RouteScope::Universe`,
+    /// i.e. a global address; `ip` uses `RouteScope::Host` for loopback
+    /// addresses.
+    pub fn with_scope(mut self, scope: RouteScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Removes the address once the given time has passed. It is rounded up
+    /// to seconds, and must not be zero.
+    ///
+    /// Without a preferred lifetime, the address is preferred until it is
+    /// removed.
+    pub fn with_valid_lifetime(mut self, lifetime: Duration) -> Self {
+        self.valid_lifetime = Some(lifetime);
+        self
+    }
+
+    /// Deprecates the address once the given time has passed, after which it
+    /// is no longer picked as a source address. It is rounded up to seconds,
+    /// and must not exceed the valid lifetime. A zero lifetime adds the address
+    /// as deprecated.
+    pub fn with_preferred_lifetime(mut self, lifetime: Duration) -> Self {
+        self.preferred_lifetime = Some(lifetime);
+        self
+    }
+
+    /// Sets the metric of the prefix route created for the address.
+    pub fn with_route_priority(mut self, priority: u32) -> Self {
+        self.route_priority = Some(priority);
+        self
+    }
+
+    /// Sets the protocol which installed the address, usually a user defined
+    /// one, as the others are used by the kernel.
+    pub fn with_protocol(mut self, protocol: AddressProtocol) -> Self {
+        self.protocol = Some(protocol);
+        self
+    }
+
+    /// Sets the label of an IPv4 address, which has to start with the name of
+    /// the interface, e.g. `eth0:1`.
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_owned());
+        self
+    }
+
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub const fn address(&self) -> IpAddr {
+        self.address
+    }
+
+    pub const fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    pub const fn flags(&self) -> AddressFlags {
+        self.flags
+    }
+
+    pub const fn scope(&self) -> &RouteScope {
+        &self.scope
+    }
+
+    /// Builds the message of the request.
+    ///
+    /// Fails with `InvalidInput` if the prefix length exceeds the length of
+    /// the address, if the valid lifetime is zero or if the preferred lifetime
+    /// exceeds the valid one.
+    pub fn to_message(&self) -> IoResult<InterfaceAddressMessage> {
+        let mut attributes = Vec::new();
+
+        let family = match self.address {
+            IpAddr::V4(address) => {
+                if self.prefix_len > 32 {
+                    return Err(invalid_prefix_len());
+                }
+
+                attributes.push(InterfaceAddressAttribute::Local(address.into()));
+                attributes.push(InterfaceAddressAttribute::Address(address.into()));
+
+                if self.prefix_len < 31 {
+                    let host_mask = u32::MAX >> self.prefix_len;
+                    let broadcast = Ipv4Addr::from(u32::from(address) | host_mask);
+                    attributes.push(InterfaceAddressAttribute::Broadcast(broadcast.into()));
+                }
+
+                AddressFamily::Inet
+            }
+            IpAddr::V6(address) => {
+                if self.prefix_len > 128 {
+                    return Err(invalid_prefix_len());
+                }
+
+                attributes.push(InterfaceAddressAttribute::Local(address.into()));
+                attributes.push(InterfaceAddressAttribute::Address(address.into()));
+
+                AddressFamily::Inet6
+            }
+        };
+
+        if let Some(label) = &self.label {
+            attributes.push(InterfaceAddressAttribute::Label(label.clone()));
+        }
+
+        if self.valid_lifetime.is_some() || self.preferred_lifetime.is_some() {
+            // The kernel counts in whole seconds, so partial ones are rounded
+            // up rather than expiring the address right away
+            let seconds = |lifetime: Option<Duration>| {
+                lifetime.map_or(INFINITY_LIFE_TIME, |x| {
+                    let seconds = x.as_secs() + (x.subsec_nanos() > 0) as u64;
+                    seconds.min(INFINITY_LIFE_TIME as u64 - 1) as u32
+                })
+            };
+
+            let valid = seconds(self.valid_lifetime);
+            let preferred = match self.preferred_lifetime {
+                Some(_) => seconds(self.preferred_lifetime),
+                None => valid,
+            };
+
+            if valid == 0 {
+                return Err(IoError::new(IoErrorKind::InvalidInput, "zero valid lifetime"));
+            }
+            if preferred > valid {
+                return Err(IoError::new(
+                    IoErrorKind::InvalidInput,
+                    "preferred lifetime exceeds the valid lifetime",
+                ));
+            }
+
+            attributes.push(InterfaceAddressAttribute::CacheInfo(AddressCacheInfo {
+                preferred,
+                valid,
+                created_timestamp: 0,
+                updated_timestamp: 0,
+            }));
+        }
+
+        if let Some(priority) = self.route_priority {
+            attributes.push(InterfaceAddressAttribute::RoutePriority(priority));
+        }
+
+        if let Some(protocol) = &self.protocol {
+            attributes.push(InterfaceAddressAttribute::Protocol(protocol.clone()));
+        }
+
+        // The flags that do not fit into ifa_flags are sent as IFA_FLAGS by
+        // InterfaceAddressMessage::serialize()
+        Ok(InterfaceAddressMessage::new(
+            family,
+            self.prefix_len,
+            self.flags,
+            self.scope.clone(),
+            self.index,
+            attributes,
+        ))
+    }
+
+    /// Adds the address to the interface. Fails with `EEXIST` if the
+    /// interface already has it.
+    pub fn add(&self, socket: &mut Socket) -> IoResult<()> {
+        let flags = Flags::Create | Flags::Excl;
+        socket.send_acknowledged(flags, NewAddress(self.to_message()?))
+    }
+
+    /// Adds the address to the interface, or updates its flags, lifetimes
+    /// and other properties if the interface already has it.
+    pub fn replace(&self, socket: &mut Socket) -> IoResult<()> {
+        let flags = Flags::Create | Flags::Replace;
+        socket.send_acknowledged(flags, NewAddress(self.to_message()?))
+    }
+
+    /// Deletes the address from the interface. Fails with `EADDRNOTAVAIL` if
+    /// the interface does not have it.
+    pub fn delete(&self, socket: &mut Socket) -> IoResult<()> {
+        socket.send_acknowledged(Flags::empty(), DeleteAddress(self.to_message()?))
+    }
+}
+
+fn invalid_prefix_len() -> IoError {
+    IoError::new(IoErrorKind::InvalidInput, "prefix length exceeds the address length")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rtnetlink::NetworkAddress;
+    use std::net::Ipv6Addr;
+
+    fn cache_info(request: &AddressRequest) -> IoResult<Option<AddressCacheInfo>> {
+        let message = request.to_message()?;
+        Ok(message.attributes().iter().find_map(|attr| match attr {
+            InterfaceAddressAttribute::CacheInfo(info) => Some(info.clone()),
+            _ => None,
+        }))
+    }
+
+    #[test]
+    fn lifetimes() {
+        let request = AddressRequest::new(1, "192.0.2.10".parse().unwrap(), 24);
+        assert_eq!(cache_info(&request).unwrap(), None);
+
+        // The preferred lifetime defaults to the valid one
+        let info = cache_info(&request.clone().with_valid_lifetime(Duration::from_secs(60)));
+        let info = info.unwrap().unwrap();
+        assert_eq!((info.preferred, info.valid), (60, 60));
+
+        let info = cache_info(&request.clone().with_preferred_lifetime(Duration::ZERO));
+        let info = info.unwrap().unwrap();
+        assert_eq!((info.preferred, info.valid), (0, INFINITY_LIFE_TIME));
+
+        // Partial seconds are rounded up
+        let info = cache_info(&request.clone().with_valid_lifetime(Duration::from_millis(1500)));
+        assert_eq!(info.unwrap().unwrap().valid, 2);
+
+        let invalid = request
+            .clone()
+            .with_valid_lifetime(Duration::from_secs(10))
+            .with_preferred_lifetime(Duration::from_secs(20));
+        assert_eq!(cache_info(&invalid).unwrap_err().kind(), IoErrorKind::InvalidInput);
+
+        let invalid = request.with_valid_lifetime(Duration::ZERO);
+        assert_eq!(cache_info(&invalid).unwrap_err().kind(), IoErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn derived_attributes() {
+        let address = Ipv4Addr::new(192, 0, 2, 10);
+        let message = AddressRequest::new(1, address.into(), 24).to_message().unwrap();
+        assert_eq!(message.family(), AddressFamily::Inet);
+        assert_eq!(
+            message.attributes(),
+            [
+                InterfaceAddressAttribute::Local(NetworkAddress::Ipv4(address)),
+                InterfaceAddressAttribute::Address(NetworkAddress::Ipv4(address)),
+                InterfaceAddressAttribute::Broadcast(NetworkAddress::Ipv4(Ipv4Addr::new(
+                    192, 0, 2, 255
+                ))),
+            ]
+        );
+
+        // IPv6 addresses have no broadcast address
+        let address: Ipv6Addr = "2001:db8::1".parse().unwrap();
+        let message = AddressRequest::new(1, address.into(), 64)
+            .with_protocol(AddressProtocol::Other(77))
+            .to_message()
+            .unwrap();
+        assert_eq!(message.family(), AddressFamily::Inet6);
+        assert_eq!(
+            message.attributes(),
+            [
+                InterfaceAddressAttribute::Local(NetworkAddress::Ipv6(address)),
+                InterfaceAddressAttribute::Address(NetworkAddress::Ipv6(address)),
+                InterfaceAddressAttribute::Protocol(AddressProtocol::Other(77)),
+            ]
+        );
+    }
+}
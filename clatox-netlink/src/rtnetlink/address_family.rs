@@ -1,5 +1,8 @@
 use libc::*;
 
+pub const RTNL_FAMILY_IPMR: u8 = 128;
+pub const RTNL_FAMILY_IP6MR: u8 = 129;
+
 /// Address families used in network communication.
 ///
 /// Corresponds to `AF_*` in libc.
@@ -62,6 +65,10 @@ pub enum AddressFamily {
     PPPoX,
     WanPipe,
     Llc,
+
+    /// Multiprotocol Label Switching
+    Mpls,
+
     Can,
     Tipc,
     Bluetooth,
@@ -75,6 +82,14 @@ pub enum AddressFamily {
     Nfc,
     Vsock,
     Xdp,
+
+    /// `RTNL_FAMILY_IPMR` - the IPv4 multicast routing tables and rules,
+    /// which are only known to rtnetlink.
+    Ipmr,
+
+    /// `RTNL_FAMILY_IP6MR` - the IPv6 multicast routing tables and rules,
+    /// which are only known to rtnetlink.
+    Ip6mr,
 }
 
 impl AddressFamily {
@@ -109,6 +124,7 @@ impl AddressFamily {
             PPPoX => AF_PPPOX,
             WanPipe => AF_WANPIPE,
             Llc => AF_LLC,
+            Mpls => AF_MPLS,
             Can => AF_CAN,
             Tipc => AF_TIPC,
             Bluetooth => AF_BLUETOOTH,
@@ -122,6 +138,8 @@ impl AddressFamily {
             Nfc => AF_NFC,
             Vsock => AF_VSOCK,
             Xdp => AF_XDP,
+            Ipmr => RTNL_FAMILY_IPMR as c_int,
+            Ip6mr => RTNL_FAMILY_IP6MR as c_int,
         };
 
         value as u8
@@ -159,6 +177,7 @@ impl AddressFamily {
             AF_PPPOX => PPPoX,
             AF_WANPIPE => WanPipe,
             AF_LLC => Llc,
+            AF_MPLS => Mpls,
             AF_CAN => Can,
             AF_TIPC => Tipc,
             AF_BLUETOOTH => Bluetooth,
@@ -172,6 +191,8 @@ impl AddressFamily {
             AF_NFC => Nfc,
             AF_VSOCK => Vsock,
             AF_XDP => Xdp,
+            x if x == RTNL_FAMILY_IPMR as i32 => Ipmr,
+            x if x == RTNL_FAMILY_IP6MR as i32 => Ip6mr,
             _ => None?
         })
     }
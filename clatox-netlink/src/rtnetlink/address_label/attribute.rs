@@ -0,0 +1,70 @@
+use std::net::Ipv6Addr;
+
+use crate::netlink::{Attribute, RawAttribute};
+use crate::utils;
+
+pub const IFAL_UNSPEC: u16 = 0;
+pub const IFAL_ADDRESS: u16 = 1;
+pub const IFAL_LABEL: u16 = 2;
+
+/// Attributes of an address label message. They correspond to `IFAL_*` in
+/// <linux/if_addrlabel.h>.
+#[doc(alias("ifaddrlblmsg", "IFAL_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddressLabelAttribute {
+    /// `IFAL_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `IFAL_ADDRESS` - the prefix the label applies to.
+    Address(Ipv6Addr),
+
+    /// `IFAL_LABEL`
+    Label(u32),
+
+    Other(u16, Vec<u8>),
+}
+
+impl AddressLabelAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                IFAL_UNSPEC
+            }
+            Self::Address(address) => {
+                buffer.extend(address.octets());
+                IFAL_ADDRESS
+            }
+            Self::Label(label) => {
+                buffer.extend(label.to_ne_bytes());
+                IFAL_LABEL
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for AddressLabelAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            IFAL_UNSPEC => Self::Unspecified(content),
+            IFAL_ADDRESS => {
+                let content = <[u8; 16]>::try_from(content).ok()?;
+                Self::Address(Ipv6Addr::from(content))
+            }
+            IFAL_LABEL => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Label(u32::from_ne_bytes(content))
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
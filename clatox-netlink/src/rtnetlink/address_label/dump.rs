@@ -0,0 +1,21 @@
+use std::io::{Error as IoError, Result as IoResult};
+
+use crate::netlink::{Flags, Message, ReceivedMessage, Socket};
+
+use super::{AddressLabelMessage, GetAddressLabel, NewAddressLabel};
+
+/// Dumps the IPv6 address label table, like `ip addrlabel list` does.
+pub fn dump_address_labels(socket: &mut Socket) -> IoResult<Vec<AddressLabelMessage>> {
+    let request = GetAddressLabel(AddressLabelMessage::new(0, 0, vec![]));
+    socket.send_message(&Message::new(Flags::Request | Flags::Dump, request))?;
+
+    match socket.receive_message::<NewAddressLabel>()? {
+        ReceivedMessage::Multipart(messages) => {
+            Ok(messages.into_iter().map(|x| x.into_payload().0).collect())
+        }
+        ReceivedMessage::Message(message) => Ok(vec![message.into_payload().0]),
+        ReceivedMessage::Error(error) => {
+            Err(IoError::from_raw_os_error(-error.payload().error_code()))
+        }
+    }
+}
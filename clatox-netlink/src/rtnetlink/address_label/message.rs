@@ -0,0 +1,228 @@
+use std::net::Ipv6Addr;
+
+use crate::netlink::{Attribute, RawAttributeIter};
+use crate::netlink::{Payload, RouteType, Type};
+use crate::rtnetlink::AddressFamily;
+use crate::utils::{align_attribute_len, read_u32};
+
+use super::AddressLabelAttribute;
+
+/// The message on which the `GetAddressLabel`, `NewAddressLabel` and
+/// `DeleteAddressLabel` requests are based on. It corresponds to the
+/// `ifaddrlblmsg` struct in <linux/if_addrlabel.h>.
+///
+/// Each message is an entry of the IPv6 policy table of RFC 6724, which
+/// assigns a label to the addresses of a prefix, optionally only on the
+/// interface of the given index. Source address selection prefers source
+/// addresses whose label matches the one of the destination, so an address
+/// with a label of its own is only picked for destinations of that label.
+///
+/// `NewAddressLabel` and `DeleteAddressLabel` requests need both the `Address`
+/// and the `Label` attribute, see `AddressLabelRequest`. The whole table is
+/// dumped by `dump_address_labels`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[doc(alias("ifaddrlblmsg"))]
+pub struct AddressLabelMessage {
+    family: AddressFamily,
+    prefix_len: u8,
+    flags: u8,
+    index: u32,
+    seq: u32,
+    attributes: Vec<AddressLabelAttribute>,
+}
+
+impl AddressLabelMessage {
+    pub const fn new(prefix_len: u8, index: u32, attributes: Vec<AddressLabelAttribute>) -> Self {
+        Self {
+            family: AddressFamily::Inet6,
+            prefix_len,
+            flags: 0,
+            index,
+            seq: 0,
+            attributes,
+        }
+    }
+
+    pub const fn family(&self) -> AddressFamily {
+        self.family
+    }
+
+    pub const fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// The index of the interface the entry is restricted to, or 0.
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// The sequence number of the table, which changes with every
+    /// modification.
+    pub const fn seq(&self) -> u32 {
+        self.seq
+    }
+
+    pub fn attributes(&self) -> &[AddressLabelAttribute] {
+        &self.attributes
+    }
+
+    /// The `Address` attribute, i.e. the prefix of the entry.
+    pub fn address(&self) -> Option<Ipv6Addr> {
+        self.attributes.iter().find_map(|x| match x {
+            AddressLabelAttribute::Address(address) => Some(*address),
+            _ => None,
+        })
+    }
+
+    /// The `Label` attribute.
+    pub fn label(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|x| match x {
+            AddressLabelAttribute::Label(label) => Some(*label),
+            _ => None,
+        })
+    }
+
+    pub fn serialize(&self) -> Box<[u8]> {
+        let mut buffer = Vec::with_capacity(12);
+        buffer.push(self.family.raw_value());
+        buffer.push(0u8); // __ifal_reserved
+        buffer.push(self.prefix_len);
+        buffer.push(self.flags);
+        buffer.extend(self.index.to_ne_bytes());
+        buffer.extend(self.seq.to_ne_bytes());
+
+        for attr in &self.attributes {
+            attr.serialize_into(&mut buffer)
+        }
+
+        buffer.into_boxed_slice()
+    }
+
+    pub fn deserialize(bytes: &[u8]) -> Option<Self> {
+        // The header is 12 bytes. If the data we receive is shorter than that
+        // it's not going to be valid
+        if bytes.len() < 12 {
+            return None;
+        }
+
+        let mut iter = bytes.iter().cloned();
+
+        let family = iter.next()?;
+        let _reserved = iter.next()?;
+        let prefix_len = iter.next()?;
+        let flags = iter.next()?;
+        let index = read_u32(iter.by_ref())?;
+        let seq = read_u32(iter.by_ref())?;
+
+        let family = AddressFamily::from_raw_value(family)?;
+
+        // We have read 12 bytes so far. Align it to NLA_ALIGNTO bytes and start
+        // deserializing the attributes.
+        let aligned_len = align_attribute_len(12);
+        for _ in 0..(aligned_len - 12) {
+            iter.next()?;
+        }
+
+        let attributes = RawAttributeIter::new(iter)
+            .map(AddressLabelAttribute::from_raw)
+            .try_collect()?;
+
+        Some(AddressLabelMessage {
+            family,
+            prefix_len,
+            flags,
+            index,
+            seq,
+            attributes,
+        })
+    }
+}
+
+/// A message that is of the `RTM_GETADDRLABEL` type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetAddressLabel(pub AddressLabelMessage);
+
+impl Payload for GetAddressLabel {
+    fn message_type() -> Type {
+        Type::Route(RouteType::GetAddressLabel)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(AddressLabelMessage::deserialize(bytes)?))
+    }
+}
+
+/// A message that is of the `RTM_NEWADDRLABEL` type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewAddressLabel(pub AddressLabelMessage);
+
+impl Payload for NewAddressLabel {
+    fn message_type() -> Type {
+        Type::Route(RouteType::NewAddressLabel)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(AddressLabelMessage::deserialize(bytes)?))
+    }
+}
+
+/// A message that is of the `RTM_DELADDRLABEL` type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeleteAddressLabel(pub AddressLabelMessage);
+
+impl Payload for DeleteAddressLabel {
+    fn message_type() -> Type {
+        Type::Route(RouteType::DeleteAddressLabel)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(AddressLabelMessage::deserialize(bytes)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rtnetlink::{IFAL_ADDRESS, IFAL_LABEL};
+
+    #[test]
+    fn table_entry_layout() {
+        let address: Ipv6Addr = "2001:db8::".parse().unwrap();
+
+        // struct ifaddrlblmsg of 2001:db8::/32 on interface 2 with sequence
+        // number 7, followed by IFAL_ADDRESS and IFAL_LABEL
+        let mut bytes = vec![libc::AF_INET6 as u8, 0, 32, 0];
+        bytes.extend(2u32.to_ne_bytes());
+        bytes.extend(7u32.to_ne_bytes());
+        bytes.extend(20u16.to_ne_bytes());
+        bytes.extend(IFAL_ADDRESS.to_ne_bytes());
+        bytes.extend(address.octets());
+        bytes.extend(8u16.to_ne_bytes());
+        bytes.extend(IFAL_LABEL.to_ne_bytes());
+        bytes.extend(100u32.to_ne_bytes());
+
+        let message = AddressLabelMessage::deserialize(&bytes).unwrap();
+        assert_eq!(message.prefix_len(), 32);
+        assert_eq!(message.index(), 2);
+        assert_eq!(message.seq(), 7);
+        assert_eq!(message.address(), Some(address));
+        assert_eq!(message.label(), Some(100));
+
+        // Requests are sent with a sequence number of 0
+        let message = AddressLabelMessage::new(32, 2, message.attributes().to_vec());
+        bytes[8..12].copy_from_slice(&0u32.to_ne_bytes());
+        assert_eq!(&message.serialize()[..], &bytes[..]);
+    }
+}
@@ -0,0 +1,16 @@
+mod attribute;
+mod dump;
+mod message;
+mod request;
+
+#[doc(inline)]
+pub use attribute::*;
+
+#[doc(inline)]
+pub use dump::*;
+
+#[doc(inline)]
+pub use message::*;
+
+#[doc(inline)]
+pub use request::*;
@@ -0,0 +1,128 @@
+use std::io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult};
+use std::net::Ipv6Addr;
+
+use crate::netlink::{Flags, Socket};
+
+use super::{AddressLabelAttribute, AddressLabelMessage, DeleteAddressLabel, NewAddressLabel};
+
+/// The label reserved by the kernel for the addresses matching no entry.
+const IPV6_ADDR_LABEL_DEFAULT: u32 = 0xffff_ffff;
+
+/// An entry of the IPv6 address label table to be added, replaced or deleted,
+/// like `ip addrlabel` does.
+///
+/// Entries are matched by their prefix and interface, so the label of an
+/// entry to be deleted does not have to match the one of the table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressLabelRequest {
+    address: Ipv6Addr,
+    prefix_len: u8,
+    label: u32,
+    index: u32,
+}
+
+impl AddressLabelRequest {
+    /// An entry assigning the label to the addresses of the IPv6 prefix of the
+    /// given address and length, on all interfaces.
+    pub const fn new(address: Ipv6Addr, prefix_len: u8, label: u32) -> Self {
+        Self {
+            address,
+            prefix_len,
+            label,
+            index: 0,
+        }
+    }
+
+    /// Restricts the entry to the interface of the given index.
+    pub fn with_index(mut self, index: u32) -> Self {
+        self.index = index;
+        self
+    }
+
+    pub const fn address(&self) -> Ipv6Addr {
+        self.address
+    }
+
+    pub const fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    pub const fn label(&self) -> u32 {
+        self.label
+    }
+
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Builds the message of the request.
+    ///
+    /// Fails with `InvalidInput` if the prefix length exceeds 128, or if the
+    /// label is the one reserved by the kernel.
+    pub fn to_message(&self) -> IoResult<AddressLabelMessage> {
+        if self.prefix_len > 128 {
+            return Err(IoError::new(
+                IoErrorKind::InvalidInput,
+                "the prefix length exceeds 128",
+            ));
+        }
+
+        if self.label == IPV6_ADDR_LABEL_DEFAULT {
+            return Err(IoError::new(
+                IoErrorKind::InvalidInput,
+                "the label is reserved by the kernel",
+            ));
+        }
+
+        let attributes = vec![
+            AddressLabelAttribute::Address(self.address),
+            AddressLabelAttribute::Label(self.label),
+        ];
+
+        Ok(AddressLabelMessage::new(self.prefix_len, self.index, attributes))
+    }
+
+    /// Adds the entry. Fails with `EEXIST` if there is an entry of the prefix
+    /// and interface.
+    pub fn add(&self, socket: &mut Socket) -> IoResult<()> {
+        let flags = Flags::Create | Flags::Excl;
+        socket.send_acknowledged(flags, NewAddressLabel(self.to_message()?))
+    }
+
+    /// Adds the entry, or replaces the label of the entry of the prefix and
+    /// interface.
+    pub fn replace(&self, socket: &mut Socket) -> IoResult<()> {
+        let flags = Flags::Create | Flags::Replace;
+        socket.send_acknowledged(flags, NewAddressLabel(self.to_message()?))
+    }
+
+    /// Deletes the entry of the prefix and interface. Fails with `ESRCH` if
+    /// there is none.
+    pub fn delete(&self, socket: &mut Socket) -> IoResult<()> {
+        socket.send_acknowledged(Flags::empty(), DeleteAddressLabel(self.to_message()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_message() {
+        let address: Ipv6Addr = "2001:db8::".parse().unwrap();
+        let message = AddressLabelRequest::new(address, 32, 100)
+            .with_index(2)
+            .to_message()
+            .unwrap();
+        assert_eq!(message.prefix_len(), 32);
+        assert_eq!(message.index(), 2);
+        assert_eq!(message.address(), Some(address));
+        assert_eq!(message.label(), Some(100));
+
+        let request = AddressLabelRequest::new(address, 129, 100);
+        assert_eq!(request.to_message().unwrap_err().kind(), IoErrorKind::InvalidInput);
+
+        let request = AddressLabelRequest::new(address, 32, IPV6_ADDR_LABEL_DEFAULT);
+        assert_eq!(request.to_message().unwrap_err().kind(), IoErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt;
+use std::io::{Error as IoError, ErrorKind as IoErrorKind};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use super::AddressFamily;
+
+/// An IP address along with a prefix length, like `192.0.2.0/24` or
+/// `64:ff9b::/96`, e.g. the destination of a route.
+///
+/// The address is kept as given, so the host bits may be set. `network()`
+/// returns the prefix with them cleared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IpPrefix {
+    address: IpAddr,
+    prefix_len: u8,
+}
+
+impl IpPrefix {
+    /// Returns `None` if the prefix length exceeds the length of the address.
+    pub const fn new(address: IpAddr, prefix_len: u8) -> Option<Self> {
+        let max_len = match address {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+
+        if prefix_len > max_len {
+            return None;
+        }
+
+        Some(Self {
+            address,
+            prefix_len,
+        })
+    }
+
+    /// The prefix of a single address, i.e. `/32` or `/128`.
+    pub const fn host(address: IpAddr) -> Self {
+        let prefix_len = match address {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+
+        Self {
+            address,
+            prefix_len,
+        }
+    }
+
+    /// The prefix matching every address of the family, i.e. `0.0.0.0/0` or
+    /// `::/0`.
+    pub const fn default_route(family: AddressFamily) -> Option<Self> {
+        let address = match family {
+            AddressFamily::Inet => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            AddressFamily::Inet6 => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+            _ => return None,
+        };
+
+        Some(Self {
+            address,
+            prefix_len: 0,
+        })
+    }
+
+    pub const fn address(&self) -> IpAddr {
+        self.address
+    }
+
+    pub const fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    pub const fn family(&self) -> AddressFamily {
+        match self.address {
+            IpAddr::V4(_) => AddressFamily::Inet,
+            IpAddr::V6(_) => AddressFamily::Inet6,
+        }
+    }
+
+    /// The prefix with the host bits of the address cleared.
+    pub fn network(&self) -> Self {
+        let address = match self.address {
+            IpAddr::V4(address) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix_len as u32).unwrap_or(0);
+                IpAddr::V4(Ipv4Addr::from(u32::from(address) & mask))
+            }
+            IpAddr::V6(address) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix_len as u32).unwrap_or(0);
+                IpAddr::V6(Ipv6Addr::from(u128::from(address) & mask))
+            }
+        };
+
+        Self {
+            address,
+            prefix_len: self.prefix_len,
+        }
+    }
+
+    /// Whether the address is part of the prefix.
+    pub fn contains(&self, address: IpAddr) -> bool {
+        match Self::new(address, self.prefix_len) {
+            Some(other) => other.network() == self.network(),
+            None => false,
+        }
+    }
+}
+
+impl From<IpAddr> for IpPrefix {
+    fn from(address: IpAddr) -> Self {
+        Self::host(address)
+    }
+}
+
+/// Parses a prefix like `192.0.2.0/24`. An address without a prefix length is
+/// a host prefix. Fails with `InvalidInput`.
+impl FromStr for IpPrefix {
+    type Err = IoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || IoError::new(IoErrorKind::InvalidInput, "invalid IP prefix");
+
+        let (address, prefix_len) = match s.split_once('/') {
+            Some((address, prefix_len)) => (address, Some(prefix_len)),
+            None => (s, None),
+        };
+
+        let address: IpAddr = address.parse().map_err(|_| invalid())?;
+
+        match prefix_len {
+            Some(prefix_len) => {
+                let prefix_len = prefix_len.parse().map_err(|_| invalid())?;
+                Self::new(address, prefix_len).ok_or_else(invalid)
+            }
+            None => Ok(Self::host(address)),
+        }
+    }
+}
+
+impl fmt::Display for IpPrefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.address, self.prefix_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let prefix: IpPrefix = "192.0.2.1/24".parse().unwrap();
+        assert_eq!(prefix.address(), IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)));
+        assert_eq!(prefix.prefix_len(), 24);
+        assert_eq!(prefix.network().to_string(), "192.0.2.0/24");
+
+        let prefix: IpPrefix = "2001:db8::1".parse().unwrap();
+        assert_eq!(prefix.to_string(), "2001:db8::1/128");
+
+        assert!("192.0.2.0/33".parse::<IpPrefix>().is_err());
+        assert!("192.0.2.0/".parse::<IpPrefix>().is_err());
+        assert!("2001:db8::/129".parse::<IpPrefix>().is_err());
+    }
+
+    #[test]
+    fn network() {
+        let prefix: IpPrefix = "64:ff9b::1:2/96".parse().unwrap();
+        assert_eq!(prefix.network().to_string(), "64:ff9b::/96");
+        assert!(prefix.contains("64:ff9b::c000:201".parse().unwrap()));
+        assert!(!prefix.contains("64:ff9c::1".parse().unwrap()));
+        assert!(!prefix.contains("192.0.2.1".parse().unwrap()));
+
+        let default = IpPrefix::default_route(AddressFamily::Inet).unwrap();
+        assert_eq!(default.network(), default);
+        assert!(default.contains("198.51.100.1".parse().unwrap()));
+        assert_eq!(IpPrefix::default_route(AddressFamily::Bridge), None);
+    }
+}
@@ -0,0 +1,243 @@
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use super::ArpHardware;
+
+/// A 48-bit IEEE 802 MAC address, as used by Ethernet and most wireless
+/// interfaces.
+///
+/// It is displayed and parsed in the canonical `xx:xx:xx:xx:xx:xx` form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct MacAddress(pub [u8; 6]);
+
+impl MacAddress {
+    pub const fn octets(&self) -> [u8; 6] {
+        self.0
+    }
+
+    pub const fn is_multicast(&self) -> bool {
+        self.0[0] & 0x01 != 0
+    }
+
+    pub const fn is_broadcast(&self) -> bool {
+        let [a, b, c, d, e, f] = self.0;
+        a & b & c & d & e & f == 0xFF
+    }
+}
+
+impl From<[u8; 6]> for MacAddress {
+    fn from(octets: [u8; 6]) -> Self {
+        Self(octets)
+    }
+}
+
+impl fmt::Display for MacAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(f, "{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{g:02x}")
+    }
+}
+
+/// The error returned when parsing a `MacAddress` fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MacAddressParseError;
+
+impl fmt::Display for MacAddressParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid MAC address syntax")
+    }
+}
+
+impl std::error::Error for MacAddressParseError {}
+
+impl FromStr for MacAddress {
+    type Err = MacAddressParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut octets = [0u8; 6];
+        let mut parts = s.split(':');
+
+        for octet in octets.iter_mut() {
+            let part = parts.next().ok_or(MacAddressParseError)?;
+            if part.is_empty() || part.len() > 2 {
+                return Err(MacAddressParseError);
+            }
+            *octet = u8::from_str_radix(part, 16).map_err(|_| MacAddressParseError)?;
+        }
+
+        match parts.next() {
+            Some(_) => Err(MacAddressParseError),
+            None => Ok(Self(octets)),
+        }
+    }
+}
+
+/// The L2 address of a link, interpreted according to its `ArpHardware`
+/// device type.
+///
+/// Addresses that do not have the length expected for their device type are
+/// kept as `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HardwareAddress {
+    /// A MAC address, used by Ethernet, Wi-Fi and loopback devices.
+    Mac(MacAddress),
+
+    /// A 20-byte InfiniBand address: the queue pair number followed by the
+    /// GID of the port.
+    InfiniBand([u8; 20]),
+
+    /// The local or remote IPv4 endpoint of a tunnel, e.g. of an IPIP, GRE or
+    /// SIT (IPv6-in-IPv4) tunnel. It is unspecified if the tunnel is not bound
+    /// to an endpoint.
+    Ipv4(Ipv4Addr),
+
+    /// The local or remote IPv6 endpoint of an IPv6 tunnel.
+    Ipv6(Ipv6Addr),
+
+    /// Links without a L2 address, e.g. TUN devices.
+    None,
+
+    Other(Vec<u8>),
+}
+
+impl HardwareAddress {
+    /// Interprets the content of `IFLA_ADDRESS`, `IFLA_BROADCAST` or
+    /// `IFLA_PERM_ADDRESS` for a link of the given device type.
+    pub fn from_bytes(device_type: ArpHardware, bytes: &[u8]) -> Self {
+        match Self::kind(device_type) {
+            AddressKind::Mac => match <[u8; 6]>::try_from(bytes) {
+                Ok(octets) => Self::Mac(MacAddress(octets)),
+                Err(_) => Self::Other(bytes.to_vec()),
+            },
+            AddressKind::InfiniBand => match <[u8; 20]>::try_from(bytes) {
+                Ok(octets) => Self::InfiniBand(octets),
+                Err(_) => Self::Other(bytes.to_vec()),
+            },
+            AddressKind::Ipv4 => match <[u8; 4]>::try_from(bytes) {
+                Ok(octets) => Self::Ipv4(Ipv4Addr::from(octets)),
+                Err(_) => Self::Other(bytes.to_vec()),
+            },
+            AddressKind::Ipv6 => match <[u8; 16]>::try_from(bytes) {
+                Ok(octets) => Self::Ipv6(Ipv6Addr::from(octets)),
+                Err(_) => Self::Other(bytes.to_vec()),
+            },
+            AddressKind::None if bytes.is_empty() => Self::None,
+            _ => Self::Other(bytes.to_vec()),
+        }
+    }
+
+    /// Parses an address written in the canonical form of the given device
+    /// type, i.e. the form produced by `Display`.
+    pub fn parse(device_type: ArpHardware, s: &str) -> Result<Self, HardwareAddressParseError> {
+        let address = match Self::kind(device_type) {
+            AddressKind::Mac => Self::Mac(s.parse().map_err(|_| HardwareAddressParseError)?),
+            AddressKind::InfiniBand => {
+                let bytes = parse_hex_octets(s)?;
+                Self::InfiniBand(bytes.try_into().map_err(|_| HardwareAddressParseError)?)
+            }
+            AddressKind::Ipv4 => Self::Ipv4(s.parse().map_err(|_| HardwareAddressParseError)?),
+            AddressKind::Ipv6 => Self::Ipv6(s.parse().map_err(|_| HardwareAddressParseError)?),
+            AddressKind::None if s.is_empty() => Self::None,
+            AddressKind::None => return Err(HardwareAddressParseError),
+            AddressKind::Other => Self::Other(parse_hex_octets(s)?),
+        };
+
+        Ok(address)
+    }
+
+    /// The address as sent in `IFLA_ADDRESS`, `IFLA_BROADCAST` or
+    /// `IFLA_PERM_ADDRESS`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::Mac(address) => address.octets().to_vec(),
+            Self::InfiniBand(octets) => octets.to_vec(),
+            Self::Ipv4(address) => address.octets().to_vec(),
+            Self::Ipv6(address) => address.octets().to_vec(),
+            Self::None => Vec::new(),
+            Self::Other(bytes) => bytes.clone(),
+        }
+    }
+
+    fn kind(device_type: ArpHardware) -> AddressKind {
+        use ArpHardware::*;
+
+        match device_type {
+            Ethernet | ExperimentalEthernet | Loopback | Ieee80211 | Ieee80211Prism
+            | Ieee80211RadioTap => AddressKind::Mac,
+            InfiniBand => AddressKind::InfiniBand,
+            Tunnel | Sit | IpGre => AddressKind::Ipv4,
+            Tunnel6 | Ipv6Gre => AddressKind::Ipv6,
+            None | RawIp | Void | Ppp => AddressKind::None,
+            _ => AddressKind::Other,
+        }
+    }
+}
+
+enum AddressKind {
+    Mac,
+    InfiniBand,
+    Ipv4,
+    Ipv6,
+    None,
+    Other,
+}
+
+fn parse_hex_octets(s: &str) -> Result<Vec<u8>, HardwareAddressParseError> {
+    s.split(':')
+        .map(|part| match part.len() {
+            1 | 2 => u8::from_str_radix(part, 16).map_err(|_| HardwareAddressParseError),
+            _ => Err(HardwareAddressParseError),
+        })
+        .collect()
+}
+
+/// MAC addresses are displayed as `xx:xx:xx:xx:xx:xx`, InfiniBand and other
+/// addresses as colon-separated hexadecimal octets, tunnel endpoints as IP
+/// addresses. Links without an address are displayed as an empty string.
+impl fmt::Display for HardwareAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_octets = |f: &mut fmt::Formatter<'_>, octets: &[u8]| {
+            let octets: Vec<_> = octets.iter().map(|x| format!("{x:02x}")).collect();
+            f.write_str(&octets.join(":"))
+        };
+
+        match self {
+            Self::Mac(address) => address.fmt(f),
+            Self::InfiniBand(octets) => write_octets(f, octets),
+            Self::Ipv4(address) => address.fmt(f),
+            Self::Ipv6(address) => address.fmt(f),
+            Self::None => Ok(()),
+            Self::Other(bytes) => write_octets(f, bytes),
+        }
+    }
+}
+
+/// The error returned when parsing a `HardwareAddress` fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HardwareAddressParseError;
+
+impl fmt::Display for HardwareAddressParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid hardware address syntax")
+    }
+}
+
+impl std::error::Error for HardwareAddressParseError {}
+
+/// An opaque identifier of a physical port or switch, as reported in
+/// `IFLA_PHYS_PORT_ID` and `IFLA_PHYS_SWITCH_ID`. Its content is defined by
+/// the driver and at most 32 bytes long.
+///
+/// It is displayed as a plain hexadecimal string, like `ip` does.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PhysicalId(pub Vec<u8>);
+
+impl fmt::Display for PhysicalId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
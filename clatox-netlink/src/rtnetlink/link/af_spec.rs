@@ -1,22 +1,25 @@
 use libc::*;
 
-use crate::netlink::{Attribute, RawAttribute};
+use crate::netlink::{Attribute, RawAttribute, RawAttributeIter};
 use crate::utils;
 
+use super::{Inet6Attribute, InetAttribute};
+
 /// Address-family specific information of a link interface.
 ///
 /// Corresponds to `IFLA_AFSPEC` and `IFLA_INET` in libc.
 ///
-/// TODO: Much of this remains to be typed.
+/// To change the IPv4 configuration, or the IPv6 token or address generation
+/// mode of a link, send them in an `RTM_SETLINK` request.
 #[doc(alias("ifinfomsg", "IFLA_AFSPEC"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[repr(u32)]
 pub enum AddressFamilySpecific {
     /// `IFLA_INET_*`
-    Inet(Vec<u8>),
+    Inet(Vec<InetAttribute>),
 
     /// `IFLA_INET6_*`
-    Inet6(Vec<u8>),
+    Inet6(Vec<Inet6Attribute>),
 
     /// Unrecognized address families
     Other(u8, Vec<u8>),
@@ -25,12 +28,12 @@ pub enum AddressFamilySpecific {
 impl AddressFamilySpecific {
     pub fn serialize_in(&self, buffer: &mut Vec<u8>) {
         utils::serialize_attribute_into(buffer, |buffer| match self {
-            Self::Inet(content) => {
-                buffer.extend(content.iter());
+            Self::Inet(attrs) => {
+                attrs.iter().for_each(|a| a.serialize_into(buffer));
                 AF_INET as u16
             }
-            Self::Inet6(content) => {
-                buffer.extend(content.iter());
+            Self::Inet6(attrs) => {
+                attrs.iter().for_each(|a| a.serialize_into(buffer));
                 AF_INET6 as u16
             }
             Self::Other(typ, content) => {
@@ -47,8 +50,20 @@ impl Attribute for AddressFamilySpecific {
         let content = raw.into_payload();
 
         let attr = match attr_type as i32 {
-            AF_INET => Self::Inet(content),
-            AF_INET6 => Self::Inet6(content),
+            AF_INET => {
+                let attrs = RawAttributeIter::new(content.iter().cloned())
+                    .map(InetAttribute::from_raw)
+                    .try_collect()?;
+
+                Self::Inet(attrs)
+            }
+            AF_INET6 => {
+                let attrs = RawAttributeIter::new(content.iter().cloned())
+                    .map(Inet6Attribute::from_raw)
+                    .try_collect()?;
+
+                Self::Inet6(attrs)
+            }
             typ => Self::Other(typ as u8, content),
         };
 
@@ -6,11 +6,15 @@ use crate::netlink::{Attribute, RawAttribute, RawAttributeIter};
 use crate::utils;
 
 use super::stats::{InterfaceStats, InterfaceStats64};
-use super::{AddressFamilySpecific, LinkInfo};
+use super::{AddressFamilySpecific, HardwareAddress, InterfaceEvent, InterfaceMap, LinkInfo};
+use super::{LinkMode, OperState, PhysicalId, ProtocolDownReasonAttribute, ProtocolInfo};
+use super::XdpAttribute;
 
 /// Those attributes are to be used with `InterfaceInfoMessage`s. They
 /// correspond to `IFLA_*` in libc.
 ///
+/// Unknown attributes are kept as `Other`.
+///
 /// TODO: The enum is complete, but some values are still untyped.
 #[doc(alias("ifinfomsg", "ifi_type", "IFLA_"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -20,10 +24,14 @@ pub enum InterfaceInfoAttribute {
     Unspecified(Vec<u8>),
 
     /// `IFLA_ADDRESS` - the L2 address of an interface.
-    Address(Vec<u8>),
+    ///
+    /// The addresses can only be interpreted with the device type of the
+    /// interface known, so they are decoded as `HardwareAddress::Other` here
+    /// and interpreted by `InterfaceInfoMessage::deserialize`.
+    Address(HardwareAddress),
 
     /// `IFLA_BROADCAST` - the L2 broadcast address of an interface.
-    Broadcast(Vec<u8>),
+    Broadcast(HardwareAddress),
 
     /// `IFLA_IFNAME` - the name of an interface.
     InterfaceName(String),
@@ -41,34 +49,38 @@ pub enum InterfaceInfoAttribute {
     Stats(InterfaceStats),
 
     /// `IFLA_COST`
-    Cost(Vec<u8>),
+    Cost(u32),
 
     /// `IFLA_PRIORITY`
-    Priority(Vec<u8>),
+    Priority(u32),
 
-    /// `IFLA_MASTER`
-    Master(Vec<u8>),
+    /// `IFLA_MASTER` - the index of the master interface (e.g. a bridge or a
+    /// bond). Setting it to 0 releases the interface from its master.
+    Master(i32),
 
     /// `IFLA_WIRELESS`
     Wireless(Vec<u8>),
 
     /// `IFLA_PROTINFO`
-    ProtocolInfo(Vec<u8>),
+    ProtocolInfo(ProtocolInfo),
 
     /// `IFLA_TXQLEN`
     TxQueueLength(u32),
 
-    /// `IFLA_MAP`
-    Map(Vec<u8>),
+    /// `IFLA_MAP` - the hardware resources of an interface.
+    Map(InterfaceMap),
 
     /// `IFLA_WEIGHT`
-    Weight(Vec<u8>),
+    Weight(u32),
 
-    /// `IFLA_OPERSTATE`
-    OperationalState(u8),
+    /// `IFLA_OPERSTATE` - the RFC 2863 operational state of an interface.
+    OperationalState(OperState),
 
-    /// `IFLA_LINKMODE`
-    LinkMode(u8),
+    /// `IFLA_LINKMODE` - how the operational state of an interface is
+    /// determined. Set it to `LinkMode::Dormant` together with
+    /// `OperationalState(OperState::Dormant)` to take over the operational
+    /// state of a link managed from userspace.
+    LinkMode(LinkMode),
 
     /// `IFLA_LINKINFO`
     LinkInfo(Vec<LinkInfo>),
@@ -115,26 +127,32 @@ pub enum InterfaceInfoAttribute {
     /// `IFLA_NUM_RX_QUEUES`
     NumRxQueues(u32),
 
-    /// `IFLA_CARRIER`
-    Carrier(Vec<u8>),
+    /// `IFLA_CARRIER` - whether the interface has a carrier. It can only be
+    /// changed for some virtual devices, e.g. `veth` peers.
+    Carrier(bool),
 
-    /// `IFLA_PHYS_PORT_ID`
-    PhysicalPortId(Vec<u8>),
+    /// `IFLA_PHYS_PORT_ID` - the identifier of the physical port of an
+    /// interface, shared by the interfaces using the same port.
+    PhysicalPortId(PhysicalId),
 
     /// `IFLA_CARRIER_CHANGES`
     CarrierChanges(u32),
 
-    /// `IFLA_PHYS_SWITCH_ID`
-    PhysicalSwitchId(Vec<u8>),
+    /// `IFLA_PHYS_SWITCH_ID` - the identifier of the switch an interface
+    /// belongs to, shared by the ports of the same switch ASIC.
+    PhysicalSwitchId(PhysicalId),
 
-    /// `IFLA_LINK_NETNSID`
-    LinkNetNamespaceId(Vec<u8>),
+    /// `IFLA_LINK_NETNSID` - the id of the network namespace the `Link` of an
+    /// interface (e.g. the peer of a `veth`) lives in, relative to the
+    /// namespace of the socket.
+    LinkNetNamespaceId(i32),
 
-    /// `IFLA_PHYS_PORT_NAME`
-    PhysicalPortName(Vec<u8>),
+    /// `IFLA_PHYS_PORT_NAME` - the name of the physical port, e.g. `p0`.
+    PhysicalPortName(String),
 
-    /// `IFLA_PROTO_DOWN`
-    ProtocolDown(Vec<u8>),
+    /// `IFLA_PROTO_DOWN` - whether the interface is put down by a control
+    /// protocol, independently of its administrative state.
+    ProtocolDown(bool),
 
     /// `IFLA_GSO_MAX_SEGS`
     GsoMaxSegments(u32),
@@ -145,11 +163,11 @@ pub enum InterfaceInfoAttribute {
     /// `IFLA_PAD`
     Pad(Vec<u8>),
 
-    /// `IFLA_XDP`
-    Xdp(Vec<u8>),
+    /// `IFLA_XDP` - the XDP programs attached to an interface.
+    Xdp(Vec<XdpAttribute>),
 
-    /// `IFLA_EVENT`
-    Event(Vec<u8>),
+    /// `IFLA_EVENT` - the reason of a link notification.
+    Event(InterfaceEvent),
 
     /// `IFLA_NEW_NETNSID`
     NewNetNamespaceId(Vec<u8>),
@@ -175,17 +193,21 @@ pub enum InterfaceInfoAttribute {
     /// `IFLA_MAX_MTU`
     MaxMTU(u32),
 
-    /// `IFLA_PROP_LIST`
-    PropertiesList(Vec<u8>),
+    /// `IFLA_PROP_LIST` - the properties of a link that can be added or
+    /// deleted one by one with `NewLinkProperty` and `DeleteLinkProperty`.
+    /// Currently these are only `AlternativeName`s.
+    PropertiesList(Vec<InterfaceInfoAttribute>),
 
-    /// `IFLA_ALT_IFNAME`
-    AlternativeName(Vec<u8>),
+    /// `IFLA_ALT_IFNAME` - an alternative name of an interface, which unlike
+    /// `InterfaceName` may be up to 127 bytes long.
+    AlternativeName(String),
 
-    /// `IFLA_PERM_ADDRESS`
-    PermanentAddress(Vec<u8>),
+    /// `IFLA_PERM_ADDRESS` - the permanent L2 address of an interface, as
+    /// burnt in by the manufacturer.
+    PermanentAddress(HardwareAddress),
 
-    /// `IFLA_PROTO_DOWN_REASON`
-    ProtocolDownReason(Vec<u8>),
+    /// `IFLA_PROTO_DOWN_REASON` - the reasons why `ProtocolDown` is set.
+    ProtocolDownReason(Vec<ProtocolDownReasonAttribute>),
 
     /// `IFLA_PARENT_DEV_NAME`
     ParentDeviceName(String),
@@ -204,6 +226,8 @@ pub enum InterfaceInfoAttribute {
 
     /// `IFLA_ALLMULTI`
     AllMulti(u32),
+
+    Other(u16, Vec<u8>),
 }
 
 impl InterfaceInfoAttribute {
@@ -215,12 +239,12 @@ impl InterfaceInfoAttribute {
             }
 
             InterfaceInfoAttribute::Address(addr) => {
-                buffer.extend(addr.iter().cloned());
+                buffer.extend(addr.to_bytes());
                 IFLA_ADDRESS
             }
 
             InterfaceInfoAttribute::Broadcast(addr) => {
-                buffer.extend(addr.iter().cloned());
+                buffer.extend(addr.to_bytes());
                 IFLA_BROADCAST
             }
 
@@ -256,18 +280,18 @@ impl InterfaceInfoAttribute {
                 IFLA_STATS
             }
 
-            InterfaceInfoAttribute::Cost(content) => {
-                buffer.extend(content.iter().cloned());
+            InterfaceInfoAttribute::Cost(cost) => {
+                buffer.extend(cost.to_ne_bytes());
                 IFLA_COST
             }
 
-            InterfaceInfoAttribute::Priority(content) => {
-                buffer.extend(content.iter().cloned());
+            InterfaceInfoAttribute::Priority(priority) => {
+                buffer.extend(priority.to_ne_bytes());
                 IFLA_PRIORITY
             }
 
-            InterfaceInfoAttribute::Master(content) => {
-                buffer.extend(content.iter().cloned());
+            InterfaceInfoAttribute::Master(master) => {
+                buffer.extend(master.to_ne_bytes());
                 IFLA_MASTER
             }
 
@@ -276,9 +300,14 @@ impl InterfaceInfoAttribute {
                 IFLA_WIRELESS
             }
 
-            InterfaceInfoAttribute::ProtocolInfo(content) => {
-                buffer.extend(content.iter().cloned());
-                IFLA_PROTINFO
+            InterfaceInfoAttribute::ProtocolInfo(info) => {
+                info.serialize_into(buffer);
+                match info {
+                    // The bridge treats IFLA_PROTINFO without NLA_F_NESTED as
+                    // a legacy, single-byte port state.
+                    ProtocolInfo::Bridge(_) => IFLA_PROTINFO | NLA_F_NESTED as u16,
+                    ProtocolInfo::Other(_) => IFLA_PROTINFO,
+                }
             }
 
             InterfaceInfoAttribute::TxQueueLength(content) => {
@@ -286,23 +315,23 @@ impl InterfaceInfoAttribute {
                 IFLA_TXQLEN
             }
 
-            InterfaceInfoAttribute::Map(content) => {
-                buffer.extend(content.iter().cloned());
+            InterfaceInfoAttribute::Map(map) => {
+                map.serialize_into(buffer);
                 IFLA_MAP
             }
 
-            InterfaceInfoAttribute::Weight(content) => {
-                buffer.extend(content.iter().cloned());
+            InterfaceInfoAttribute::Weight(weight) => {
+                buffer.extend(weight.to_ne_bytes());
                 IFLA_WEIGHT
             }
 
-            InterfaceInfoAttribute::OperationalState(content) => {
-                buffer.extend(content.to_ne_bytes().into_iter());
+            InterfaceInfoAttribute::OperationalState(state) => {
+                buffer.push(state.raw_value());
                 IFLA_OPERSTATE
             }
 
-            InterfaceInfoAttribute::LinkMode(content) => {
-                buffer.extend(content.to_ne_bytes().into_iter());
+            InterfaceInfoAttribute::LinkMode(mode) => {
+                buffer.push(mode.raw_value());
                 IFLA_LINKMODE
             }
 
@@ -393,13 +422,13 @@ impl InterfaceInfoAttribute {
                 IFLA_NUM_RX_QUEUES
             }
 
-            InterfaceInfoAttribute::Carrier(content) => {
-                buffer.extend(content.iter().cloned());
+            InterfaceInfoAttribute::Carrier(carrier) => {
+                buffer.push(*carrier as u8);
                 IFLA_CARRIER
             }
 
-            InterfaceInfoAttribute::PhysicalPortId(content) => {
-                buffer.extend(content.iter().cloned());
+            InterfaceInfoAttribute::PhysicalPortId(id) => {
+                buffer.extend(id.0.iter());
                 IFLA_PHYS_PORT_ID
             }
 
@@ -408,23 +437,24 @@ impl InterfaceInfoAttribute {
                 IFLA_CARRIER_CHANGES
             }
 
-            InterfaceInfoAttribute::PhysicalSwitchId(content) => {
-                buffer.extend(content.iter().cloned());
+            InterfaceInfoAttribute::PhysicalSwitchId(id) => {
+                buffer.extend(id.0.iter());
                 IFLA_PHYS_SWITCH_ID
             }
 
-            InterfaceInfoAttribute::LinkNetNamespaceId(content) => {
-                buffer.extend(content.iter().cloned());
+            InterfaceInfoAttribute::LinkNetNamespaceId(id) => {
+                buffer.extend(id.to_ne_bytes());
                 IFLA_LINK_NETNSID
             }
 
-            InterfaceInfoAttribute::PhysicalPortName(content) => {
-                buffer.extend(content.iter().cloned());
+            InterfaceInfoAttribute::PhysicalPortName(name) => {
+                buffer.extend(name.bytes());
+                buffer.push(0u8); // zero-terminated string
                 IFLA_PHYS_PORT_NAME
             }
 
-            InterfaceInfoAttribute::ProtocolDown(content) => {
-                buffer.extend(content.iter().cloned());
+            InterfaceInfoAttribute::ProtocolDown(down) => {
+                buffer.push(*down as u8);
                 IFLA_PROTO_DOWN
             }
 
@@ -443,13 +473,13 @@ impl InterfaceInfoAttribute {
                 IFLA_PAD
             }
 
-            InterfaceInfoAttribute::Xdp(content) => {
-                buffer.extend(content.iter().cloned());
-                IFLA_XDP
+            InterfaceInfoAttribute::Xdp(attrs) => {
+                attrs.iter().for_each(|a| a.serialize_into(buffer));
+                IFLA_XDP | NLA_F_NESTED as u16
             }
 
-            InterfaceInfoAttribute::Event(content) => {
-                buffer.extend(content.iter().cloned());
+            InterfaceInfoAttribute::Event(event) => {
+                buffer.extend(event.raw_value().to_ne_bytes());
                 IFLA_EVENT
             }
 
@@ -493,23 +523,26 @@ impl InterfaceInfoAttribute {
                 IFLA_MAX_MTU
             }
 
-            InterfaceInfoAttribute::PropertiesList(content) => {
-                buffer.extend(content.iter().cloned());
-                IFLA_PROP_LIST
+            InterfaceInfoAttribute::PropertiesList(properties) => {
+                for property in properties {
+                    property.serialize_into(buffer);
+                }
+                IFLA_PROP_LIST | NLA_F_NESTED as u16
             }
 
-            InterfaceInfoAttribute::AlternativeName(content) => {
-                buffer.extend(content.iter().cloned());
+            InterfaceInfoAttribute::AlternativeName(name) => {
+                buffer.extend(name.bytes());
+                buffer.push(0u8); // zero terminated string
                 IFLA_ALT_IFNAME
             }
 
-            InterfaceInfoAttribute::PermanentAddress(content) => {
-                buffer.extend(content.iter().cloned());
+            InterfaceInfoAttribute::PermanentAddress(address) => {
+                buffer.extend(address.to_bytes());
                 IFLA_PERM_ADDRESS
             }
 
-            InterfaceInfoAttribute::ProtocolDownReason(content) => {
-                buffer.extend(content.iter().cloned());
+            InterfaceInfoAttribute::ProtocolDownReason(attrs) => {
+                attrs.iter().for_each(|a| a.serialize_into(buffer));
                 IFLA_PROTO_DOWN_REASON
             }
 
@@ -544,6 +577,11 @@ impl InterfaceInfoAttribute {
                 buffer.extend(content.to_ne_bytes().into_iter());
                 IFLA_ALLMULTI
             }
+
+            InterfaceInfoAttribute::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
         });
     }
 
@@ -561,8 +599,8 @@ impl Attribute for InterfaceInfoAttribute {
 
         let attr = match attr_type {
             IFLA_UNSPEC => InterfaceInfoAttribute::Unspecified(content),
-            IFLA_ADDRESS => InterfaceInfoAttribute::Address(content),
-            IFLA_BROADCAST => InterfaceInfoAttribute::Broadcast(content),
+            IFLA_ADDRESS => InterfaceInfoAttribute::Address(HardwareAddress::Other(content)),
+            IFLA_BROADCAST => InterfaceInfoAttribute::Broadcast(HardwareAddress::Other(content)),
             IFLA_IFNAME => {
                 let mut content = String::from_utf8(content).ok()?;
                 let zero = content.pop();
@@ -593,30 +631,39 @@ impl Attribute for InterfaceInfoAttribute {
                 };
                 InterfaceInfoAttribute::Stats(stats)
             }
-            IFLA_COST => InterfaceInfoAttribute::Cost(content),
-            IFLA_PRIORITY => InterfaceInfoAttribute::Priority(content),
-            IFLA_MASTER => InterfaceInfoAttribute::Master(content),
+            IFLA_COST => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Cost(u32::from_ne_bytes(content))
+            }
+            IFLA_PRIORITY => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Priority(u32::from_ne_bytes(content))
+            }
+            IFLA_MASTER => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                InterfaceInfoAttribute::Master(i32::from_ne_bytes(content))
+            }
             IFLA_WIRELESS => InterfaceInfoAttribute::Wireless(content),
-            IFLA_PROTINFO => InterfaceInfoAttribute::ProtocolInfo(content),
+            IFLA_PROTINFO => InterfaceInfoAttribute::ProtocolInfo(ProtocolInfo::Other(content)),
             IFLA_TXQLEN => {
                 let content = <[u8; 4]>::try_from(content).ok()?;
                 InterfaceInfoAttribute::TxQueueLength(u32::from_ne_bytes(content))
             }
-            IFLA_MAP => InterfaceInfoAttribute::Map(content),
-            IFLA_WEIGHT => InterfaceInfoAttribute::Weight(content),
+            IFLA_MAP => Self::Map(InterfaceMap::deserialize(&content)?),
+            IFLA_WEIGHT => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Weight(u32::from_ne_bytes(content))
+            }
             IFLA_OPERSTATE => {
-                let content = content.get(0)?;
-                InterfaceInfoAttribute::OperationalState(*content)
+                let content = content.first()?;
+                InterfaceInfoAttribute::OperationalState(OperState::from_raw_value(*content)?)
             }
             IFLA_LINKMODE => {
-                let content = content.get(0)?;
-                InterfaceInfoAttribute::LinkMode(*content)
+                let content = content.first()?;
+                InterfaceInfoAttribute::LinkMode(LinkMode::from_raw_value(*content)?)
             }
             IFLA_LINKINFO => {
-                let infos = RawAttributeIter::new(content.iter().cloned())
-                    .map(LinkInfo::from_raw)
-                    .try_collect()?;
-
+                let infos = LinkInfo::deserialize_list(&content)?;
                 InterfaceInfoAttribute::LinkInfo(infos)
             }
             IFLA_NET_NS_PID => InterfaceInfoAttribute::NetNamespacePid(content),
@@ -668,16 +715,31 @@ impl Attribute for InterfaceInfoAttribute {
                 let content = <[u8; 4]>::try_from(content).ok()?;
                 InterfaceInfoAttribute::NumRxQueues(u32::from_ne_bytes(content))
             }
-            IFLA_CARRIER => Self::Carrier(content),
-            IFLA_PHYS_PORT_ID => Self::PhysicalPortId(content),
+            IFLA_CARRIER => {
+                let content = <[u8; 1]>::try_from(content).ok()?;
+                Self::Carrier(content[0] != 0)
+            }
+            IFLA_PHYS_PORT_ID => Self::PhysicalPortId(PhysicalId(content)),
             IFLA_CARRIER_CHANGES => {
                 let content = <[u8; 4]>::try_from(content).ok()?;
                 InterfaceInfoAttribute::CarrierChanges(u32::from_ne_bytes(content))
             }
-            IFLA_PHYS_SWITCH_ID => Self::PhysicalSwitchId(content),
-            IFLA_LINK_NETNSID => Self::LinkNetNamespaceId(content),
-            IFLA_PHYS_PORT_NAME => Self::PhysicalPortName(content),
-            IFLA_PROTO_DOWN => Self::ProtocolDown(content),
+            IFLA_PHYS_SWITCH_ID => Self::PhysicalSwitchId(PhysicalId(content)),
+            IFLA_LINK_NETNSID => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::LinkNetNamespaceId(i32::from_ne_bytes(content))
+            }
+            IFLA_PHYS_PORT_NAME => {
+                let mut content = String::from_utf8(content).ok()?;
+                if content.ends_with('\0') {
+                    content.pop();
+                }
+                Self::PhysicalPortName(content)
+            }
+            IFLA_PROTO_DOWN => {
+                let content = <[u8; 1]>::try_from(content).ok()?;
+                Self::ProtocolDown(content[0] != 0)
+            }
             IFLA_GSO_MAX_SEGS => {
                 let content = <[u8; 4]>::try_from(content).ok()?;
                 InterfaceInfoAttribute::GsoMaxSegments(u32::from_ne_bytes(content))
@@ -687,8 +749,17 @@ impl Attribute for InterfaceInfoAttribute {
                 InterfaceInfoAttribute::GsoMaxSize(u32::from_ne_bytes(content))
             }
             IFLA_PAD => Self::Pad(content),
-            IFLA_XDP => Self::Xdp(content),
-            IFLA_EVENT => Self::Event(content),
+            IFLA_XDP => {
+                let attrs = RawAttributeIter::new(content.iter().cloned())
+                    .map(XdpAttribute::from_raw)
+                    .try_collect()?;
+
+                Self::Xdp(attrs)
+            }
+            IFLA_EVENT => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Event(InterfaceEvent::from_raw_value(u32::from_ne_bytes(content)))
+            }
             IFLA_NEW_NETNSID => Self::NewNetNamespaceId(content),
             IFLA_IF_NETNSID => Self::InterfaceNetNamespaceId(content),
             IFLA_CARRIER_UP_COUNT => {
@@ -711,10 +782,28 @@ impl Attribute for InterfaceInfoAttribute {
                 let content = <[u8; 4]>::try_from(content).ok()?;
                 InterfaceInfoAttribute::MaxMTU(u32::from_ne_bytes(content))
             }
-            IFLA_PROP_LIST => Self::PropertiesList(content),
-            IFLA_ALT_IFNAME => Self::AlternativeName(content),
-            IFLA_PERM_ADDRESS => Self::PermanentAddress(content),
-            IFLA_PROTO_DOWN_REASON => Self::ProtocolDownReason(content),
+            IFLA_PROP_LIST => {
+                let properties = RawAttributeIter::new(content.iter().cloned())
+                    .map(InterfaceInfoAttribute::from_raw)
+                    .try_collect()?;
+
+                Self::PropertiesList(properties)
+            }
+            IFLA_ALT_IFNAME => {
+                let mut content = String::from_utf8(content).ok()?;
+                if content.ends_with('\0') {
+                    content.pop();
+                }
+                Self::AlternativeName(content)
+            }
+            IFLA_PERM_ADDRESS => Self::PermanentAddress(HardwareAddress::Other(content)),
+            IFLA_PROTO_DOWN_REASON => {
+                let attrs = RawAttributeIter::new(content.iter().cloned())
+                    .map(ProtocolDownReasonAttribute::from_raw)
+                    .try_collect()?;
+
+                Self::ProtocolDownReason(attrs)
+            }
             IFLA_PARENT_DEV_NAME => {
                 let mut content = String::from_utf8(content).ok()?;
                 let zero = content.pop();
@@ -743,9 +832,7 @@ impl Attribute for InterfaceInfoAttribute {
                 let content = <[u8; 4]>::try_from(content).ok()?;
                 InterfaceInfoAttribute::AllMulti(u32::from_ne_bytes(content))
             }
-            x @ _ => {
-                panic!("received unknown rtnetlink attribute 0x{:X}", x)
-            }
+            x => Self::Other(x, content),
         };
 
         Some(attr)
@@ -0,0 +1,647 @@
+use std::net::Ipv4Addr;
+
+use crate::netlink::{Attribute, RawAttribute, RawAttributeIter};
+use crate::utils;
+
+pub const IFLA_BOND_UNSPEC: u16 = 0;
+pub const IFLA_BOND_MODE: u16 = 1;
+pub const IFLA_BOND_ACTIVE_SLAVE: u16 = 2;
+pub const IFLA_BOND_MIIMON: u16 = 3;
+pub const IFLA_BOND_UPDELAY: u16 = 4;
+pub const IFLA_BOND_DOWNDELAY: u16 = 5;
+pub const IFLA_BOND_USE_CARRIER: u16 = 6;
+pub const IFLA_BOND_ARP_INTERVAL: u16 = 7;
+pub const IFLA_BOND_ARP_IP_TARGET: u16 = 8;
+pub const IFLA_BOND_ARP_VALIDATE: u16 = 9;
+pub const IFLA_BOND_ARP_ALL_TARGETS: u16 = 10;
+pub const IFLA_BOND_PRIMARY: u16 = 11;
+pub const IFLA_BOND_PRIMARY_RESELECT: u16 = 12;
+pub const IFLA_BOND_FAIL_OVER_MAC: u16 = 13;
+pub const IFLA_BOND_XMIT_HASH_POLICY: u16 = 14;
+pub const IFLA_BOND_RESEND_IGMP: u16 = 15;
+pub const IFLA_BOND_NUM_PEER_NOTIF: u16 = 16;
+pub const IFLA_BOND_ALL_SLAVES_ACTIVE: u16 = 17;
+pub const IFLA_BOND_MIN_LINKS: u16 = 18;
+pub const IFLA_BOND_LP_INTERVAL: u16 = 19;
+pub const IFLA_BOND_PACKETS_PER_SLAVE: u16 = 20;
+pub const IFLA_BOND_AD_LACP_RATE: u16 = 21;
+pub const IFLA_BOND_AD_SELECT: u16 = 22;
+pub const IFLA_BOND_AD_INFO: u16 = 23;
+pub const IFLA_BOND_AD_ACTOR_SYS_PRIO: u16 = 24;
+pub const IFLA_BOND_AD_USER_PORT_KEY: u16 = 25;
+pub const IFLA_BOND_AD_ACTOR_SYSTEM: u16 = 26;
+pub const IFLA_BOND_TLB_DYNAMIC_LB: u16 = 27;
+pub const IFLA_BOND_PEER_NOTIF_DELAY: u16 = 28;
+pub const IFLA_BOND_AD_LACP_ACTIVE: u16 = 29;
+pub const IFLA_BOND_MISSED_MAX: u16 = 30;
+
+pub const IFLA_BOND_SLAVE_UNSPEC: u16 = 0;
+pub const IFLA_BOND_SLAVE_STATE: u16 = 1;
+pub const IFLA_BOND_SLAVE_MII_STATUS: u16 = 2;
+pub const IFLA_BOND_SLAVE_LINK_FAILURE_COUNT: u16 = 3;
+pub const IFLA_BOND_SLAVE_PERM_HWADDR: u16 = 4;
+pub const IFLA_BOND_SLAVE_QUEUE_ID: u16 = 5;
+pub const IFLA_BOND_SLAVE_AD_AGGREGATOR_ID: u16 = 6;
+pub const IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE: u16 = 7;
+pub const IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE: u16 = 8;
+pub const IFLA_BOND_SLAVE_PRIO: u16 = 9;
+
+pub const BOND_MODE_ROUNDROBIN: u8 = 0;
+pub const BOND_MODE_ACTIVEBACKUP: u8 = 1;
+pub const BOND_MODE_XOR: u8 = 2;
+pub const BOND_MODE_BROADCAST: u8 = 3;
+pub const BOND_MODE_8023AD: u8 = 4;
+pub const BOND_MODE_TLB: u8 = 5;
+pub const BOND_MODE_ALB: u8 = 6;
+
+pub const BOND_XMIT_POLICY_LAYER2: u8 = 0;
+pub const BOND_XMIT_POLICY_LAYER34: u8 = 1;
+pub const BOND_XMIT_POLICY_LAYER23: u8 = 2;
+pub const BOND_XMIT_POLICY_ENCAP23: u8 = 3;
+pub const BOND_XMIT_POLICY_ENCAP34: u8 = 4;
+pub const BOND_XMIT_POLICY_VLAN_SRCMAC: u8 = 5;
+
+/// The bonding mode. Corresponds to `BOND_MODE_*` in libc.
+#[doc(alias("BOND_MODE_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BondMode {
+    /// `balance-rr`
+    RoundRobin,
+
+    /// `active-backup`
+    ActiveBackup,
+
+    /// `balance-xor`
+    Xor,
+
+    /// `broadcast`
+    Broadcast,
+
+    /// `802.3ad`, also known as LACP
+    Ieee8023AD,
+
+    /// `balance-tlb`
+    Tlb,
+
+    /// `balance-alb`
+    Alb,
+
+    /// Any other value
+    Other(u8),
+}
+
+impl BondMode {
+    pub const fn raw_value(&self) -> u8 {
+        match self {
+            Self::RoundRobin => BOND_MODE_ROUNDROBIN,
+            Self::ActiveBackup => BOND_MODE_ACTIVEBACKUP,
+            Self::Xor => BOND_MODE_XOR,
+            Self::Broadcast => BOND_MODE_BROADCAST,
+            Self::Ieee8023AD => BOND_MODE_8023AD,
+            Self::Tlb => BOND_MODE_TLB,
+            Self::Alb => BOND_MODE_ALB,
+            Self::Other(x) => *x,
+        }
+    }
+
+    pub const fn from_raw_value(value: u8) -> Self {
+        match value {
+            BOND_MODE_ROUNDROBIN => Self::RoundRobin,
+            BOND_MODE_ACTIVEBACKUP => Self::ActiveBackup,
+            BOND_MODE_XOR => Self::Xor,
+            BOND_MODE_BROADCAST => Self::Broadcast,
+            BOND_MODE_8023AD => Self::Ieee8023AD,
+            BOND_MODE_TLB => Self::Tlb,
+            BOND_MODE_ALB => Self::Alb,
+            x => Self::Other(x),
+        }
+    }
+}
+
+/// The policy used to pick a slave when transmitting in the `Xor` and
+/// `Ieee8023AD` modes. Corresponds to `BOND_XMIT_POLICY_*` in libc.
+#[doc(alias("BOND_XMIT_POLICY_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BondTransmitHashPolicy {
+    /// `layer2`
+    Layer2,
+
+    /// `layer3+4`
+    Layer34,
+
+    /// `layer2+3`
+    Layer23,
+
+    /// `encap2+3`
+    Encap23,
+
+    /// `encap3+4`
+    Encap34,
+
+    /// `vlan+srcmac`
+    VlanSourceMac,
+
+    /// Any other value
+    Other(u8),
+}
+
+impl BondTransmitHashPolicy {
+    pub const fn raw_value(&self) -> u8 {
+        match self {
+            Self::Layer2 => BOND_XMIT_POLICY_LAYER2,
+            Self::Layer34 => BOND_XMIT_POLICY_LAYER34,
+            Self::Layer23 => BOND_XMIT_POLICY_LAYER23,
+            Self::Encap23 => BOND_XMIT_POLICY_ENCAP23,
+            Self::Encap34 => BOND_XMIT_POLICY_ENCAP34,
+            Self::VlanSourceMac => BOND_XMIT_POLICY_VLAN_SRCMAC,
+            Self::Other(x) => *x,
+        }
+    }
+
+    pub const fn from_raw_value(value: u8) -> Self {
+        match value {
+            BOND_XMIT_POLICY_LAYER2 => Self::Layer2,
+            BOND_XMIT_POLICY_LAYER34 => Self::Layer34,
+            BOND_XMIT_POLICY_LAYER23 => Self::Layer23,
+            BOND_XMIT_POLICY_ENCAP23 => Self::Encap23,
+            BOND_XMIT_POLICY_ENCAP34 => Self::Encap34,
+            BOND_XMIT_POLICY_VLAN_SRCMAC => Self::VlanSourceMac,
+            x => Self::Other(x),
+        }
+    }
+}
+
+/// Attributes nested in `IFLA_INFO_DATA` for links of the `bond` kind.
+/// They correspond to `IFLA_BOND_*` in libc.
+///
+/// Unless noted otherwise, times are given in milliseconds.
+#[doc(alias("IFLA_BOND_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BondAttribute {
+    /// `IFLA_BOND_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `IFLA_BOND_MODE`
+    Mode(BondMode),
+
+    /// `IFLA_BOND_ACTIVE_SLAVE` - the interface index of the active slave.
+    ActiveSlave(u32),
+
+    /// `IFLA_BOND_MIIMON` - the MII link monitoring interval.
+    MiiMonitor(u32),
+
+    /// `IFLA_BOND_UPDELAY`
+    UpDelay(u32),
+
+    /// `IFLA_BOND_DOWNDELAY`
+    DownDelay(u32),
+
+    /// `IFLA_BOND_USE_CARRIER`
+    UseCarrier(bool),
+
+    /// `IFLA_BOND_ARP_INTERVAL`
+    ArpInterval(u32),
+
+    /// `IFLA_BOND_ARP_IP_TARGET`
+    ArpIpTarget(Vec<Ipv4Addr>),
+
+    /// `IFLA_BOND_ARP_VALIDATE`
+    ArpValidate(u32),
+
+    /// `IFLA_BOND_ARP_ALL_TARGETS`
+    ArpAllTargets(u32),
+
+    /// `IFLA_BOND_PRIMARY` - the interface index of the primary slave.
+    Primary(u32),
+
+    /// `IFLA_BOND_PRIMARY_RESELECT`
+    PrimaryReselect(u8),
+
+    /// `IFLA_BOND_FAIL_OVER_MAC`
+    FailOverMac(u8),
+
+    /// `IFLA_BOND_XMIT_HASH_POLICY`
+    TransmitHashPolicy(BondTransmitHashPolicy),
+
+    /// `IFLA_BOND_RESEND_IGMP`
+    ResendIgmp(u32),
+
+    /// `IFLA_BOND_NUM_PEER_NOTIF`
+    NumPeerNotifications(u8),
+
+    /// `IFLA_BOND_ALL_SLAVES_ACTIVE`
+    AllSlavesActive(bool),
+
+    /// `IFLA_BOND_MIN_LINKS`
+    MinLinks(u32),
+
+    /// `IFLA_BOND_LP_INTERVAL` - in seconds.
+    LearningPacketInterval(u32),
+
+    /// `IFLA_BOND_PACKETS_PER_SLAVE`
+    PacketsPerSlave(u32),
+
+    /// `IFLA_BOND_AD_LACP_RATE` - 0 for slow, 1 for fast.
+    AdLacpRate(u8),
+
+    /// `IFLA_BOND_AD_SELECT`
+    AdSelect(u8),
+
+    /// `IFLA_BOND_AD_INFO`
+    AdInfo(Vec<u8>),
+
+    /// `IFLA_BOND_AD_ACTOR_SYS_PRIO`
+    AdActorSystemPriority(u16),
+
+    /// `IFLA_BOND_AD_USER_PORT_KEY`
+    AdUserPortKey(u16),
+
+    /// `IFLA_BOND_AD_ACTOR_SYSTEM`
+    AdActorSystem([u8; 6]),
+
+    /// `IFLA_BOND_TLB_DYNAMIC_LB`
+    TlbDynamicLb(bool),
+
+    /// `IFLA_BOND_PEER_NOTIF_DELAY`
+    PeerNotificationDelay(u32),
+
+    /// `IFLA_BOND_AD_LACP_ACTIVE`
+    AdLacpActive(bool),
+
+    /// `IFLA_BOND_MISSED_MAX`
+    MissedMax(u8),
+
+    Other(u16, Vec<u8>),
+}
+
+impl BondAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                IFLA_BOND_UNSPEC
+            }
+            Self::Mode(mode) => {
+                buffer.push(mode.raw_value());
+                IFLA_BOND_MODE
+            }
+            Self::ActiveSlave(index) => {
+                buffer.extend(index.to_ne_bytes());
+                IFLA_BOND_ACTIVE_SLAVE
+            }
+            Self::MiiMonitor(interval) => {
+                buffer.extend(interval.to_ne_bytes());
+                IFLA_BOND_MIIMON
+            }
+            Self::UpDelay(delay) => {
+                buffer.extend(delay.to_ne_bytes());
+                IFLA_BOND_UPDELAY
+            }
+            Self::DownDelay(delay) => {
+                buffer.extend(delay.to_ne_bytes());
+                IFLA_BOND_DOWNDELAY
+            }
+            Self::UseCarrier(b) => {
+                buffer.push(*b as u8);
+                IFLA_BOND_USE_CARRIER
+            }
+            Self::ArpInterval(interval) => {
+                buffer.extend(interval.to_ne_bytes());
+                IFLA_BOND_ARP_INTERVAL
+            }
+            Self::ArpIpTarget(targets) => {
+                // The targets are nested attributes whose types are simply
+                // their indices in the list.
+                for (i, target) in targets.iter().enumerate() {
+                    utils::serialize_attribute_into(buffer, |buffer| {
+                        buffer.extend(target.octets());
+                        i as u16
+                    });
+                }
+                IFLA_BOND_ARP_IP_TARGET
+            }
+            Self::ArpValidate(validate) => {
+                buffer.extend(validate.to_ne_bytes());
+                IFLA_BOND_ARP_VALIDATE
+            }
+            Self::ArpAllTargets(all) => {
+                buffer.extend(all.to_ne_bytes());
+                IFLA_BOND_ARP_ALL_TARGETS
+            }
+            Self::Primary(index) => {
+                buffer.extend(index.to_ne_bytes());
+                IFLA_BOND_PRIMARY
+            }
+            Self::PrimaryReselect(reselect) => {
+                buffer.push(*reselect);
+                IFLA_BOND_PRIMARY_RESELECT
+            }
+            Self::FailOverMac(fail_over_mac) => {
+                buffer.push(*fail_over_mac);
+                IFLA_BOND_FAIL_OVER_MAC
+            }
+            Self::TransmitHashPolicy(policy) => {
+                buffer.push(policy.raw_value());
+                IFLA_BOND_XMIT_HASH_POLICY
+            }
+            Self::ResendIgmp(resend) => {
+                buffer.extend(resend.to_ne_bytes());
+                IFLA_BOND_RESEND_IGMP
+            }
+            Self::NumPeerNotifications(num) => {
+                buffer.push(*num);
+                IFLA_BOND_NUM_PEER_NOTIF
+            }
+            Self::AllSlavesActive(b) => {
+                buffer.push(*b as u8);
+                IFLA_BOND_ALL_SLAVES_ACTIVE
+            }
+            Self::MinLinks(links) => {
+                buffer.extend(links.to_ne_bytes());
+                IFLA_BOND_MIN_LINKS
+            }
+            Self::LearningPacketInterval(interval) => {
+                buffer.extend(interval.to_ne_bytes());
+                IFLA_BOND_LP_INTERVAL
+            }
+            Self::PacketsPerSlave(packets) => {
+                buffer.extend(packets.to_ne_bytes());
+                IFLA_BOND_PACKETS_PER_SLAVE
+            }
+            Self::AdLacpRate(rate) => {
+                buffer.push(*rate);
+                IFLA_BOND_AD_LACP_RATE
+            }
+            Self::AdSelect(select) => {
+                buffer.push(*select);
+                IFLA_BOND_AD_SELECT
+            }
+            Self::AdInfo(content) => {
+                buffer.extend(content.iter());
+                IFLA_BOND_AD_INFO
+            }
+            Self::AdActorSystemPriority(priority) => {
+                buffer.extend(priority.to_ne_bytes());
+                IFLA_BOND_AD_ACTOR_SYS_PRIO
+            }
+            Self::AdUserPortKey(key) => {
+                buffer.extend(key.to_ne_bytes());
+                IFLA_BOND_AD_USER_PORT_KEY
+            }
+            Self::AdActorSystem(addr) => {
+                buffer.extend(addr.iter());
+                IFLA_BOND_AD_ACTOR_SYSTEM
+            }
+            Self::TlbDynamicLb(b) => {
+                buffer.push(*b as u8);
+                IFLA_BOND_TLB_DYNAMIC_LB
+            }
+            Self::PeerNotificationDelay(delay) => {
+                buffer.extend(delay.to_ne_bytes());
+                IFLA_BOND_PEER_NOTIF_DELAY
+            }
+            Self::AdLacpActive(b) => {
+                buffer.push(*b as u8);
+                IFLA_BOND_AD_LACP_ACTIVE
+            }
+            Self::MissedMax(max) => {
+                buffer.push(*max);
+                IFLA_BOND_MISSED_MAX
+            }
+            Self::Other(typ, content) => {
+                buffer.extend(content.iter());
+                *typ
+            }
+        })
+    }
+}
+
+impl Attribute for BondAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let read_bool = |content: &[u8]| content.first().map(|b| *b != 0);
+
+        let attr = match attr_type {
+            IFLA_BOND_UNSPEC => Self::Unspecified(content),
+            IFLA_BOND_MODE => Self::Mode(BondMode::from_raw_value(*content.first()?)),
+            IFLA_BOND_ACTIVE_SLAVE => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::ActiveSlave(u32::from_ne_bytes(content))
+            }
+            IFLA_BOND_MIIMON => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::MiiMonitor(u32::from_ne_bytes(content))
+            }
+            IFLA_BOND_UPDELAY => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::UpDelay(u32::from_ne_bytes(content))
+            }
+            IFLA_BOND_DOWNDELAY => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::DownDelay(u32::from_ne_bytes(content))
+            }
+            IFLA_BOND_USE_CARRIER => Self::UseCarrier(read_bool(&content)?),
+            IFLA_BOND_ARP_INTERVAL => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::ArpInterval(u32::from_ne_bytes(content))
+            }
+            IFLA_BOND_ARP_IP_TARGET => {
+                let targets = RawAttributeIter::new(content.into_iter())
+                    .map(|attr| <[u8; 4]>::try_from(attr.payload()).ok().map(Ipv4Addr::from))
+                    .try_collect()?;
+                Self::ArpIpTarget(targets)
+            }
+            IFLA_BOND_ARP_VALIDATE => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::ArpValidate(u32::from_ne_bytes(content))
+            }
+            IFLA_BOND_ARP_ALL_TARGETS => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::ArpAllTargets(u32::from_ne_bytes(content))
+            }
+            IFLA_BOND_PRIMARY => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Primary(u32::from_ne_bytes(content))
+            }
+            IFLA_BOND_PRIMARY_RESELECT => Self::PrimaryReselect(*content.first()?),
+            IFLA_BOND_FAIL_OVER_MAC => Self::FailOverMac(*content.first()?),
+            IFLA_BOND_XMIT_HASH_POLICY => {
+                let policy = BondTransmitHashPolicy::from_raw_value(*content.first()?);
+                Self::TransmitHashPolicy(policy)
+            }
+            IFLA_BOND_RESEND_IGMP => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::ResendIgmp(u32::from_ne_bytes(content))
+            }
+            IFLA_BOND_NUM_PEER_NOTIF => Self::NumPeerNotifications(*content.first()?),
+            IFLA_BOND_ALL_SLAVES_ACTIVE => Self::AllSlavesActive(read_bool(&content)?),
+            IFLA_BOND_MIN_LINKS => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::MinLinks(u32::from_ne_bytes(content))
+            }
+            IFLA_BOND_LP_INTERVAL => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::LearningPacketInterval(u32::from_ne_bytes(content))
+            }
+            IFLA_BOND_PACKETS_PER_SLAVE => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::PacketsPerSlave(u32::from_ne_bytes(content))
+            }
+            IFLA_BOND_AD_LACP_RATE => Self::AdLacpRate(*content.first()?),
+            IFLA_BOND_AD_SELECT => Self::AdSelect(*content.first()?),
+            IFLA_BOND_AD_INFO => Self::AdInfo(content),
+            IFLA_BOND_AD_ACTOR_SYS_PRIO => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::AdActorSystemPriority(u16::from_ne_bytes(content))
+            }
+            IFLA_BOND_AD_USER_PORT_KEY => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::AdUserPortKey(u16::from_ne_bytes(content))
+            }
+            IFLA_BOND_AD_ACTOR_SYSTEM => Self::AdActorSystem(<[u8; 6]>::try_from(content).ok()?),
+            IFLA_BOND_TLB_DYNAMIC_LB => Self::TlbDynamicLb(read_bool(&content)?),
+            IFLA_BOND_PEER_NOTIF_DELAY => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::PeerNotificationDelay(u32::from_ne_bytes(content))
+            }
+            IFLA_BOND_AD_LACP_ACTIVE => Self::AdLacpActive(read_bool(&content)?),
+            IFLA_BOND_MISSED_MAX => Self::MissedMax(*content.first()?),
+            typ => Self::Other(typ, content),
+        };
+
+        Some(attr)
+    }
+}
+
+/// Attributes nested in `IFLA_INFO_SLAVE_DATA` of links enslaved to a bond.
+/// They correspond to `IFLA_BOND_SLAVE_*` in libc.
+///
+/// Most of those are read-only; only `QueueId` and `Priority` can be set.
+#[doc(alias("IFLA_BOND_SLAVE_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BondSlaveAttribute {
+    /// `IFLA_BOND_SLAVE_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `IFLA_BOND_SLAVE_STATE` - 0 if active, 1 if backup.
+    State(u8),
+
+    /// `IFLA_BOND_SLAVE_MII_STATUS`
+    MiiStatus(u8),
+
+    /// `IFLA_BOND_SLAVE_LINK_FAILURE_COUNT`
+    LinkFailureCount(u32),
+
+    /// `IFLA_BOND_SLAVE_PERM_HWADDR`
+    PermanentHardwareAddress(Vec<u8>),
+
+    /// `IFLA_BOND_SLAVE_QUEUE_ID`
+    QueueId(u16),
+
+    /// `IFLA_BOND_SLAVE_AD_AGGREGATOR_ID`
+    AdAggregatorId(u16),
+
+    /// `IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE`
+    AdActorOperPortState(u8),
+
+    /// `IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE`
+    AdPartnerOperPortState(u16),
+
+    /// `IFLA_BOND_SLAVE_PRIO`
+    Priority(i32),
+
+    Other(u16, Vec<u8>),
+}
+
+impl BondSlaveAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                IFLA_BOND_SLAVE_UNSPEC
+            }
+            Self::State(state) => {
+                buffer.push(*state);
+                IFLA_BOND_SLAVE_STATE
+            }
+            Self::MiiStatus(status) => {
+                buffer.push(*status);
+                IFLA_BOND_SLAVE_MII_STATUS
+            }
+            Self::LinkFailureCount(count) => {
+                buffer.extend(count.to_ne_bytes());
+                IFLA_BOND_SLAVE_LINK_FAILURE_COUNT
+            }
+            Self::PermanentHardwareAddress(addr) => {
+                buffer.extend(addr.iter());
+                IFLA_BOND_SLAVE_PERM_HWADDR
+            }
+            Self::QueueId(id) => {
+                buffer.extend(id.to_ne_bytes());
+                IFLA_BOND_SLAVE_QUEUE_ID
+            }
+            Self::AdAggregatorId(id) => {
+                buffer.extend(id.to_ne_bytes());
+                IFLA_BOND_SLAVE_AD_AGGREGATOR_ID
+            }
+            Self::AdActorOperPortState(state) => {
+                buffer.push(*state);
+                IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE
+            }
+            Self::AdPartnerOperPortState(state) => {
+                buffer.extend(state.to_ne_bytes());
+                IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE
+            }
+            Self::Priority(priority) => {
+                buffer.extend(priority.to_ne_bytes());
+                IFLA_BOND_SLAVE_PRIO
+            }
+            Self::Other(typ, content) => {
+                buffer.extend(content.iter());
+                *typ
+            }
+        })
+    }
+}
+
+impl Attribute for BondSlaveAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            IFLA_BOND_SLAVE_UNSPEC => Self::Unspecified(content),
+            IFLA_BOND_SLAVE_STATE => Self::State(*content.first()?),
+            IFLA_BOND_SLAVE_MII_STATUS => Self::MiiStatus(*content.first()?),
+            IFLA_BOND_SLAVE_LINK_FAILURE_COUNT => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::LinkFailureCount(u32::from_ne_bytes(content))
+            }
+            IFLA_BOND_SLAVE_PERM_HWADDR => Self::PermanentHardwareAddress(content),
+            IFLA_BOND_SLAVE_QUEUE_ID => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::QueueId(u16::from_ne_bytes(content))
+            }
+            IFLA_BOND_SLAVE_AD_AGGREGATOR_ID => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::AdAggregatorId(u16::from_ne_bytes(content))
+            }
+            IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE => {
+                Self::AdActorOperPortState(*content.first()?)
+            }
+            IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::AdPartnerOperPortState(u16::from_ne_bytes(content))
+            }
+            IFLA_BOND_SLAVE_PRIO => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Priority(i32::from_ne_bytes(content))
+            }
+            typ => Self::Other(typ, content),
+        };
+
+        Some(attr)
+    }
+}
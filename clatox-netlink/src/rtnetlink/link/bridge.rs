@@ -0,0 +1,864 @@
+use crate::netlink::{Attribute, RawAttribute};
+use crate::utils;
+
+use super::VlanProtocol;
+
+pub const IFLA_BR_UNSPEC: u16 = 0;
+pub const IFLA_BR_FORWARD_DELAY: u16 = 1;
+pub const IFLA_BR_HELLO_TIME: u16 = 2;
+pub const IFLA_BR_MAX_AGE: u16 = 3;
+pub const IFLA_BR_AGEING_TIME: u16 = 4;
+pub const IFLA_BR_STP_STATE: u16 = 5;
+pub const IFLA_BR_PRIORITY: u16 = 6;
+pub const IFLA_BR_VLAN_FILTERING: u16 = 7;
+pub const IFLA_BR_VLAN_PROTOCOL: u16 = 8;
+pub const IFLA_BR_GROUP_FWD_MASK: u16 = 9;
+pub const IFLA_BR_ROOT_ID: u16 = 10;
+pub const IFLA_BR_BRIDGE_ID: u16 = 11;
+pub const IFLA_BR_ROOT_PORT: u16 = 12;
+pub const IFLA_BR_ROOT_PATH_COST: u16 = 13;
+pub const IFLA_BR_TOPOLOGY_CHANGE: u16 = 14;
+pub const IFLA_BR_TOPOLOGY_CHANGE_DETECTED: u16 = 15;
+pub const IFLA_BR_HELLO_TIMER: u16 = 16;
+pub const IFLA_BR_TCN_TIMER: u16 = 17;
+pub const IFLA_BR_TOPOLOGY_CHANGE_TIMER: u16 = 18;
+pub const IFLA_BR_GC_TIMER: u16 = 19;
+pub const IFLA_BR_GROUP_ADDR: u16 = 20;
+pub const IFLA_BR_FDB_FLUSH: u16 = 21;
+pub const IFLA_BR_MCAST_ROUTER: u16 = 22;
+pub const IFLA_BR_MCAST_SNOOPING: u16 = 23;
+pub const IFLA_BR_MCAST_QUERY_USE_IFADDR: u16 = 24;
+pub const IFLA_BR_MCAST_QUERIER: u16 = 25;
+pub const IFLA_BR_NF_CALL_IPTABLES: u16 = 36;
+pub const IFLA_BR_NF_CALL_IP6TABLES: u16 = 37;
+pub const IFLA_BR_NF_CALL_ARPTABLES: u16 = 38;
+pub const IFLA_BR_VLAN_DEFAULT_PVID: u16 = 39;
+pub const IFLA_BR_PAD: u16 = 40;
+pub const IFLA_BR_VLAN_STATS_ENABLED: u16 = 41;
+pub const IFLA_BR_MCAST_STATS_ENABLED: u16 = 42;
+pub const IFLA_BR_MCAST_IGMP_VERSION: u16 = 43;
+pub const IFLA_BR_MCAST_MLD_VERSION: u16 = 44;
+pub const IFLA_BR_VLAN_STATS_PER_PORT: u16 = 45;
+
+pub const IFLA_BRPORT_UNSPEC: u16 = 0;
+pub const IFLA_BRPORT_STATE: u16 = 1;
+pub const IFLA_BRPORT_PRIORITY: u16 = 2;
+pub const IFLA_BRPORT_COST: u16 = 3;
+pub const IFLA_BRPORT_MODE: u16 = 4;
+pub const IFLA_BRPORT_GUARD: u16 = 5;
+pub const IFLA_BRPORT_PROTECT: u16 = 6;
+pub const IFLA_BRPORT_FAST_LEAVE: u16 = 7;
+pub const IFLA_BRPORT_LEARNING: u16 = 8;
+pub const IFLA_BRPORT_UNICAST_FLOOD: u16 = 9;
+pub const IFLA_BRPORT_PROXYARP: u16 = 10;
+pub const IFLA_BRPORT_LEARNING_SYNC: u16 = 11;
+pub const IFLA_BRPORT_PROXYARP_WIFI: u16 = 12;
+pub const IFLA_BRPORT_ROOT_ID: u16 = 13;
+pub const IFLA_BRPORT_BRIDGE_ID: u16 = 14;
+pub const IFLA_BRPORT_DESIGNATED_PORT: u16 = 15;
+pub const IFLA_BRPORT_DESIGNATED_COST: u16 = 16;
+pub const IFLA_BRPORT_ID: u16 = 17;
+pub const IFLA_BRPORT_NO: u16 = 18;
+pub const IFLA_BRPORT_TOPOLOGY_CHANGE_ACK: u16 = 19;
+pub const IFLA_BRPORT_CONFIG_PENDING: u16 = 20;
+pub const IFLA_BRPORT_MESSAGE_AGE_TIMER: u16 = 21;
+pub const IFLA_BRPORT_FORWARD_DELAY_TIMER: u16 = 22;
+pub const IFLA_BRPORT_HOLD_TIMER: u16 = 23;
+pub const IFLA_BRPORT_FLUSH: u16 = 24;
+pub const IFLA_BRPORT_MULTICAST_ROUTER: u16 = 25;
+pub const IFLA_BRPORT_PAD: u16 = 26;
+pub const IFLA_BRPORT_MCAST_FLOOD: u16 = 27;
+pub const IFLA_BRPORT_MCAST_TO_UCAST: u16 = 28;
+pub const IFLA_BRPORT_VLAN_TUNNEL: u16 = 29;
+pub const IFLA_BRPORT_BCAST_FLOOD: u16 = 30;
+pub const IFLA_BRPORT_GROUP_FWD_MASK: u16 = 31;
+pub const IFLA_BRPORT_NEIGH_SUPPRESS: u16 = 32;
+pub const IFLA_BRPORT_ISOLATED: u16 = 33;
+pub const IFLA_BRPORT_BACKUP_PORT: u16 = 34;
+
+pub const BR_STATE_DISABLED: u8 = 0;
+pub const BR_STATE_LISTENING: u8 = 1;
+pub const BR_STATE_LEARNING: u8 = 2;
+pub const BR_STATE_FORWARDING: u8 = 3;
+pub const BR_STATE_BLOCKING: u8 = 4;
+
+/// A bridge identifier, as used by STP. Corresponds to `struct ifla_bridge_id`
+/// in libc.
+#[doc(alias("ifla_bridge_id"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BridgeId {
+    pub priority: [u8; 2],
+    pub address: [u8; 6],
+}
+
+impl BridgeId {
+    fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        buffer.extend(self.priority.iter());
+        buffer.extend(self.address.iter());
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        let bytes = <[u8; 8]>::try_from(bytes).ok()?;
+
+        Some(Self {
+            priority: [bytes[0], bytes[1]],
+            address: [bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]],
+        })
+    }
+}
+
+/// The STP state of a bridge port. Corresponds to `BR_STATE_*` in libc.
+#[doc(alias("BR_STATE_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BridgePortState {
+    Disabled,
+    Listening,
+    Learning,
+    Forwarding,
+    Blocking,
+
+    /// Any other value
+    Other(u8),
+}
+
+impl BridgePortState {
+    pub const fn raw_value(&self) -> u8 {
+        match self {
+            Self::Disabled => BR_STATE_DISABLED,
+            Self::Listening => BR_STATE_LISTENING,
+            Self::Learning => BR_STATE_LEARNING,
+            Self::Forwarding => BR_STATE_FORWARDING,
+            Self::Blocking => BR_STATE_BLOCKING,
+            Self::Other(x) => *x,
+        }
+    }
+
+    pub const fn from_raw_value(value: u8) -> Self {
+        match value {
+            BR_STATE_DISABLED => Self::Disabled,
+            BR_STATE_LISTENING => Self::Listening,
+            BR_STATE_LEARNING => Self::Learning,
+            BR_STATE_FORWARDING => Self::Forwarding,
+            BR_STATE_BLOCKING => Self::Blocking,
+            x => Self::Other(x),
+        }
+    }
+}
+
+/// Attributes nested in `IFLA_INFO_DATA` for links of the `bridge` kind.
+/// They correspond to `IFLA_BR_*` in libc.
+///
+/// Unless noted otherwise, times are given in hundredths of a second
+/// (`clock_t`).
+#[doc(alias("IFLA_BR_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgeAttribute {
+    /// `IFLA_BR_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `IFLA_BR_FORWARD_DELAY`
+    ForwardDelay(u32),
+
+    /// `IFLA_BR_HELLO_TIME`
+    HelloTime(u32),
+
+    /// `IFLA_BR_MAX_AGE`
+    MaxAge(u32),
+
+    /// `IFLA_BR_AGEING_TIME` - the lifetime of learned FDB entries.
+    AgeingTime(u32),
+
+    /// `IFLA_BR_STP_STATE` - 0 if STP is disabled, 1 for kernel STP and
+    /// 2 for userspace STP.
+    StpState(u32),
+
+    /// `IFLA_BR_PRIORITY`
+    Priority(u16),
+
+    /// `IFLA_BR_VLAN_FILTERING`
+    VlanFiltering(bool),
+
+    /// `IFLA_BR_VLAN_PROTOCOL`
+    VlanProtocol(VlanProtocol),
+
+    /// `IFLA_BR_GROUP_FWD_MASK`
+    GroupForwardMask(u16),
+
+    /// `IFLA_BR_ROOT_ID`
+    RootId(BridgeId),
+
+    /// `IFLA_BR_BRIDGE_ID`
+    BridgeId(BridgeId),
+
+    /// `IFLA_BR_ROOT_PORT`
+    RootPort(u16),
+
+    /// `IFLA_BR_ROOT_PATH_COST`
+    RootPathCost(u32),
+
+    /// `IFLA_BR_TOPOLOGY_CHANGE`
+    TopologyChange(bool),
+
+    /// `IFLA_BR_TOPOLOGY_CHANGE_DETECTED`
+    TopologyChangeDetected(bool),
+
+    /// `IFLA_BR_HELLO_TIMER`
+    HelloTimer(u64),
+
+    /// `IFLA_BR_TCN_TIMER`
+    TcnTimer(u64),
+
+    /// `IFLA_BR_TOPOLOGY_CHANGE_TIMER`
+    TopologyChangeTimer(u64),
+
+    /// `IFLA_BR_GC_TIMER`
+    GcTimer(u64),
+
+    /// `IFLA_BR_GROUP_ADDR`
+    GroupAddress([u8; 6]),
+
+    /// `IFLA_BR_FDB_FLUSH` - flushes the FDB of the bridge when set.
+    FdbFlush,
+
+    /// `IFLA_BR_MCAST_ROUTER`
+    MulticastRouter(u8),
+
+    /// `IFLA_BR_MCAST_SNOOPING`
+    MulticastSnooping(bool),
+
+    /// `IFLA_BR_MCAST_QUERY_USE_IFADDR`
+    MulticastQueryUseInterfaceAddress(bool),
+
+    /// `IFLA_BR_MCAST_QUERIER`
+    MulticastQuerier(bool),
+
+    /// `IFLA_BR_NF_CALL_IPTABLES`
+    NfCallIptables(bool),
+
+    /// `IFLA_BR_NF_CALL_IP6TABLES`
+    NfCallIp6tables(bool),
+
+    /// `IFLA_BR_NF_CALL_ARPTABLES`
+    NfCallArptables(bool),
+
+    /// `IFLA_BR_VLAN_DEFAULT_PVID`
+    VlanDefaultPvid(u16),
+
+    /// `IFLA_BR_PAD`
+    Pad(Vec<u8>),
+
+    /// `IFLA_BR_VLAN_STATS_ENABLED`
+    VlanStatsEnabled(bool),
+
+    /// `IFLA_BR_MCAST_STATS_ENABLED`
+    MulticastStatsEnabled(bool),
+
+    /// `IFLA_BR_MCAST_IGMP_VERSION`
+    MulticastIgmpVersion(u8),
+
+    /// `IFLA_BR_MCAST_MLD_VERSION`
+    MulticastMldVersion(u8),
+
+    /// `IFLA_BR_VLAN_STATS_PER_PORT`
+    VlanStatsPerPort(bool),
+
+    Other(u16, Vec<u8>),
+}
+
+impl BridgeAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                IFLA_BR_UNSPEC
+            }
+            Self::ForwardDelay(delay) => {
+                buffer.extend(delay.to_ne_bytes());
+                IFLA_BR_FORWARD_DELAY
+            }
+            Self::HelloTime(time) => {
+                buffer.extend(time.to_ne_bytes());
+                IFLA_BR_HELLO_TIME
+            }
+            Self::MaxAge(age) => {
+                buffer.extend(age.to_ne_bytes());
+                IFLA_BR_MAX_AGE
+            }
+            Self::AgeingTime(time) => {
+                buffer.extend(time.to_ne_bytes());
+                IFLA_BR_AGEING_TIME
+            }
+            Self::StpState(state) => {
+                buffer.extend(state.to_ne_bytes());
+                IFLA_BR_STP_STATE
+            }
+            Self::Priority(priority) => {
+                buffer.extend(priority.to_ne_bytes());
+                IFLA_BR_PRIORITY
+            }
+            Self::VlanFiltering(b) => {
+                buffer.push(*b as u8);
+                IFLA_BR_VLAN_FILTERING
+            }
+            Self::VlanProtocol(protocol) => {
+                buffer.extend(protocol.raw_value().to_be_bytes());
+                IFLA_BR_VLAN_PROTOCOL
+            }
+            Self::GroupForwardMask(mask) => {
+                buffer.extend(mask.to_ne_bytes());
+                IFLA_BR_GROUP_FWD_MASK
+            }
+            Self::RootId(id) => {
+                id.serialize_into(buffer);
+                IFLA_BR_ROOT_ID
+            }
+            Self::BridgeId(id) => {
+                id.serialize_into(buffer);
+                IFLA_BR_BRIDGE_ID
+            }
+            Self::RootPort(port) => {
+                buffer.extend(port.to_ne_bytes());
+                IFLA_BR_ROOT_PORT
+            }
+            Self::RootPathCost(cost) => {
+                buffer.extend(cost.to_ne_bytes());
+                IFLA_BR_ROOT_PATH_COST
+            }
+            Self::TopologyChange(b) => {
+                buffer.push(*b as u8);
+                IFLA_BR_TOPOLOGY_CHANGE
+            }
+            Self::TopologyChangeDetected(b) => {
+                buffer.push(*b as u8);
+                IFLA_BR_TOPOLOGY_CHANGE_DETECTED
+            }
+            Self::HelloTimer(timer) => {
+                buffer.extend(timer.to_ne_bytes());
+                IFLA_BR_HELLO_TIMER
+            }
+            Self::TcnTimer(timer) => {
+                buffer.extend(timer.to_ne_bytes());
+                IFLA_BR_TCN_TIMER
+            }
+            Self::TopologyChangeTimer(timer) => {
+                buffer.extend(timer.to_ne_bytes());
+                IFLA_BR_TOPOLOGY_CHANGE_TIMER
+            }
+            Self::GcTimer(timer) => {
+                buffer.extend(timer.to_ne_bytes());
+                IFLA_BR_GC_TIMER
+            }
+            Self::GroupAddress(addr) => {
+                buffer.extend(addr.iter());
+                IFLA_BR_GROUP_ADDR
+            }
+            Self::FdbFlush => IFLA_BR_FDB_FLUSH,
+            Self::MulticastRouter(router) => {
+                buffer.push(*router);
+                IFLA_BR_MCAST_ROUTER
+            }
+            Self::MulticastSnooping(b) => {
+                buffer.push(*b as u8);
+                IFLA_BR_MCAST_SNOOPING
+            }
+            Self::MulticastQueryUseInterfaceAddress(b) => {
+                buffer.push(*b as u8);
+                IFLA_BR_MCAST_QUERY_USE_IFADDR
+            }
+            Self::MulticastQuerier(b) => {
+                buffer.push(*b as u8);
+                IFLA_BR_MCAST_QUERIER
+            }
+            Self::NfCallIptables(b) => {
+                buffer.push(*b as u8);
+                IFLA_BR_NF_CALL_IPTABLES
+            }
+            Self::NfCallIp6tables(b) => {
+                buffer.push(*b as u8);
+                IFLA_BR_NF_CALL_IP6TABLES
+            }
+            Self::NfCallArptables(b) => {
+                buffer.push(*b as u8);
+                IFLA_BR_NF_CALL_ARPTABLES
+            }
+            Self::VlanDefaultPvid(pvid) => {
+                buffer.extend(pvid.to_ne_bytes());
+                IFLA_BR_VLAN_DEFAULT_PVID
+            }
+            Self::Pad(content) => {
+                buffer.extend(content.iter());
+                IFLA_BR_PAD
+            }
+            Self::VlanStatsEnabled(b) => {
+                buffer.push(*b as u8);
+                IFLA_BR_VLAN_STATS_ENABLED
+            }
+            Self::MulticastStatsEnabled(b) => {
+                buffer.push(*b as u8);
+                IFLA_BR_MCAST_STATS_ENABLED
+            }
+            Self::MulticastIgmpVersion(version) => {
+                buffer.push(*version);
+                IFLA_BR_MCAST_IGMP_VERSION
+            }
+            Self::MulticastMldVersion(version) => {
+                buffer.push(*version);
+                IFLA_BR_MCAST_MLD_VERSION
+            }
+            Self::VlanStatsPerPort(b) => {
+                buffer.push(*b as u8);
+                IFLA_BR_VLAN_STATS_PER_PORT
+            }
+            Self::Other(typ, content) => {
+                buffer.extend(content.iter());
+                *typ
+            }
+        })
+    }
+}
+
+impl Attribute for BridgeAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let read_bool = |content: &[u8]| content.first().map(|b| *b != 0);
+
+        let attr = match attr_type {
+            IFLA_BR_UNSPEC => Self::Unspecified(content),
+            IFLA_BR_FORWARD_DELAY => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::ForwardDelay(u32::from_ne_bytes(content))
+            }
+            IFLA_BR_HELLO_TIME => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::HelloTime(u32::from_ne_bytes(content))
+            }
+            IFLA_BR_MAX_AGE => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::MaxAge(u32::from_ne_bytes(content))
+            }
+            IFLA_BR_AGEING_TIME => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::AgeingTime(u32::from_ne_bytes(content))
+            }
+            IFLA_BR_STP_STATE => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::StpState(u32::from_ne_bytes(content))
+            }
+            IFLA_BR_PRIORITY => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::Priority(u16::from_ne_bytes(content))
+            }
+            IFLA_BR_VLAN_FILTERING => Self::VlanFiltering(read_bool(&content)?),
+            IFLA_BR_VLAN_PROTOCOL => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::VlanProtocol(VlanProtocol::from_raw_value(u16::from_be_bytes(content)))
+            }
+            IFLA_BR_GROUP_FWD_MASK => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::GroupForwardMask(u16::from_ne_bytes(content))
+            }
+            IFLA_BR_ROOT_ID => Self::RootId(BridgeId::deserialize(&content)?),
+            IFLA_BR_BRIDGE_ID => Self::BridgeId(BridgeId::deserialize(&content)?),
+            IFLA_BR_ROOT_PORT => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::RootPort(u16::from_ne_bytes(content))
+            }
+            IFLA_BR_ROOT_PATH_COST => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::RootPathCost(u32::from_ne_bytes(content))
+            }
+            IFLA_BR_TOPOLOGY_CHANGE => Self::TopologyChange(read_bool(&content)?),
+            IFLA_BR_TOPOLOGY_CHANGE_DETECTED => {
+                Self::TopologyChangeDetected(read_bool(&content)?)
+            }
+            IFLA_BR_HELLO_TIMER => {
+                let content = <[u8; 8]>::try_from(content).ok()?;
+                Self::HelloTimer(u64::from_ne_bytes(content))
+            }
+            IFLA_BR_TCN_TIMER => {
+                let content = <[u8; 8]>::try_from(content).ok()?;
+                Self::TcnTimer(u64::from_ne_bytes(content))
+            }
+            IFLA_BR_TOPOLOGY_CHANGE_TIMER => {
+                let content = <[u8; 8]>::try_from(content).ok()?;
+                Self::TopologyChangeTimer(u64::from_ne_bytes(content))
+            }
+            IFLA_BR_GC_TIMER => {
+                let content = <[u8; 8]>::try_from(content).ok()?;
+                Self::GcTimer(u64::from_ne_bytes(content))
+            }
+            IFLA_BR_GROUP_ADDR => Self::GroupAddress(<[u8; 6]>::try_from(content).ok()?),
+            IFLA_BR_FDB_FLUSH => Self::FdbFlush,
+            IFLA_BR_MCAST_ROUTER => Self::MulticastRouter(*content.first()?),
+            IFLA_BR_MCAST_SNOOPING => Self::MulticastSnooping(read_bool(&content)?),
+            IFLA_BR_MCAST_QUERY_USE_IFADDR => {
+                Self::MulticastQueryUseInterfaceAddress(read_bool(&content)?)
+            }
+            IFLA_BR_MCAST_QUERIER => Self::MulticastQuerier(read_bool(&content)?),
+            IFLA_BR_NF_CALL_IPTABLES => Self::NfCallIptables(read_bool(&content)?),
+            IFLA_BR_NF_CALL_IP6TABLES => Self::NfCallIp6tables(read_bool(&content)?),
+            IFLA_BR_NF_CALL_ARPTABLES => Self::NfCallArptables(read_bool(&content)?),
+            IFLA_BR_VLAN_DEFAULT_PVID => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::VlanDefaultPvid(u16::from_ne_bytes(content))
+            }
+            IFLA_BR_PAD => Self::Pad(content),
+            IFLA_BR_VLAN_STATS_ENABLED => Self::VlanStatsEnabled(read_bool(&content)?),
+            IFLA_BR_MCAST_STATS_ENABLED => Self::MulticastStatsEnabled(read_bool(&content)?),
+            IFLA_BR_MCAST_IGMP_VERSION => Self::MulticastIgmpVersion(*content.first()?),
+            IFLA_BR_MCAST_MLD_VERSION => Self::MulticastMldVersion(*content.first()?),
+            IFLA_BR_VLAN_STATS_PER_PORT => Self::VlanStatsPerPort(read_bool(&content)?),
+            typ => Self::Other(typ, content),
+        };
+
+        Some(attr)
+    }
+}
+
+/// Attributes of a bridge port. They are nested in `IFLA_INFO_SLAVE_DATA` of
+/// links enslaved to a bridge, as well as in `IFLA_PROTINFO` of `AF_BRIDGE`
+/// link messages. They correspond to `IFLA_BRPORT_*` in libc.
+#[doc(alias("IFLA_BRPORT_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgePortAttribute {
+    /// `IFLA_BRPORT_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `IFLA_BRPORT_STATE`
+    State(BridgePortState),
+
+    /// `IFLA_BRPORT_PRIORITY`
+    Priority(u16),
+
+    /// `IFLA_BRPORT_COST`
+    Cost(u32),
+
+    /// `IFLA_BRPORT_MODE` - whether hairpin mode is enabled.
+    Hairpin(bool),
+
+    /// `IFLA_BRPORT_GUARD` - whether STP BPDUs are blocked.
+    BpduGuard(bool),
+
+    /// `IFLA_BRPORT_PROTECT` - whether the port is prevented from becoming
+    /// the root port.
+    RootBlock(bool),
+
+    /// `IFLA_BRPORT_FAST_LEAVE`
+    FastLeave(bool),
+
+    /// `IFLA_BRPORT_LEARNING`
+    Learning(bool),
+
+    /// `IFLA_BRPORT_UNICAST_FLOOD`
+    UnicastFlood(bool),
+
+    /// `IFLA_BRPORT_PROXYARP`
+    ProxyArp(bool),
+
+    /// `IFLA_BRPORT_LEARNING_SYNC`
+    LearningSync(bool),
+
+    /// `IFLA_BRPORT_PROXYARP_WIFI`
+    ProxyArpWifi(bool),
+
+    /// `IFLA_BRPORT_ROOT_ID`
+    RootId(BridgeId),
+
+    /// `IFLA_BRPORT_BRIDGE_ID`
+    BridgeId(BridgeId),
+
+    /// `IFLA_BRPORT_DESIGNATED_PORT`
+    DesignatedPort(u16),
+
+    /// `IFLA_BRPORT_DESIGNATED_COST`
+    DesignatedCost(u16),
+
+    /// `IFLA_BRPORT_ID`
+    Id(u16),
+
+    /// `IFLA_BRPORT_NO`
+    Number(u16),
+
+    /// `IFLA_BRPORT_TOPOLOGY_CHANGE_ACK`
+    TopologyChangeAck(bool),
+
+    /// `IFLA_BRPORT_CONFIG_PENDING`
+    ConfigPending(bool),
+
+    /// `IFLA_BRPORT_MESSAGE_AGE_TIMER`
+    MessageAgeTimer(u64),
+
+    /// `IFLA_BRPORT_FORWARD_DELAY_TIMER`
+    ForwardDelayTimer(u64),
+
+    /// `IFLA_BRPORT_HOLD_TIMER`
+    HoldTimer(u64),
+
+    /// `IFLA_BRPORT_FLUSH` - flushes the FDB entries of the port when set.
+    Flush,
+
+    /// `IFLA_BRPORT_MULTICAST_ROUTER`
+    MulticastRouter(u8),
+
+    /// `IFLA_BRPORT_PAD`
+    Pad(Vec<u8>),
+
+    /// `IFLA_BRPORT_MCAST_FLOOD`
+    MulticastFlood(bool),
+
+    /// `IFLA_BRPORT_MCAST_TO_UCAST`
+    MulticastToUnicast(bool),
+
+    /// `IFLA_BRPORT_VLAN_TUNNEL`
+    VlanTunnel(bool),
+
+    /// `IFLA_BRPORT_BCAST_FLOOD`
+    BroadcastFlood(bool),
+
+    /// `IFLA_BRPORT_GROUP_FWD_MASK`
+    GroupForwardMask(u16),
+
+    /// `IFLA_BRPORT_NEIGH_SUPPRESS`
+    NeighborSuppress(bool),
+
+    /// `IFLA_BRPORT_ISOLATED`
+    Isolated(bool),
+
+    /// `IFLA_BRPORT_BACKUP_PORT` - the interface index of the backup port.
+    BackupPort(u32),
+
+    Other(u16, Vec<u8>),
+}
+
+impl BridgePortAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                IFLA_BRPORT_UNSPEC
+            }
+            Self::State(state) => {
+                buffer.push(state.raw_value());
+                IFLA_BRPORT_STATE
+            }
+            Self::Priority(priority) => {
+                buffer.extend(priority.to_ne_bytes());
+                IFLA_BRPORT_PRIORITY
+            }
+            Self::Cost(cost) => {
+                buffer.extend(cost.to_ne_bytes());
+                IFLA_BRPORT_COST
+            }
+            Self::Hairpin(b) => {
+                buffer.push(*b as u8);
+                IFLA_BRPORT_MODE
+            }
+            Self::BpduGuard(b) => {
+                buffer.push(*b as u8);
+                IFLA_BRPORT_GUARD
+            }
+            Self::RootBlock(b) => {
+                buffer.push(*b as u8);
+                IFLA_BRPORT_PROTECT
+            }
+            Self::FastLeave(b) => {
+                buffer.push(*b as u8);
+                IFLA_BRPORT_FAST_LEAVE
+            }
+            Self::Learning(b) => {
+                buffer.push(*b as u8);
+                IFLA_BRPORT_LEARNING
+            }
+            Self::UnicastFlood(b) => {
+                buffer.push(*b as u8);
+                IFLA_BRPORT_UNICAST_FLOOD
+            }
+            Self::ProxyArp(b) => {
+                buffer.push(*b as u8);
+                IFLA_BRPORT_PROXYARP
+            }
+            Self::LearningSync(b) => {
+                buffer.push(*b as u8);
+                IFLA_BRPORT_LEARNING_SYNC
+            }
+            Self::ProxyArpWifi(b) => {
+                buffer.push(*b as u8);
+                IFLA_BRPORT_PROXYARP_WIFI
+            }
+            Self::RootId(id) => {
+                id.serialize_into(buffer);
+                IFLA_BRPORT_ROOT_ID
+            }
+            Self::BridgeId(id) => {
+                id.serialize_into(buffer);
+                IFLA_BRPORT_BRIDGE_ID
+            }
+            Self::DesignatedPort(port) => {
+                buffer.extend(port.to_ne_bytes());
+                IFLA_BRPORT_DESIGNATED_PORT
+            }
+            Self::DesignatedCost(cost) => {
+                buffer.extend(cost.to_ne_bytes());
+                IFLA_BRPORT_DESIGNATED_COST
+            }
+            Self::Id(id) => {
+                buffer.extend(id.to_ne_bytes());
+                IFLA_BRPORT_ID
+            }
+            Self::Number(no) => {
+                buffer.extend(no.to_ne_bytes());
+                IFLA_BRPORT_NO
+            }
+            Self::TopologyChangeAck(b) => {
+                buffer.push(*b as u8);
+                IFLA_BRPORT_TOPOLOGY_CHANGE_ACK
+            }
+            Self::ConfigPending(b) => {
+                buffer.push(*b as u8);
+                IFLA_BRPORT_CONFIG_PENDING
+            }
+            Self::MessageAgeTimer(timer) => {
+                buffer.extend(timer.to_ne_bytes());
+                IFLA_BRPORT_MESSAGE_AGE_TIMER
+            }
+            Self::ForwardDelayTimer(timer) => {
+                buffer.extend(timer.to_ne_bytes());
+                IFLA_BRPORT_FORWARD_DELAY_TIMER
+            }
+            Self::HoldTimer(timer) => {
+                buffer.extend(timer.to_ne_bytes());
+                IFLA_BRPORT_HOLD_TIMER
+            }
+            Self::Flush => IFLA_BRPORT_FLUSH,
+            Self::MulticastRouter(router) => {
+                buffer.push(*router);
+                IFLA_BRPORT_MULTICAST_ROUTER
+            }
+            Self::Pad(content) => {
+                buffer.extend(content.iter());
+                IFLA_BRPORT_PAD
+            }
+            Self::MulticastFlood(b) => {
+                buffer.push(*b as u8);
+                IFLA_BRPORT_MCAST_FLOOD
+            }
+            Self::MulticastToUnicast(b) => {
+                buffer.push(*b as u8);
+                IFLA_BRPORT_MCAST_TO_UCAST
+            }
+            Self::VlanTunnel(b) => {
+                buffer.push(*b as u8);
+                IFLA_BRPORT_VLAN_TUNNEL
+            }
+            Self::BroadcastFlood(b) => {
+                buffer.push(*b as u8);
+                IFLA_BRPORT_BCAST_FLOOD
+            }
+            Self::GroupForwardMask(mask) => {
+                buffer.extend(mask.to_ne_bytes());
+                IFLA_BRPORT_GROUP_FWD_MASK
+            }
+            Self::NeighborSuppress(b) => {
+                buffer.push(*b as u8);
+                IFLA_BRPORT_NEIGH_SUPPRESS
+            }
+            Self::Isolated(b) => {
+                buffer.push(*b as u8);
+                IFLA_BRPORT_ISOLATED
+            }
+            Self::BackupPort(port) => {
+                buffer.extend(port.to_ne_bytes());
+                IFLA_BRPORT_BACKUP_PORT
+            }
+            Self::Other(typ, content) => {
+                buffer.extend(content.iter());
+                *typ
+            }
+        })
+    }
+}
+
+impl Attribute for BridgePortAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let read_bool = |content: &[u8]| content.first().map(|b| *b != 0);
+
+        let attr = match attr_type {
+            IFLA_BRPORT_UNSPEC => Self::Unspecified(content),
+            IFLA_BRPORT_STATE => Self::State(BridgePortState::from_raw_value(*content.first()?)),
+            IFLA_BRPORT_PRIORITY => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::Priority(u16::from_ne_bytes(content))
+            }
+            IFLA_BRPORT_COST => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Cost(u32::from_ne_bytes(content))
+            }
+            IFLA_BRPORT_MODE => Self::Hairpin(read_bool(&content)?),
+            IFLA_BRPORT_GUARD => Self::BpduGuard(read_bool(&content)?),
+            IFLA_BRPORT_PROTECT => Self::RootBlock(read_bool(&content)?),
+            IFLA_BRPORT_FAST_LEAVE => Self::FastLeave(read_bool(&content)?),
+            IFLA_BRPORT_LEARNING => Self::Learning(read_bool(&content)?),
+            IFLA_BRPORT_UNICAST_FLOOD => Self::UnicastFlood(read_bool(&content)?),
+            IFLA_BRPORT_PROXYARP => Self::ProxyArp(read_bool(&content)?),
+            IFLA_BRPORT_LEARNING_SYNC => Self::LearningSync(read_bool(&content)?),
+            IFLA_BRPORT_PROXYARP_WIFI => Self::ProxyArpWifi(read_bool(&content)?),
+            IFLA_BRPORT_ROOT_ID => Self::RootId(BridgeId::deserialize(&content)?),
+            IFLA_BRPORT_BRIDGE_ID => Self::BridgeId(BridgeId::deserialize(&content)?),
+            IFLA_BRPORT_DESIGNATED_PORT => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::DesignatedPort(u16::from_ne_bytes(content))
+            }
+            IFLA_BRPORT_DESIGNATED_COST => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::DesignatedCost(u16::from_ne_bytes(content))
+            }
+            IFLA_BRPORT_ID => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::Id(u16::from_ne_bytes(content))
+            }
+            IFLA_BRPORT_NO => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::Number(u16::from_ne_bytes(content))
+            }
+            IFLA_BRPORT_TOPOLOGY_CHANGE_ACK => Self::TopologyChangeAck(read_bool(&content)?),
+            IFLA_BRPORT_CONFIG_PENDING => Self::ConfigPending(read_bool(&content)?),
+            IFLA_BRPORT_MESSAGE_AGE_TIMER => {
+                let content = <[u8; 8]>::try_from(content).ok()?;
+                Self::MessageAgeTimer(u64::from_ne_bytes(content))
+            }
+            IFLA_BRPORT_FORWARD_DELAY_TIMER => {
+                let content = <[u8; 8]>::try_from(content).ok()?;
+                Self::ForwardDelayTimer(u64::from_ne_bytes(content))
+            }
+            IFLA_BRPORT_HOLD_TIMER => {
+                let content = <[u8; 8]>::try_from(content).ok()?;
+                Self::HoldTimer(u64::from_ne_bytes(content))
+            }
+            IFLA_BRPORT_FLUSH => Self::Flush,
+            IFLA_BRPORT_MULTICAST_ROUTER => Self::MulticastRouter(*content.first()?),
+            IFLA_BRPORT_PAD => Self::Pad(content),
+            IFLA_BRPORT_MCAST_FLOOD => Self::MulticastFlood(read_bool(&content)?),
+            IFLA_BRPORT_MCAST_TO_UCAST => Self::MulticastToUnicast(read_bool(&content)?),
+            IFLA_BRPORT_VLAN_TUNNEL => Self::VlanTunnel(read_bool(&content)?),
+            IFLA_BRPORT_BCAST_FLOOD => Self::BroadcastFlood(read_bool(&content)?),
+            IFLA_BRPORT_GROUP_FWD_MASK => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::GroupForwardMask(u16::from_ne_bytes(content))
+            }
+            IFLA_BRPORT_NEIGH_SUPPRESS => Self::NeighborSuppress(read_bool(&content)?),
+            IFLA_BRPORT_ISOLATED => Self::Isolated(read_bool(&content)?),
+            IFLA_BRPORT_BACKUP_PORT => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::BackupPort(u32::from_ne_bytes(content))
+            }
+            typ => Self::Other(typ, content),
+        };
+
+        Some(attr)
+    }
+}
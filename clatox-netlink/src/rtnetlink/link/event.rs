@@ -0,0 +1,65 @@
+pub const IFLA_EVENT_NONE: u32 = 0;
+pub const IFLA_EVENT_REBOOT: u32 = 1;
+pub const IFLA_EVENT_FEATURES: u32 = 2;
+pub const IFLA_EVENT_BONDING_FAILOVER: u32 = 3;
+pub const IFLA_EVENT_NOTIFY_PEERS: u32 = 4;
+pub const IFLA_EVENT_IGMP_RESEND: u32 = 5;
+pub const IFLA_EVENT_BONDING_OPTIONS: u32 = 6;
+
+/// The reason why the kernel sent an `RTM_NEWLINK` notification. It is only
+/// reported for some notifications, never in replies to requests.
+///
+/// It corresponds to `IFLA_EVENT_*` in <linux/if_link.h>.
+#[doc(alias("IFLA_EVENT_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterfaceEvent {
+    None,
+
+    /// The device is rebooting.
+    Reboot,
+
+    /// The offload features of the device changed.
+    Features,
+
+    /// The active slave of a bond changed.
+    BondingFailover,
+
+    /// The peers of the device should be notified, e.g. with gratuitous ARP.
+    NotifyPeers,
+
+    /// IGMP reports should be resent.
+    IgmpResend,
+
+    /// The options of a bond changed.
+    BondingOptions,
+
+    Other(u32),
+}
+
+impl InterfaceEvent {
+    pub const fn raw_value(&self) -> u32 {
+        match self {
+            Self::None => IFLA_EVENT_NONE,
+            Self::Reboot => IFLA_EVENT_REBOOT,
+            Self::Features => IFLA_EVENT_FEATURES,
+            Self::BondingFailover => IFLA_EVENT_BONDING_FAILOVER,
+            Self::NotifyPeers => IFLA_EVENT_NOTIFY_PEERS,
+            Self::IgmpResend => IFLA_EVENT_IGMP_RESEND,
+            Self::BondingOptions => IFLA_EVENT_BONDING_OPTIONS,
+            Self::Other(x) => *x,
+        }
+    }
+
+    pub const fn from_raw_value(value: u32) -> Self {
+        match value {
+            IFLA_EVENT_NONE => Self::None,
+            IFLA_EVENT_REBOOT => Self::Reboot,
+            IFLA_EVENT_FEATURES => Self::Features,
+            IFLA_EVENT_BONDING_FAILOVER => Self::BondingFailover,
+            IFLA_EVENT_NOTIFY_PEERS => Self::NotifyPeers,
+            IFLA_EVENT_IGMP_RESEND => Self::IgmpResend,
+            IFLA_EVENT_BONDING_OPTIONS => Self::BondingOptions,
+            x => Self::Other(x),
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use std::mem::{size_of, transmute};
+
+use crate::netlink::{Attribute, RawAttribute};
+use crate::utils;
+
+pub const IFLA_INET_UNSPEC: u16 = 0;
+pub const IFLA_INET_CONF: u16 = 1;
+
+pub const IPV4_DEVCONF_FORWARDING: u16 = 1;
+pub const IPV4_DEVCONF_MC_FORWARDING: u16 = 2;
+pub const IPV4_DEVCONF_PROXY_ARP: u16 = 3;
+pub const IPV4_DEVCONF_ACCEPT_REDIRECTS: u16 = 4;
+pub const IPV4_DEVCONF_SECURE_REDIRECTS: u16 = 5;
+pub const IPV4_DEVCONF_SEND_REDIRECTS: u16 = 6;
+pub const IPV4_DEVCONF_SHARED_MEDIA: u16 = 7;
+pub const IPV4_DEVCONF_RP_FILTER: u16 = 8;
+pub const IPV4_DEVCONF_ACCEPT_SOURCE_ROUTE: u16 = 9;
+pub const IPV4_DEVCONF_BOOTP_RELAY: u16 = 10;
+pub const IPV4_DEVCONF_LOG_MARTIANS: u16 = 11;
+pub const IPV4_DEVCONF_TAG: u16 = 12;
+pub const IPV4_DEVCONF_ARPFILTER: u16 = 13;
+pub const IPV4_DEVCONF_MEDIUM_ID: u16 = 14;
+pub const IPV4_DEVCONF_NOXFRM: u16 = 15;
+pub const IPV4_DEVCONF_NOPOLICY: u16 = 16;
+pub const IPV4_DEVCONF_FORCE_IGMP_VERSION: u16 = 17;
+pub const IPV4_DEVCONF_ARP_ANNOUNCE: u16 = 18;
+pub const IPV4_DEVCONF_ARP_IGNORE: u16 = 19;
+pub const IPV4_DEVCONF_PROMOTE_SECONDARIES: u16 = 20;
+pub const IPV4_DEVCONF_ARP_ACCEPT: u16 = 21;
+pub const IPV4_DEVCONF_ARP_NOTIFY: u16 = 22;
+pub const IPV4_DEVCONF_ACCEPT_LOCAL: u16 = 23;
+pub const IPV4_DEVCONF_SRC_VMARK: u16 = 24;
+pub const IPV4_DEVCONF_PROXY_ARP_PVLAN: u16 = 25;
+pub const IPV4_DEVCONF_ROUTE_LOCALNET: u16 = 26;
+pub const IPV4_DEVCONF_IGMPV2_UNSOLICITED_REPORT_INTERVAL: u16 = 27;
+pub const IPV4_DEVCONF_IGMPV3_UNSOLICITED_REPORT_INTERVAL: u16 = 28;
+pub const IPV4_DEVCONF_IGNORE_ROUTES_WITH_LINKDOWN: u16 = 29;
+pub const IPV4_DEVCONF_DROP_UNICAST_IN_L2_MULTICAST: u16 = 30;
+pub const IPV4_DEVCONF_DROP_GRATUITOUS_ARP: u16 = 31;
+pub const IPV4_DEVCONF_BC_FORWARDING: u16 = 32;
+pub const IPV4_DEVCONF_ARP_EVICT_NOCARRIER: u16 = 33;
+
+/// The per-interface IPv4 configuration, i.e. what can be found under
+/// `/proc/sys/net/ipv4/conf/<interface>`.
+///
+/// The fields are laid out in the order of `IPV4_DEVCONF_*` in <linux/ip.h>.
+/// Entries that the running kernel does not report are left as zero, while
+/// entries unknown to this crate are dropped.
+#[doc(alias("ipv4_devconf", "IPV4_DEVCONF_"))]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct InetConfiguration {
+    pub forwarding: i32,
+    pub mc_forwarding: i32,
+    pub proxy_arp: i32,
+    pub accept_redirects: i32,
+    pub secure_redirects: i32,
+    pub send_redirects: i32,
+    pub shared_media: i32,
+    pub rp_filter: i32,
+    pub accept_source_route: i32,
+    pub bootp_relay: i32,
+    pub log_martians: i32,
+    pub tag: i32,
+    pub arp_filter: i32,
+    pub medium_id: i32,
+    pub disable_xfrm: i32,
+    pub disable_policy: i32,
+    pub force_igmp_version: i32,
+    pub arp_announce: i32,
+    pub arp_ignore: i32,
+    pub promote_secondaries: i32,
+    pub arp_accept: i32,
+    pub arp_notify: i32,
+    pub accept_local: i32,
+    pub src_valid_mark: i32,
+    pub proxy_arp_pvlan: i32,
+    pub route_localnet: i32,
+    pub igmpv2_unsolicited_report_interval: i32,
+    pub igmpv3_unsolicited_report_interval: i32,
+    pub ignore_routes_with_linkdown: i32,
+    pub drop_unicast_in_l2_multicast: i32,
+    pub drop_gratuitous_arp: i32,
+    pub bc_forwarding: i32,
+    pub arp_evict_nocarrier: i32,
+}
+
+/// A single entry of the per-interface IPv4 configuration, used to change it
+/// through `RTM_SETLINK`.
+///
+/// Corresponds to `IPV4_DEVCONF_*` in libc.
+#[doc(alias("IPV4_DEVCONF_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InetConfigurationEntry {
+    Forwarding,
+    MulticastForwarding,
+    ProxyArp,
+    AcceptRedirects,
+    SecureRedirects,
+    SendRedirects,
+    SharedMedia,
+    ReversePathFilter,
+    AcceptSourceRoute,
+    BootpRelay,
+    LogMartians,
+    Tag,
+    ArpFilter,
+    MediumId,
+    DisableXfrm,
+    DisablePolicy,
+    ForceIgmpVersion,
+    ArpAnnounce,
+    ArpIgnore,
+    PromoteSecondaries,
+    ArpAccept,
+    ArpNotify,
+    AcceptLocal,
+    SourceValidMark,
+    ProxyArpPrivateVlan,
+    RouteLocalnet,
+    Igmpv2UnsolicitedReportInterval,
+    Igmpv3UnsolicitedReportInterval,
+    IgnoreRoutesWithLinkDown,
+    DropUnicastInL2Multicast,
+    DropGratuitousArp,
+    BroadcastForwarding,
+    ArpEvictNoCarrier,
+    Other(u16),
+}
+
+impl InetConfigurationEntry {
+    pub const fn raw_value(&self) -> u16 {
+        match self {
+            Self::Forwarding => IPV4_DEVCONF_FORWARDING,
+            Self::MulticastForwarding => IPV4_DEVCONF_MC_FORWARDING,
+            Self::ProxyArp => IPV4_DEVCONF_PROXY_ARP,
+            Self::AcceptRedirects => IPV4_DEVCONF_ACCEPT_REDIRECTS,
+            Self::SecureRedirects => IPV4_DEVCONF_SECURE_REDIRECTS,
+            Self::SendRedirects => IPV4_DEVCONF_SEND_REDIRECTS,
+            Self::SharedMedia => IPV4_DEVCONF_SHARED_MEDIA,
+            Self::ReversePathFilter => IPV4_DEVCONF_RP_FILTER,
+            Self::AcceptSourceRoute => IPV4_DEVCONF_ACCEPT_SOURCE_ROUTE,
+            Self::BootpRelay => IPV4_DEVCONF_BOOTP_RELAY,
+            Self::LogMartians => IPV4_DEVCONF_LOG_MARTIANS,
+            Self::Tag => IPV4_DEVCONF_TAG,
+            Self::ArpFilter => IPV4_DEVCONF_ARPFILTER,
+            Self::MediumId => IPV4_DEVCONF_MEDIUM_ID,
+            Self::DisableXfrm => IPV4_DEVCONF_NOXFRM,
+            Self::DisablePolicy => IPV4_DEVCONF_NOPOLICY,
+            Self::ForceIgmpVersion => IPV4_DEVCONF_FORCE_IGMP_VERSION,
+            Self::ArpAnnounce => IPV4_DEVCONF_ARP_ANNOUNCE,
+            Self::ArpIgnore => IPV4_DEVCONF_ARP_IGNORE,
+            Self::PromoteSecondaries => IPV4_DEVCONF_PROMOTE_SECONDARIES,
+            Self::ArpAccept => IPV4_DEVCONF_ARP_ACCEPT,
+            Self::ArpNotify => IPV4_DEVCONF_ARP_NOTIFY,
+            Self::AcceptLocal => IPV4_DEVCONF_ACCEPT_LOCAL,
+            Self::SourceValidMark => IPV4_DEVCONF_SRC_VMARK,
+            Self::ProxyArpPrivateVlan => IPV4_DEVCONF_PROXY_ARP_PVLAN,
+            Self::RouteLocalnet => IPV4_DEVCONF_ROUTE_LOCALNET,
+            Self::Igmpv2UnsolicitedReportInterval => {
+                IPV4_DEVCONF_IGMPV2_UNSOLICITED_REPORT_INTERVAL
+            }
+            Self::Igmpv3UnsolicitedReportInterval => {
+                IPV4_DEVCONF_IGMPV3_UNSOLICITED_REPORT_INTERVAL
+            }
+            Self::IgnoreRoutesWithLinkDown => IPV4_DEVCONF_IGNORE_ROUTES_WITH_LINKDOWN,
+            Self::DropUnicastInL2Multicast => IPV4_DEVCONF_DROP_UNICAST_IN_L2_MULTICAST,
+            Self::DropGratuitousArp => IPV4_DEVCONF_DROP_GRATUITOUS_ARP,
+            Self::BroadcastForwarding => IPV4_DEVCONF_BC_FORWARDING,
+            Self::ArpEvictNoCarrier => IPV4_DEVCONF_ARP_EVICT_NOCARRIER,
+            Self::Other(x) => *x,
+        }
+    }
+
+    pub const fn from_raw_value(value: u16) -> Self {
+        match value {
+            IPV4_DEVCONF_FORWARDING => Self::Forwarding,
+            IPV4_DEVCONF_MC_FORWARDING => Self::MulticastForwarding,
+            IPV4_DEVCONF_PROXY_ARP => Self::ProxyArp,
+            IPV4_DEVCONF_ACCEPT_REDIRECTS => Self::AcceptRedirects,
+            IPV4_DEVCONF_SECURE_REDIRECTS => Self::SecureRedirects,
+            IPV4_DEVCONF_SEND_REDIRECTS => Self::SendRedirects,
+            IPV4_DEVCONF_SHARED_MEDIA => Self::SharedMedia,
+            IPV4_DEVCONF_RP_FILTER => Self::ReversePathFilter,
+            IPV4_DEVCONF_ACCEPT_SOURCE_ROUTE => Self::AcceptSourceRoute,
+            IPV4_DEVCONF_BOOTP_RELAY => Self::BootpRelay,
+            IPV4_DEVCONF_LOG_MARTIANS => Self::LogMartians,
+            IPV4_DEVCONF_TAG => Self::Tag,
+            IPV4_DEVCONF_ARPFILTER => Self::ArpFilter,
+            IPV4_DEVCONF_MEDIUM_ID => Self::MediumId,
+            IPV4_DEVCONF_NOXFRM => Self::DisableXfrm,
+            IPV4_DEVCONF_NOPOLICY => Self::DisablePolicy,
+            IPV4_DEVCONF_FORCE_IGMP_VERSION => Self::ForceIgmpVersion,
+            IPV4_DEVCONF_ARP_ANNOUNCE => Self::ArpAnnounce,
+            IPV4_DEVCONF_ARP_IGNORE => Self::ArpIgnore,
+            IPV4_DEVCONF_PROMOTE_SECONDARIES => Self::PromoteSecondaries,
+            IPV4_DEVCONF_ARP_ACCEPT => Self::ArpAccept,
+            IPV4_DEVCONF_ARP_NOTIFY => Self::ArpNotify,
+            IPV4_DEVCONF_ACCEPT_LOCAL => Self::AcceptLocal,
+            IPV4_DEVCONF_SRC_VMARK => Self::SourceValidMark,
+            IPV4_DEVCONF_PROXY_ARP_PVLAN => Self::ProxyArpPrivateVlan,
+            IPV4_DEVCONF_ROUTE_LOCALNET => Self::RouteLocalnet,
+            IPV4_DEVCONF_IGMPV2_UNSOLICITED_REPORT_INTERVAL => {
+                Self::Igmpv2UnsolicitedReportInterval
+            }
+            IPV4_DEVCONF_IGMPV3_UNSOLICITED_REPORT_INTERVAL => {
+                Self::Igmpv3UnsolicitedReportInterval
+            }
+            IPV4_DEVCONF_IGNORE_ROUTES_WITH_LINKDOWN => Self::IgnoreRoutesWithLinkDown,
+            IPV4_DEVCONF_DROP_UNICAST_IN_L2_MULTICAST => Self::DropUnicastInL2Multicast,
+            IPV4_DEVCONF_DROP_GRATUITOUS_ARP => Self::DropGratuitousArp,
+            IPV4_DEVCONF_BC_FORWARDING => Self::BroadcastForwarding,
+            IPV4_DEVCONF_ARP_EVICT_NOCARRIER => Self::ArpEvictNoCarrier,
+            x => Self::Other(x),
+        }
+    }
+}
+
+/// IPv4-specific information of a link, nested in the `AF_INET` entry of
+/// `IFLA_AF_SPEC`. They correspond to `IFLA_INET_*` in libc.
+#[doc(alias("IFLA_INET_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InetAttribute {
+    /// `IFLA_INET_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `IFLA_INET_CONF` - the whole configuration, as reported by the kernel.
+    Configuration(InetConfiguration),
+
+    /// `IFLA_INET_CONF` - the entries of the configuration to be changed in an
+    /// `RTM_SETLINK` request. The others are left untouched.
+    ChangeConfiguration(Vec<(InetConfigurationEntry, i32)>),
+
+    Other(u16, Vec<u8>),
+}
+
+impl InetAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                IFLA_INET_UNSPEC
+            }
+            Self::Configuration(conf) => {
+                // SAFETY: InetConfiguration is a plain-old-data struct made of
+                // i32 only.
+                let entries = unsafe {
+                    transmute::<InetConfiguration, [i32; size_of::<InetConfiguration>() / 4]>(
+                        conf.clone(),
+                    )
+                };
+                for entry in entries {
+                    buffer.extend(entry.to_ne_bytes());
+                }
+                IFLA_INET_CONF
+            }
+            Self::ChangeConfiguration(entries) => {
+                for (entry, value) in entries {
+                    utils::serialize_attribute_into(buffer, |buffer| {
+                        buffer.extend(value.to_ne_bytes());
+                        entry.raw_value()
+                    });
+                }
+                IFLA_INET_CONF
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for InetAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            IFLA_INET_UNSPEC => Self::Unspecified(content),
+            IFLA_INET_CONF => {
+                const LEN: usize = size_of::<InetConfiguration>() / 4;
+
+                let mut entries = [0i32; LEN];
+                for (entry, chunk) in entries.iter_mut().zip(content.chunks_exact(4)) {
+                    *entry = i32::from_ne_bytes(chunk.try_into().ok()?);
+                }
+
+                // SAFETY: InetConfiguration is a plain-old-data struct made of
+                // i32 only.
+                let conf = unsafe { transmute::<[i32; LEN], InetConfiguration>(entries) };
+                Self::Configuration(conf)
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
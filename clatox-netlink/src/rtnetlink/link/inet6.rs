@@ -0,0 +1,446 @@
+use std::mem::{size_of, transmute};
+use std::net::Ipv6Addr;
+
+use crate::netlink::{Attribute, RawAttribute};
+use crate::utils;
+
+use bitflags::bitflags;
+
+pub const IFLA_INET6_UNSPEC: u16 = 0;
+pub const IFLA_INET6_FLAGS: u16 = 1;
+pub const IFLA_INET6_CONF: u16 = 2;
+pub const IFLA_INET6_STATS: u16 = 3;
+pub const IFLA_INET6_MCAST: u16 = 4;
+pub const IFLA_INET6_CACHEINFO: u16 = 5;
+pub const IFLA_INET6_ICMP6STATS: u16 = 6;
+pub const IFLA_INET6_TOKEN: u16 = 7;
+pub const IFLA_INET6_ADDR_GEN_MODE: u16 = 8;
+pub const IFLA_INET6_RA_MTU: u16 = 9;
+
+pub const IF_RS_SENT: u32 = 0x10;
+pub const IF_RA_RCVD: u32 = 0x20;
+pub const IF_RA_MANAGED: u32 = 0x40;
+pub const IF_RA_OTHERCONF: u32 = 0x80;
+pub const IF_READY: u32 = 0x80000000;
+
+pub const IN6_ADDR_GEN_MODE_EUI64: u8 = 0;
+pub const IN6_ADDR_GEN_MODE_NONE: u8 = 1;
+pub const IN6_ADDR_GEN_MODE_STABLE_PRIVACY: u8 = 2;
+pub const IN6_ADDR_GEN_MODE_RANDOM: u8 = 3;
+
+bitflags! {
+    /// A bitfield of the IPv6 state of an interface.
+    ///
+    /// It corresponds to `IF_RS_SENT`, `IF_RA_*` and `IF_READY` in
+    /// <linux/if_link.h>.
+    #[doc(alias("IF_RA_", "IF_RS_SENT", "IF_READY"))]
+    #[derive(Copy, Debug, Clone, PartialEq, Eq)]
+    pub struct Inet6Flags: u32 {
+        /// A router solicitation has been sent
+        const RouterSolicitationSent = IF_RS_SENT;
+        /// A router advertisement has been received
+        const RouterAdvertisementReceived = IF_RA_RCVD;
+        /// The M flag of the last router advertisement was set
+        const Managed = IF_RA_MANAGED;
+        /// The O flag of the last router advertisement was set
+        const OtherConfiguration = IF_RA_OTHERCONF;
+        const Ready = IF_READY;
+    }
+}
+
+/// How the kernel generates the link-local (and SLAAC) addresses of an
+/// interface.
+///
+/// Corresponds to `IN6_ADDR_GEN_MODE_*` in libc.
+#[doc(alias("in6_addr_gen_mode", "IN6_ADDR_GEN_MODE_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressGenerationMode {
+    /// `IN6_ADDR_GEN_MODE_EUI64` - derive the interface identifier from the
+    /// hardware address.
+    Eui64,
+
+    /// `IN6_ADDR_GEN_MODE_NONE` - do not generate addresses at all.
+    None,
+
+    /// `IN6_ADDR_GEN_MODE_STABLE_PRIVACY` - RFC 7217 stable identifiers.
+    StablePrivacy,
+
+    /// `IN6_ADDR_GEN_MODE_RANDOM`
+    Random,
+
+    Other(u8),
+}
+
+impl AddressGenerationMode {
+    pub const fn raw_value(&self) -> u8 {
+        match self {
+            Self::Eui64 => IN6_ADDR_GEN_MODE_EUI64,
+            Self::None => IN6_ADDR_GEN_MODE_NONE,
+            Self::StablePrivacy => IN6_ADDR_GEN_MODE_STABLE_PRIVACY,
+            Self::Random => IN6_ADDR_GEN_MODE_RANDOM,
+            Self::Other(x) => *x,
+        }
+    }
+
+    pub const fn from_raw_value(value: u8) -> Self {
+        match value {
+            IN6_ADDR_GEN_MODE_EUI64 => Self::Eui64,
+            IN6_ADDR_GEN_MODE_NONE => Self::None,
+            IN6_ADDR_GEN_MODE_STABLE_PRIVACY => Self::StablePrivacy,
+            IN6_ADDR_GEN_MODE_RANDOM => Self::Random,
+            x => Self::Other(x),
+        }
+    }
+}
+
+/// The per-interface IPv6 configuration, i.e. what can be found under
+/// `/proc/sys/net/ipv6/conf/<interface>`.
+///
+/// The fields are laid out in the order of `DEVCONF_*` in <linux/ipv6.h>.
+/// Entries that the running kernel does not report are left as zero, while
+/// entries unknown to this crate are dropped.
+#[doc(alias("ipv6_devconf", "DEVCONF_"))]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct Inet6Configuration {
+    pub forwarding: i32,
+    pub hop_limit: i32,
+    pub mtu6: i32,
+    pub accept_ra: i32,
+    pub accept_redirects: i32,
+    pub autoconf: i32,
+    pub dad_transmits: i32,
+    pub router_solicitations: i32,
+    pub router_solicitation_interval: i32,
+    pub router_solicitation_delay: i32,
+    pub use_tempaddr: i32,
+    pub temp_valid_lft: i32,
+    pub temp_prefered_lft: i32,
+    pub regen_max_retry: i32,
+    pub max_desync_factor: i32,
+    pub max_addresses: i32,
+    pub force_mld_version: i32,
+    pub accept_ra_defrtr: i32,
+    pub accept_ra_pinfo: i32,
+    pub accept_ra_rtr_pref: i32,
+    pub router_probe_interval: i32,
+    pub accept_ra_rt_info_max_plen: i32,
+    pub proxy_ndp: i32,
+    pub optimistic_dad: i32,
+    pub accept_source_route: i32,
+    pub mc_forwarding: i32,
+    pub disable_ipv6: i32,
+    pub accept_dad: i32,
+    pub force_tllao: i32,
+    pub ndisc_notify: i32,
+    pub mldv1_unsolicited_report_interval: i32,
+    pub mldv2_unsolicited_report_interval: i32,
+    pub suppress_frag_ndisc: i32,
+    pub accept_ra_from_local: i32,
+    pub use_optimistic: i32,
+    pub accept_ra_mtu: i32,
+    pub stable_secret: i32,
+    pub use_oif_addrs_only: i32,
+    pub accept_ra_min_hop_limit: i32,
+    pub ignore_routes_with_linkdown: i32,
+    pub drop_unicast_in_l2_multicast: i32,
+    pub drop_unsolicited_na: i32,
+    pub keep_addr_on_down: i32,
+    pub router_solicitation_max_interval: i32,
+    pub seg6_enabled: i32,
+    pub seg6_require_hmac: i32,
+    pub enhanced_dad: i32,
+    pub addr_gen_mode: i32,
+    pub disable_policy: i32,
+    pub accept_ra_rt_info_min_plen: i32,
+    pub ndisc_tclass: i32,
+    pub rpl_seg_enabled: i32,
+    pub ra_defrtr_metric: i32,
+    pub ioam6_enabled: i32,
+    pub ioam6_id: i32,
+    pub ioam6_id_wide: i32,
+    pub ndisc_evict_nocarrier: i32,
+    pub accept_untracked_na: i32,
+    pub accept_ra_min_lft: i32,
+}
+
+/// IPv6 statistics of an interface, as found in
+/// `/proc/net/dev_snmp6/<interface>`.
+///
+/// The fields are laid out in the order of `IPSTATS_MIB_*` in <linux/snmp.h>.
+/// Counters that the running kernel does not report are left as zero.
+#[doc(alias("IPSTATS_MIB_"))]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct Inet6Stats {
+    pub in_packets: u64,
+    pub in_octets: u64,
+    pub in_delivers: u64,
+    pub out_forwarded_datagrams: u64,
+    pub out_requests: u64,
+    pub out_octets: u64,
+    pub in_header_errors: u64,
+    pub in_too_big_errors: u64,
+    pub in_no_routes: u64,
+    pub in_address_errors: u64,
+    pub in_unknown_protocols: u64,
+    pub in_truncated_packets: u64,
+    pub in_discards: u64,
+    pub out_discards: u64,
+    pub out_no_routes: u64,
+    pub reassembly_timeout: u64,
+    pub reassembly_requireds: u64,
+    pub reassembly_oks: u64,
+    pub reassembly_fails: u64,
+    pub fragment_oks: u64,
+    pub fragment_fails: u64,
+    pub fragment_creates: u64,
+    pub in_multicast_packets: u64,
+    pub out_multicast_packets: u64,
+    pub in_broadcast_packets: u64,
+    pub out_broadcast_packets: u64,
+    pub in_multicast_octets: u64,
+    pub out_multicast_octets: u64,
+    pub in_broadcast_octets: u64,
+    pub out_broadcast_octets: u64,
+    pub checksum_errors: u64,
+    pub in_no_ect_packets: u64,
+    pub in_ect1_packets: u64,
+    pub in_ect0_packets: u64,
+    pub in_ce_packets: u64,
+    pub reassembly_overlaps: u64,
+    pub out_transmits: u64,
+}
+
+/// ICMPv6 statistics of an interface.
+///
+/// The fields are laid out in the order of `ICMP6_MIB_*` in <linux/snmp.h>.
+/// Counters that the running kernel does not report are left as zero.
+#[doc(alias("ICMP6_MIB_"))]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct Icmp6Stats {
+    pub in_messages: u64,
+    pub in_errors: u64,
+    pub out_messages: u64,
+    pub out_errors: u64,
+    pub in_checksum_errors: u64,
+    pub out_rate_limit_host: u64,
+}
+
+/// Neighbor discovery related timings of an interface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[doc(alias("ifla_cacheinfo"))]
+#[repr(C)]
+pub struct Inet6CacheInfo {
+    pub max_reassembly_len: u32,
+    /// Time of the last change, in hundredths of a second since boot
+    pub timestamp: u32,
+    /// In milliseconds
+    pub reachable_time: u32,
+    /// In milliseconds
+    pub retransmit_time: u32,
+}
+
+/// IPv6-specific information of a link, nested in the `AF_INET6` entry of
+/// `IFLA_AF_SPEC`. They correspond to `IFLA_INET6_*` in libc.
+///
+/// Only `Token` and `AddressGenerationMode` are accepted by the kernel in an
+/// `RTM_SETLINK` request, the rest are read-only.
+#[doc(alias("IFLA_INET6_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Inet6Attribute {
+    /// `IFLA_INET6_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `IFLA_INET6_FLAGS`
+    Flags(Inet6Flags),
+
+    /// `IFLA_INET6_CONF`
+    Configuration(Inet6Configuration),
+
+    /// `IFLA_INET6_STATS`
+    Stats(Inet6Stats),
+
+    /// `IFLA_INET6_MCAST`
+    Multicast(Vec<u8>),
+
+    /// `IFLA_INET6_CACHEINFO`
+    CacheInfo(Inet6CacheInfo),
+
+    /// `IFLA_INET6_ICMP6STATS`
+    Icmp6Stats(Icmp6Stats),
+
+    /// `IFLA_INET6_TOKEN` - the interface identifier used for SLAAC
+    /// addresses. Only the lower 64 bits are significant.
+    Token(Ipv6Addr),
+
+    /// `IFLA_INET6_ADDR_GEN_MODE`
+    AddressGenerationMode(AddressGenerationMode),
+
+    /// `IFLA_INET6_RA_MTU` - the MTU advertised by the last router
+    /// advertisement.
+    RouterAdvertisementMtu(u32),
+
+    Other(u16, Vec<u8>),
+}
+
+/// Serializes counters as the kernel does: a leading count (of all the
+/// entries, itself included) followed by the counters.
+fn serialize_counters(counters: &[u64], buffer: &mut Vec<u8>) {
+    buffer.extend((counters.len() as u64 + 1).to_ne_bytes());
+    for counter in counters {
+        buffer.extend(counter.to_ne_bytes());
+    }
+}
+
+fn deserialize_counters<const N: usize>(bytes: &[u8]) -> Option<[u64; N]> {
+    let count = u64::from_ne_bytes(bytes.get(..8)?.try_into().ok()?) as usize;
+
+    let mut counters = [0u64; N];
+    let chunks = bytes.chunks_exact(8).take(count).skip(1);
+    for (counter, chunk) in counters.iter_mut().zip(chunks) {
+        *counter = u64::from_ne_bytes(chunk.try_into().ok()?);
+    }
+
+    Some(counters)
+}
+
+impl Inet6Attribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                IFLA_INET6_UNSPEC
+            }
+            Self::Flags(flags) => {
+                buffer.extend(flags.bits().to_ne_bytes());
+                IFLA_INET6_FLAGS
+            }
+            Self::Configuration(conf) => {
+                // SAFETY: Inet6Configuration is a plain-old-data struct made of
+                // i32 only.
+                let entries = unsafe {
+                    transmute::<Inet6Configuration, [i32; size_of::<Inet6Configuration>() / 4]>(
+                        conf.clone(),
+                    )
+                };
+                for entry in entries {
+                    buffer.extend(entry.to_ne_bytes());
+                }
+                IFLA_INET6_CONF
+            }
+            Self::Stats(stats) => {
+                // SAFETY: Inet6Stats is a plain-old-data struct made of u64 only.
+                let counters = unsafe {
+                    transmute::<Inet6Stats, [u64; size_of::<Inet6Stats>() / 8]>(stats.clone())
+                };
+                serialize_counters(&counters, buffer);
+                IFLA_INET6_STATS
+            }
+            Self::Multicast(content) => {
+                buffer.extend(content.iter());
+                IFLA_INET6_MCAST
+            }
+            Self::CacheInfo(cache) => {
+                // SAFETY: Inet6CacheInfo does not contain any paddings.
+                let bytes = unsafe {
+                    transmute::<Inet6CacheInfo, [u8; size_of::<Inet6CacheInfo>()]>(cache.clone())
+                };
+                buffer.extend(bytes);
+                IFLA_INET6_CACHEINFO
+            }
+            Self::Icmp6Stats(stats) => {
+                // SAFETY: Icmp6Stats is a plain-old-data struct made of u64 only.
+                let counters = unsafe {
+                    transmute::<Icmp6Stats, [u64; size_of::<Icmp6Stats>() / 8]>(stats.clone())
+                };
+                serialize_counters(&counters, buffer);
+                IFLA_INET6_ICMP6STATS
+            }
+            Self::Token(token) => {
+                buffer.extend(token.octets());
+                IFLA_INET6_TOKEN
+            }
+            Self::AddressGenerationMode(mode) => {
+                buffer.push(mode.raw_value());
+                IFLA_INET6_ADDR_GEN_MODE
+            }
+            Self::RouterAdvertisementMtu(mtu) => {
+                buffer.extend(mtu.to_ne_bytes());
+                IFLA_INET6_RA_MTU
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for Inet6Attribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            IFLA_INET6_UNSPEC => Self::Unspecified(content),
+            IFLA_INET6_FLAGS => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Flags(Inet6Flags::from_bits_retain(u32::from_ne_bytes(content)))
+            }
+            IFLA_INET6_CONF => {
+                const LEN: usize = size_of::<Inet6Configuration>() / 4;
+
+                let mut entries = [0i32; LEN];
+                for (entry, chunk) in entries.iter_mut().zip(content.chunks_exact(4)) {
+                    *entry = i32::from_ne_bytes(chunk.try_into().ok()?);
+                }
+
+                // SAFETY: Inet6Configuration is a plain-old-data struct made of
+                // i32 only.
+                let conf = unsafe { transmute::<[i32; LEN], Inet6Configuration>(entries) };
+                Self::Configuration(conf)
+            }
+            IFLA_INET6_STATS => {
+                const LEN: usize = size_of::<Inet6Stats>() / 8;
+
+                let counters = deserialize_counters::<LEN>(&content)?;
+                // SAFETY: Inet6Stats is a plain-old-data struct made of u64 only.
+                Self::Stats(unsafe { transmute::<[u64; LEN], Inet6Stats>(counters) })
+            }
+            IFLA_INET6_MCAST => Self::Multicast(content),
+            IFLA_INET6_CACHEINFO => {
+                let content = <[u8; size_of::<Inet6CacheInfo>()]>::try_from(content).ok()?;
+                // SAFETY: Inet6CacheInfo is a plain-old-data struct and contains no
+                // paddings.
+                let cache = unsafe {
+                    transmute::<[u8; size_of::<Inet6CacheInfo>()], Inet6CacheInfo>(content)
+                };
+                Self::CacheInfo(cache)
+            }
+            IFLA_INET6_ICMP6STATS => {
+                const LEN: usize = size_of::<Icmp6Stats>() / 8;
+
+                let counters = deserialize_counters::<LEN>(&content)?;
+                // SAFETY: Icmp6Stats is a plain-old-data struct made of u64 only.
+                Self::Icmp6Stats(unsafe { transmute::<[u64; LEN], Icmp6Stats>(counters) })
+            }
+            IFLA_INET6_TOKEN => {
+                let content = <[u8; 16]>::try_from(content).ok()?;
+                Self::Token(Ipv6Addr::from(content))
+            }
+            IFLA_INET6_ADDR_GEN_MODE => {
+                let content = <[u8; 1]>::try_from(content).ok()?;
+                Self::AddressGenerationMode(AddressGenerationMode::from_raw_value(content[0]))
+            }
+            IFLA_INET6_RA_MTU => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::RouterAdvertisementMtu(u32::from_ne_bytes(content))
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
@@ -0,0 +1,148 @@
+use crate::netlink::{Attribute, RawAttribute};
+use crate::utils;
+
+use bitflags::bitflags;
+
+pub const IFLA_IPVLAN_UNSPEC: u16 = 0;
+pub const IFLA_IPVLAN_MODE: u16 = 1;
+pub const IFLA_IPVLAN_FLAGS: u16 = 2;
+
+pub const IPVLAN_MODE_L2: u16 = 0;
+pub const IPVLAN_MODE_L3: u16 = 1;
+pub const IPVLAN_MODE_L3S: u16 = 2;
+
+pub const IPVLAN_F_PRIVATE: u16 = 0x01;
+pub const IPVLAN_F_VEPA: u16 = 0x02;
+
+/// The mode of an ipvlan (or ipvtap) device. Corresponds to `IPVLAN_MODE_*`
+/// in libc.
+#[doc(alias("IPVLAN_MODE_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpVlanMode {
+    /// Switch on L2 - the slaves see the broadcast and multicast traffic.
+    L2,
+
+    /// Switch on L3 - the parent routes the traffic to the slaves.
+    L3,
+
+    /// Like L3, but the traffic passes through netfilter on the slaves.
+    L3S,
+
+    /// Any other value
+    Other(u16),
+}
+
+impl IpVlanMode {
+    pub const fn raw_value(&self) -> u16 {
+        match self {
+            Self::L2 => IPVLAN_MODE_L2,
+            Self::L3 => IPVLAN_MODE_L3,
+            Self::L3S => IPVLAN_MODE_L3S,
+            Self::Other(x) => *x,
+        }
+    }
+
+    pub const fn from_raw_value(value: u16) -> Self {
+        match value {
+            IPVLAN_MODE_L2 => Self::L2,
+            IPVLAN_MODE_L3 => Self::L3,
+            IPVLAN_MODE_L3S => Self::L3S,
+            x => Self::Other(x),
+        }
+    }
+}
+
+bitflags! {
+    /// A bitfield of ipvlan device flags. Without any of those flags, the
+    /// device operates in bridge mode.
+    ///
+    /// It corresponds to `IPVLAN_F_*` in libc.
+    #[doc(alias("IPVLAN_F_"))]
+    #[derive(Copy, Debug, Clone, PartialEq, Eq)]
+    pub struct IpVlanFlags: u16 {
+        const Private = IPVLAN_F_PRIVATE;
+        const Vepa = IPVLAN_F_VEPA;
+    }
+}
+
+/// Attributes nested in `IFLA_INFO_DATA` for links of the `ipvlan` and
+/// `ipvtap` kinds. They correspond to `IFLA_IPVLAN_*` in libc.
+#[doc(alias("IFLA_IPVLAN_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IpVlanAttribute {
+    /// `IFLA_IPVLAN_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `IFLA_IPVLAN_MODE`
+    Mode(IpVlanMode),
+
+    /// `IFLA_IPVLAN_FLAGS`
+    Flags(IpVlanFlags),
+
+    Other(u16, Vec<u8>),
+}
+
+impl IpVlanAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                IFLA_IPVLAN_UNSPEC
+            }
+            Self::Mode(mode) => {
+                buffer.extend(mode.raw_value().to_ne_bytes());
+                IFLA_IPVLAN_MODE
+            }
+            Self::Flags(flags) => {
+                buffer.extend(flags.bits().to_ne_bytes());
+                IFLA_IPVLAN_FLAGS
+            }
+            Self::Other(typ, content) => {
+                buffer.extend(content.iter());
+                *typ
+            }
+        })
+    }
+}
+
+impl Attribute for IpVlanAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            IFLA_IPVLAN_UNSPEC => Self::Unspecified(content),
+            IFLA_IPVLAN_MODE => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::Mode(IpVlanMode::from_raw_value(u16::from_ne_bytes(content)))
+            }
+            IFLA_IPVLAN_FLAGS => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::Flags(IpVlanFlags::from_bits_retain(u16::from_ne_bytes(content)))
+            }
+            typ => Self::Other(typ, content),
+        };
+
+        Some(attr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mode_and_flags() {
+        let raw = RawAttribute::new(IFLA_IPVLAN_MODE, IPVLAN_MODE_L3S.to_ne_bytes().to_vec());
+        assert_eq!(IpVlanAttribute::from_raw(raw), Some(IpVlanAttribute::Mode(IpVlanMode::L3S)));
+
+        let raw = RawAttribute::new(IFLA_IPVLAN_MODE, 7u16.to_ne_bytes().to_vec());
+        let attr = IpVlanAttribute::from_raw(raw);
+        assert_eq!(attr, Some(IpVlanAttribute::Mode(IpVlanMode::Other(7))));
+
+        let mut buffer = Vec::new();
+        IpVlanAttribute::Flags(IpVlanFlags::Private).serialize_into(&mut buffer);
+        assert_eq!(u16::from_ne_bytes([buffer[0], buffer[1]]), 6);
+        assert_eq!(&buffer[4..6], &IPVLAN_F_PRIVATE.to_ne_bytes());
+    }
+}
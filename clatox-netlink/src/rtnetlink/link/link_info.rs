@@ -1,29 +1,187 @@
 use libc::*;
 
-use crate::netlink::{Attribute, RawAttribute};
+use crate::netlink::{Attribute, RawAttribute, RawAttributeIter};
 use crate::utils;
 
+use super::{
+    BondAttribute, BondSlaveAttribute, BridgeAttribute, BridgePortAttribute, IpVlanAttribute,
+    MacVlanAttribute, TunAttribute, VlanAttribute, VxlanAttribute,
+};
+
 /// Information of a link interface. Corresponds to `IFLA_INFO_*` in libc.
 ///
 /// LinkInfo is really similar to InterfaceInfoAttribute in format. In Netlink
 /// terms, it is called a nested attribute.
 ///
+/// The content of `IFLA_INFO_DATA` depends on `IFLA_INFO_KIND` (and likewise
+/// for the slave counterparts), so a list of LinkInfo should be deserialized as
+/// a whole using [`LinkInfo::deserialize_list`].
 #[doc(alias("ifinfomsg", "IFLA_INFO_"))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[repr(u32)]
 pub enum LinkInfo {
     /// `IFLA_INFO_UNSPEC`
     Unspecified(Vec<u8>),
-    /// `IFLA_INFO_KIND`
-    Kind(Vec<u8>),
+    /// `IFLA_INFO_KIND` - the kind of the link, e.g. `vlan` or `vxlan`.
+    Kind(String),
     /// `IFLA_INFO_DATA`
-    Data(Vec<u8>),
+    Data(LinkInfoData),
     /// `IFLA_INFO_XSTATS`
     ExtendedStats(Vec<u8>),
-    /// `IFLA_INFO_SLAVE_KIND`
-    SlaveKind(Vec<u8>),
+    /// `IFLA_INFO_SLAVE_KIND` - the kind of the master, e.g. `bridge`.
+    SlaveKind(String),
     /// `IFLA_INFO_SLAVE_DATA`
-    SlaveData(Vec<u8>),
+    SlaveData(LinkInfoSlaveData),
+    Other(u16, Vec<u8>),
+}
+
+/// Kind-specific link configuration, nested in `IFLA_INFO_DATA`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkInfoData {
+    /// `vlan`
+    Vlan(Vec<VlanAttribute>),
+
+    /// `macvlan`
+    MacVlan(Vec<MacVlanAttribute>),
+
+    /// `macvtap`
+    MacVtap(Vec<MacVlanAttribute>),
+
+    /// `ipvlan`
+    IpVlan(Vec<IpVlanAttribute>),
+
+    /// `ipvtap`
+    IpVtap(Vec<IpVlanAttribute>),
+
+    /// `vxlan`
+    Vxlan(Vec<VxlanAttribute>),
+
+    /// `bridge`
+    Bridge(Vec<BridgeAttribute>),
+
+    /// `bond`
+    Bond(Vec<BondAttribute>),
+
+    /// `tun`, reported for both TUN and TAP devices
+    Tun(Vec<TunAttribute>),
+
+    /// `wireguard`, which has no attributes of its own. The device is
+    /// configured through the WireGuard generic netlink family instead, see
+    /// `set_wireguard_device`.
+    Wireguard,
+
+    /// Data of link kinds that are not (yet) typed.
+    Other(Vec<u8>),
+}
+
+impl LinkInfoData {
+    /// The value of `IFLA_INFO_KIND` that this data belongs to. Returns `None`
+    /// for `Other`.
+    pub const fn kind(&self) -> Option<&'static str> {
+        match self {
+            Self::Vlan(_) => Some("vlan"),
+            Self::MacVlan(_) => Some("macvlan"),
+            Self::MacVtap(_) => Some("macvtap"),
+            Self::IpVlan(_) => Some("ipvlan"),
+            Self::IpVtap(_) => Some("ipvtap"),
+            Self::Vxlan(_) => Some("vxlan"),
+            Self::Bridge(_) => Some("bridge"),
+            Self::Bond(_) => Some("bond"),
+            Self::Tun(_) => Some("tun"),
+            Self::Wireguard => Some("wireguard"),
+            Self::Other(_) => None,
+        }
+    }
+
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        match self {
+            Self::Vlan(attrs) => attrs.iter().for_each(|a| a.serialize_into(buffer)),
+            Self::MacVlan(attrs) | Self::MacVtap(attrs) => {
+                attrs.iter().for_each(|a| a.serialize_into(buffer))
+            }
+            Self::IpVlan(attrs) | Self::IpVtap(attrs) => {
+                attrs.iter().for_each(|a| a.serialize_into(buffer))
+            }
+            Self::Vxlan(attrs) => attrs.iter().for_each(|a| a.serialize_into(buffer)),
+            Self::Bridge(attrs) => attrs.iter().for_each(|a| a.serialize_into(buffer)),
+            Self::Bond(attrs) => attrs.iter().for_each(|a| a.serialize_into(buffer)),
+            Self::Tun(attrs) => attrs.iter().for_each(|a| a.serialize_into(buffer)),
+            Self::Wireguard => {}
+            Self::Other(content) => buffer.extend(content.iter()),
+        }
+    }
+
+    /// Deserializes the content of `IFLA_INFO_DATA` according to the link kind.
+    /// Unknown kinds are kept as `Other`.
+    pub fn deserialize(kind: &str, bytes: &[u8]) -> Option<Self> {
+        let data = match kind {
+            "vlan" => Self::Vlan(nested(bytes)?),
+            "macvlan" => Self::MacVlan(nested(bytes)?),
+            "macvtap" => Self::MacVtap(nested(bytes)?),
+            "ipvlan" => Self::IpVlan(nested(bytes)?),
+            "ipvtap" => Self::IpVtap(nested(bytes)?),
+            "vxlan" => Self::Vxlan(nested(bytes)?),
+            "bridge" => Self::Bridge(nested(bytes)?),
+            "bond" => Self::Bond(nested(bytes)?),
+            "tun" => Self::Tun(nested(bytes)?),
+            "wireguard" => Self::Wireguard,
+            _ => Self::Other(bytes.to_vec()),
+        };
+
+        Some(data)
+    }
+}
+
+/// Configuration of a link with regards to its master, nested in
+/// `IFLA_INFO_SLAVE_DATA`. The variants are named after the kind of the master.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkInfoSlaveData {
+    /// `bridge`
+    Bridge(Vec<BridgePortAttribute>),
+
+    /// `bond`
+    Bond(Vec<BondSlaveAttribute>),
+
+    /// Data of master kinds that are not (yet) typed.
+    Other(Vec<u8>),
+}
+
+impl LinkInfoSlaveData {
+    /// The value of `IFLA_INFO_SLAVE_KIND` that this data belongs to. Returns
+    /// `None` for `Other`.
+    pub const fn kind(&self) -> Option<&'static str> {
+        match self {
+            Self::Bridge(_) => Some("bridge"),
+            Self::Bond(_) => Some("bond"),
+            Self::Other(_) => None,
+        }
+    }
+
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        match self {
+            Self::Bridge(attrs) => attrs.iter().for_each(|a| a.serialize_into(buffer)),
+            Self::Bond(attrs) => attrs.iter().for_each(|a| a.serialize_into(buffer)),
+            Self::Other(content) => buffer.extend(content.iter()),
+        }
+    }
+
+    /// Deserializes the content of `IFLA_INFO_SLAVE_DATA` according to the
+    /// master kind. Unknown kinds are kept as `Other`.
+    pub fn deserialize(kind: &str, bytes: &[u8]) -> Option<Self> {
+        let data = match kind {
+            "bridge" => Self::Bridge(nested(bytes)?),
+            "bond" => Self::Bond(nested(bytes)?),
+            _ => Self::Other(bytes.to_vec()),
+        };
+
+        Some(data)
+    }
+}
+
+fn nested<A: Attribute>(bytes: &[u8]) -> Option<Vec<A>> {
+    RawAttributeIter::new(bytes.iter().cloned())
+        .map(A::from_raw)
+        .try_collect()
 }
 
 impl LinkInfo {
@@ -33,28 +191,70 @@ impl LinkInfo {
                 buffer.extend(content.iter());
                 IFLA_INFO_UNSPEC
             }
-            Self::Kind(content) => {
-                buffer.extend(content.iter());
+            Self::Kind(kind) => {
+                buffer.extend(kind.bytes());
+                buffer.push(0u8); // zero-terminated string
                 IFLA_INFO_KIND
             }
-            Self::Data(content) => {
-                buffer.extend(content.iter());
+            Self::Data(data) => {
+                data.serialize_into(buffer);
                 IFLA_INFO_DATA
             }
             Self::ExtendedStats(content) => {
                 buffer.extend(content.iter());
                 IFLA_INFO_XSTATS
             }
-            Self::SlaveKind(content) => {
-                buffer.extend(content.iter());
+            Self::SlaveKind(kind) => {
+                buffer.extend(kind.bytes());
+                buffer.push(0u8); // zero-terminated string
                 IFLA_INFO_SLAVE_KIND
             }
-            Self::SlaveData(content) => {
-                buffer.extend(content.iter());
+            Self::SlaveData(data) => {
+                data.serialize_into(buffer);
                 IFLA_INFO_SLAVE_DATA
             }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
         });
     }
+
+    /// Deserializes the attributes nested in `IFLA_LINKINFO`. Unlike
+    /// [`Attribute::from_raw`], this decodes `IFLA_INFO_DATA` and
+    /// `IFLA_INFO_SLAVE_DATA` according to the `IFLA_INFO_KIND` and
+    /// `IFLA_INFO_SLAVE_KIND` found in the same list.
+    pub fn deserialize_list(bytes: &[u8]) -> Option<Vec<Self>> {
+        let mut infos: Vec<Self> = RawAttributeIter::new(bytes.iter().cloned())
+            .map(LinkInfo::from_raw)
+            .try_collect()?;
+
+        let kind = infos.iter().find_map(|info| match info {
+            Self::Kind(kind) => Some(kind.clone()),
+            _ => None,
+        });
+
+        let slave_kind = infos.iter().find_map(|info| match info {
+            Self::SlaveKind(kind) => Some(kind.clone()),
+            _ => None,
+        });
+
+        for info in infos.iter_mut() {
+            let decoded = match (&*info, &kind, &slave_kind) {
+                (Self::Data(LinkInfoData::Other(content)), Some(kind), _) => {
+                    Self::Data(LinkInfoData::deserialize(kind, content)?)
+                }
+                (Self::SlaveData(LinkInfoSlaveData::Other(content)), _, Some(kind)) => {
+                    Self::SlaveData(LinkInfoSlaveData::deserialize(kind, content)?)
+                }
+                _ => continue,
+            };
+
+            *info = decoded;
+        }
+
+        Some(infos)
+    }
 }
 
 impl Attribute for LinkInfo {
@@ -64,14 +264,62 @@ impl Attribute for LinkInfo {
 
         let attr = match attr_type {
             IFLA_INFO_UNSPEC => LinkInfo::Unspecified(content),
-            IFLA_INFO_KIND => LinkInfo::Kind(content),
-            IFLA_INFO_DATA => LinkInfo::Data(content),
+            IFLA_INFO_KIND => {
+                let mut content = String::from_utf8(content).ok()?;
+                if content.ends_with('\0') {
+                    content.pop();
+                }
+                LinkInfo::Kind(content)
+            }
+            IFLA_INFO_DATA => LinkInfo::Data(LinkInfoData::Other(content)),
             IFLA_INFO_XSTATS => LinkInfo::ExtendedStats(content),
-            IFLA_INFO_SLAVE_KIND => LinkInfo::SlaveKind(content),
-            IFLA_INFO_SLAVE_DATA => LinkInfo::SlaveData(content),
-            x @ _ => panic!("unknown LinkInfo type: {}", x),
+            IFLA_INFO_SLAVE_KIND => {
+                let mut content = String::from_utf8(content).ok()?;
+                if content.ends_with('\0') {
+                    content.pop();
+                }
+                LinkInfo::SlaveKind(content)
+            }
+            IFLA_INFO_SLAVE_DATA => LinkInfo::SlaveData(LinkInfoSlaveData::Other(content)),
+            x => LinkInfo::Other(x, content),
         };
 
         Some(attr)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rtnetlink::VlanProtocol;
+
+    #[test]
+    fn data_follows_the_kind() {
+        // The payload of IFLA_LINKINFO of a VLAN, with an attribute of a
+        // newer kernel
+        let mut bytes = Vec::new();
+        LinkInfo::Kind("vlan".to_owned()).serialize_into(&mut bytes);
+        utils::serialize_attribute_into(&mut bytes, |buffer| {
+            VlanAttribute::Id(100).serialize_into(buffer);
+            VlanAttribute::Protocol(VlanProtocol::Ieee8021AD).serialize_into(buffer);
+            IFLA_INFO_DATA
+        });
+        utils::serialize_attribute_into(&mut bytes, |buffer| {
+            buffer.extend([1, 2, 3, 4]);
+            42
+        });
+
+        let infos = LinkInfo::deserialize_list(&bytes).unwrap();
+        assert_eq!(
+            infos,
+            [
+                LinkInfo::Kind("vlan".to_owned()),
+                LinkInfo::Data(LinkInfoData::Vlan(vec![
+                    VlanAttribute::Id(100),
+                    VlanAttribute::Protocol(VlanProtocol::Ieee8021AD),
+                ])),
+                LinkInfo::Other(42, vec![1, 2, 3, 4]),
+            ]
+        );
+    }
+}
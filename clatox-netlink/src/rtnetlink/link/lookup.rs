@@ -0,0 +1,40 @@
+use std::io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult};
+
+use crate::netlink::{Flags, Message, ReceivedMessage, Socket};
+
+use super::{ArpHardware, GetLink, InterfaceFlags, InterfaceInfoAttribute, InterfaceInfoMessage};
+use super::NewLink;
+
+/// Looks up the index of the interface of the given name, which may also be
+/// one of its alternative names.
+///
+/// Fails with `ENODEV` if there is no such interface.
+pub fn resolve_interface_index(socket: &mut Socket, name: &str) -> IoResult<u32> {
+    let attribute = if name.len() < libc::IFNAMSIZ {
+        InterfaceInfoAttribute::InterfaceName(name.to_owned())
+    } else {
+        InterfaceInfoAttribute::AlternativeName(name.to_owned())
+    };
+
+    let message = Message::new(
+        Flags::Request,
+        GetLink(InterfaceInfoMessage::new(
+            ArpHardware::Ethernet,
+            0,
+            InterfaceFlags::empty(),
+            vec![attribute],
+        )),
+    );
+    socket.send_message(&message)?;
+
+    match socket.receive_message::<NewLink>()? {
+        // Interface indexes are always positive
+        ReceivedMessage::Message(reply) => {
+            u32::try_from(reply.payload().0.index()).map_err(|_| IoErrorKind::InvalidData.into())
+        }
+        ReceivedMessage::Error(error) => {
+            Err(IoError::from_raw_os_error(-error.payload().error_code()))
+        }
+        ReceivedMessage::Multipart(_) => Err(IoErrorKind::InvalidData.into()),
+    }
+}
@@ -0,0 +1,309 @@
+use crate::netlink::{Attribute, RawAttribute, RawAttributeIter};
+use crate::utils;
+
+use bitflags::bitflags;
+
+pub const IFLA_MACVLAN_UNSPEC: u16 = 0;
+pub const IFLA_MACVLAN_MODE: u16 = 1;
+pub const IFLA_MACVLAN_FLAGS: u16 = 2;
+pub const IFLA_MACVLAN_MACADDR_MODE: u16 = 3;
+pub const IFLA_MACVLAN_MACADDR: u16 = 4;
+pub const IFLA_MACVLAN_MACADDR_DATA: u16 = 5;
+pub const IFLA_MACVLAN_MACADDR_COUNT: u16 = 6;
+pub const IFLA_MACVLAN_BC_QUEUE_LEN: u16 = 7;
+pub const IFLA_MACVLAN_BC_QUEUE_LEN_USED: u16 = 8;
+pub const IFLA_MACVLAN_BC_CUTOFF: u16 = 9;
+
+pub const MACVLAN_MODE_PRIVATE: u32 = 1;
+pub const MACVLAN_MODE_VEPA: u32 = 2;
+pub const MACVLAN_MODE_BRIDGE: u32 = 4;
+pub const MACVLAN_MODE_PASSTHRU: u32 = 8;
+pub const MACVLAN_MODE_SOURCE: u32 = 16;
+
+pub const MACVLAN_MACADDR_ADD: u32 = 0;
+pub const MACVLAN_MACADDR_DEL: u32 = 1;
+pub const MACVLAN_MACADDR_FLUSH: u32 = 2;
+pub const MACVLAN_MACADDR_SET: u32 = 3;
+
+pub const MACVLAN_FLAG_NOPROMISC: u16 = 1;
+pub const MACVLAN_FLAG_NODST: u16 = 2;
+
+/// The mode of a macvlan (or macvtap) device, determining how it exchanges
+/// traffic with its siblings on the same parent. Corresponds to
+/// `MACVLAN_MODE_*` in libc.
+#[doc(alias("MACVLAN_MODE_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MacVlanMode {
+    /// Don't talk to other macvlans
+    Private,
+
+    /// Talk to other ports through an external bridge
+    Vepa,
+
+    /// Talk to bridge ports directly
+    Bridge,
+
+    /// Take over the underlying device
+    PassThrough,
+
+    /// Use source MAC address list to assign
+    Source,
+
+    /// Any other value
+    Other(u32),
+}
+
+impl MacVlanMode {
+    pub const fn raw_value(&self) -> u32 {
+        match self {
+            Self::Private => MACVLAN_MODE_PRIVATE,
+            Self::Vepa => MACVLAN_MODE_VEPA,
+            Self::Bridge => MACVLAN_MODE_BRIDGE,
+            Self::PassThrough => MACVLAN_MODE_PASSTHRU,
+            Self::Source => MACVLAN_MODE_SOURCE,
+            Self::Other(x) => *x,
+        }
+    }
+
+    pub const fn from_raw_value(value: u32) -> Self {
+        match value {
+            MACVLAN_MODE_PRIVATE => Self::Private,
+            MACVLAN_MODE_VEPA => Self::Vepa,
+            MACVLAN_MODE_BRIDGE => Self::Bridge,
+            MACVLAN_MODE_PASSTHRU => Self::PassThrough,
+            MACVLAN_MODE_SOURCE => Self::Source,
+            x => Self::Other(x),
+        }
+    }
+}
+
+/// The operation to be done on the source MAC address list of a macvlan device
+/// in the `Source` mode. Corresponds to `MACVLAN_MACADDR_*` in libc.
+#[doc(alias("MACVLAN_MACADDR_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MacVlanMacAddressMode {
+    Add,
+    Delete,
+    Flush,
+    Set,
+
+    /// Any other value
+    Other(u32),
+}
+
+impl MacVlanMacAddressMode {
+    pub const fn raw_value(&self) -> u32 {
+        match self {
+            Self::Add => MACVLAN_MACADDR_ADD,
+            Self::Delete => MACVLAN_MACADDR_DEL,
+            Self::Flush => MACVLAN_MACADDR_FLUSH,
+            Self::Set => MACVLAN_MACADDR_SET,
+            Self::Other(x) => *x,
+        }
+    }
+
+    pub const fn from_raw_value(value: u32) -> Self {
+        match value {
+            MACVLAN_MACADDR_ADD => Self::Add,
+            MACVLAN_MACADDR_DEL => Self::Delete,
+            MACVLAN_MACADDR_FLUSH => Self::Flush,
+            MACVLAN_MACADDR_SET => Self::Set,
+            x => Self::Other(x),
+        }
+    }
+}
+
+bitflags! {
+    /// A bitfield of macvlan device flags.
+    ///
+    /// It corresponds to `MACVLAN_FLAG_*` in libc.
+    #[doc(alias("MACVLAN_FLAG_"))]
+    #[derive(Copy, Debug, Clone, PartialEq, Eq)]
+    pub struct MacVlanFlags: u16 {
+        /// Do not put the parent device into promiscuous mode (passthru only)
+        const NoPromiscuous = MACVLAN_FLAG_NOPROMISC;
+        const NoDestination = MACVLAN_FLAG_NODST;
+    }
+}
+
+/// Attributes nested in `IFLA_INFO_DATA` for links of the `macvlan` and
+/// `macvtap` kinds. They correspond to `IFLA_MACVLAN_*` in libc.
+#[doc(alias("IFLA_MACVLAN_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MacVlanAttribute {
+    /// `IFLA_MACVLAN_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `IFLA_MACVLAN_MODE`
+    Mode(MacVlanMode),
+
+    /// `IFLA_MACVLAN_FLAGS`
+    Flags(MacVlanFlags),
+
+    /// `IFLA_MACVLAN_MACADDR_MODE`
+    MacAddressMode(MacVlanMacAddressMode),
+
+    /// `IFLA_MACVLAN_MACADDR` - a single source MAC address, to be used with
+    /// `MacAddressMode`.
+    MacAddress([u8; 6]),
+
+    /// `IFLA_MACVLAN_MACADDR_DATA` - the list of source MAC addresses.
+    MacAddressData(Vec<[u8; 6]>),
+
+    /// `IFLA_MACVLAN_MACADDR_COUNT`
+    MacAddressCount(u32),
+
+    /// `IFLA_MACVLAN_BC_QUEUE_LEN`
+    BroadcastQueueLength(u32),
+
+    /// `IFLA_MACVLAN_BC_QUEUE_LEN_USED`
+    BroadcastQueueLengthUsed(u32),
+
+    /// `IFLA_MACVLAN_BC_CUTOFF`
+    BroadcastCutoff(i32),
+
+    Other(u16, Vec<u8>),
+}
+
+impl MacVlanAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                IFLA_MACVLAN_UNSPEC
+            }
+            Self::Mode(mode) => {
+                buffer.extend(mode.raw_value().to_ne_bytes());
+                IFLA_MACVLAN_MODE
+            }
+            Self::Flags(flags) => {
+                buffer.extend(flags.bits().to_ne_bytes());
+                IFLA_MACVLAN_FLAGS
+            }
+            Self::MacAddressMode(mode) => {
+                buffer.extend(mode.raw_value().to_ne_bytes());
+                IFLA_MACVLAN_MACADDR_MODE
+            }
+            Self::MacAddress(addr) => {
+                buffer.extend(addr.iter());
+                IFLA_MACVLAN_MACADDR
+            }
+            Self::MacAddressData(addrs) => {
+                for addr in addrs {
+                    Self::MacAddress(*addr).serialize_into(buffer);
+                }
+                IFLA_MACVLAN_MACADDR_DATA
+            }
+            Self::MacAddressCount(count) => {
+                buffer.extend(count.to_ne_bytes());
+                IFLA_MACVLAN_MACADDR_COUNT
+            }
+            Self::BroadcastQueueLength(len) => {
+                buffer.extend(len.to_ne_bytes());
+                IFLA_MACVLAN_BC_QUEUE_LEN
+            }
+            Self::BroadcastQueueLengthUsed(len) => {
+                buffer.extend(len.to_ne_bytes());
+                IFLA_MACVLAN_BC_QUEUE_LEN_USED
+            }
+            Self::BroadcastCutoff(cutoff) => {
+                buffer.extend(cutoff.to_ne_bytes());
+                IFLA_MACVLAN_BC_CUTOFF
+            }
+            Self::Other(typ, content) => {
+                buffer.extend(content.iter());
+                *typ
+            }
+        })
+    }
+}
+
+impl Attribute for MacVlanAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            IFLA_MACVLAN_UNSPEC => Self::Unspecified(content),
+            IFLA_MACVLAN_MODE => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Mode(MacVlanMode::from_raw_value(u32::from_ne_bytes(content)))
+            }
+            IFLA_MACVLAN_FLAGS => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::Flags(MacVlanFlags::from_bits_retain(u16::from_ne_bytes(content)))
+            }
+            IFLA_MACVLAN_MACADDR_MODE => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                let mode = MacVlanMacAddressMode::from_raw_value(u32::from_ne_bytes(content));
+                Self::MacAddressMode(mode)
+            }
+            IFLA_MACVLAN_MACADDR => Self::MacAddress(<[u8; 6]>::try_from(content).ok()?),
+            IFLA_MACVLAN_MACADDR_DATA => {
+                let addrs = RawAttributeIter::new(content.into_iter())
+                    .filter(|attr| attr.attr_type() == IFLA_MACVLAN_MACADDR)
+                    .map(|attr| <[u8; 6]>::try_from(attr.into_payload()).ok())
+                    .try_collect()?;
+                Self::MacAddressData(addrs)
+            }
+            IFLA_MACVLAN_MACADDR_COUNT => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::MacAddressCount(u32::from_ne_bytes(content))
+            }
+            IFLA_MACVLAN_BC_QUEUE_LEN => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::BroadcastQueueLength(u32::from_ne_bytes(content))
+            }
+            IFLA_MACVLAN_BC_QUEUE_LEN_USED => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::BroadcastQueueLengthUsed(u32::from_ne_bytes(content))
+            }
+            IFLA_MACVLAN_BC_CUTOFF => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::BroadcastCutoff(i32::from_ne_bytes(content))
+            }
+            typ => Self::Other(typ, content),
+        };
+
+        Some(attr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mode() {
+        let raw = RawAttribute::new(IFLA_MACVLAN_MODE, MACVLAN_MODE_BRIDGE.to_ne_bytes().to_vec());
+        let attr = MacVlanAttribute::from_raw(raw).unwrap();
+        assert_eq!(attr, MacVlanAttribute::Mode(MacVlanMode::Bridge));
+
+        let mut buffer = Vec::new();
+        MacVlanAttribute::Mode(MacVlanMode::PassThrough).serialize_into(&mut buffer);
+        assert_eq!(&buffer[4..], &MACVLAN_MODE_PASSTHRU.to_ne_bytes());
+
+        // Modes of newer kernels are kept
+        let raw = RawAttribute::new(IFLA_MACVLAN_MODE, 32u32.to_ne_bytes().to_vec());
+        let attr = MacVlanAttribute::from_raw(raw).unwrap();
+        assert_eq!(attr, MacVlanAttribute::Mode(MacVlanMode::Other(32)));
+    }
+
+    #[test]
+    fn source_addresses() {
+        let addresses = vec![[0x02, 0, 0, 0, 0, 1], [0x02, 0, 0, 0, 0, 2]];
+
+        let mut buffer = Vec::new();
+        MacVlanAttribute::MacAddressData(addresses.clone()).serialize_into(&mut buffer);
+
+        // The addresses are nested as padded IFLA_MACVLAN_MACADDR attributes
+        assert_eq!(buffer.len(), 4 + 2 * 12);
+        let nested: Vec<_> = RawAttributeIter::new(buffer[4..].iter().cloned()).collect();
+        assert_eq!(nested[1].attr_type(), IFLA_MACVLAN_MACADDR);
+        assert_eq!(nested[1].payload(), &addresses[1]);
+
+        let raw = RawAttributeIter::new(buffer.into_iter()).next().unwrap();
+        let attr = MacVlanAttribute::from_raw(raw).unwrap();
+        assert_eq!(attr, MacVlanAttribute::MacAddressData(addresses));
+    }
+}
@@ -0,0 +1,43 @@
+/// The hardware resources of a device, mostly relevant for legacy ISA cards.
+/// Only the fields supported by the driver can be changed.
+///
+/// It corresponds to the `rtnl_link_ifmap` struct in libc.
+#[doc(alias("rtnl_link_ifmap", "ifmap"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InterfaceMap {
+    pub mem_start: u64,
+    pub mem_end: u64,
+    pub base_addr: u64,
+    pub irq: u16,
+    pub dma: u8,
+    pub port: u8,
+}
+
+impl InterfaceMap {
+    /// The size of `struct rtnl_link_ifmap`, the trailing padding included.
+    pub const SIZE: usize = 32;
+
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        buffer.extend(self.mem_start.to_ne_bytes());
+        buffer.extend(self.mem_end.to_ne_bytes());
+        buffer.extend(self.base_addr.to_ne_bytes());
+        buffer.extend(self.irq.to_ne_bytes());
+        buffer.push(self.dma);
+        buffer.push(self.port);
+        buffer.extend([0u8; 4]); // padding
+    }
+
+    pub fn deserialize(bytes: &[u8]) -> Option<Self> {
+        let bytes = <[u8; Self::SIZE]>::try_from(bytes).ok()?;
+        let u64_at = |i: usize| u64::from_ne_bytes(bytes[i..i + 8].try_into().unwrap());
+
+        Some(Self {
+            mem_start: u64_at(0),
+            mem_end: u64_at(8),
+            base_addr: u64_at(16),
+            irq: u16::from_ne_bytes([bytes[24], bytes[25]]),
+            dma: bytes[26],
+            port: bytes[27],
+        })
+    }
+}
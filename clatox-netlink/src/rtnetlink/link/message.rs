@@ -1,12 +1,14 @@
 use libc::*;
 
 use crate::netlink::{Attribute, Payload, RawAttributeIter, RouteType, Type};
+use crate::rtnetlink::AddressFamily;
 use crate::utils::{align_attribute_len, read_u16, read_u32};
 
-use super::{ArpHardware, InterfaceFlags, InterfaceInfoAttribute};
+use super::{ArpHardware, HardwareAddress, InterfaceFlags, InterfaceInfoAttribute, ProtocolInfo};
 
-/// The message on which the `GetLink`, `AddLink`, `DeleteLink` requests are based
-/// on. It corresponds to the `ifinfomsg` struct in libc.
+/// The message on which the `GetLink`, `AddLink`, `SetLink`, `DeleteLink`,
+/// `NewLinkProperty` and `DeleteLinkProperty` requests are based on. It
+/// corresponds to the `ifinfomsg` struct in libc.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct InterfaceInfoMessage {
     family: u16, // This is an unsigned char in ifinfomsg. Use u16 to cover the padding.
@@ -34,6 +36,18 @@ impl InterfaceInfoMessage {
         }
     }
 
+    /// Sets the address family of the message. Most requests use
+    /// `AddressFamily::Unspecified`, which is the default, but e.g. bridge port
+    /// configuration is done through `AddressFamily::Bridge`.
+    pub fn with_family(mut self, family: AddressFamily) -> Self {
+        self.family = family.raw_value() as u16;
+        self
+    }
+
+    pub fn family(&self) -> Option<AddressFamily> {
+        AddressFamily::from_raw_value(self.family as u8)
+    }
+
     pub const fn device_type(&self) -> ArpHardware {
         self.device_type
     }
@@ -94,15 +108,33 @@ impl InterfaceInfoMessage {
             iter.next()?;
         }
 
-        let attributes = RawAttributeIter::new(iter.cloned())
-            .map(|a| {
-                let typ = a.attr_type();
-                let attr = InterfaceInfoAttribute::from_raw(a);
-                println!("{}, {:?}", typ, attr);
-                attr
-            })
+        let mut attributes: Vec<_> = RawAttributeIter::new(iter.cloned())
+            .map(InterfaceInfoAttribute::from_raw)
             .try_collect()?;
 
+        // IFLA_PROTINFO can only be interpreted with the address family known,
+        // and the L2 addresses with the device type known
+        for attr in attributes.iter_mut() {
+            match attr {
+                InterfaceInfoAttribute::ProtocolInfo(ProtocolInfo::Other(content)) => {
+                    if family == AF_BRIDGE as u16 {
+                        let attrs = RawAttributeIter::new(content.iter().cloned())
+                            .map(Attribute::from_raw)
+                            .try_collect()?;
+                        *attr = InterfaceInfoAttribute::ProtocolInfo(ProtocolInfo::Bridge(attrs));
+                    }
+                }
+                InterfaceInfoAttribute::Address(address)
+                | InterfaceInfoAttribute::Broadcast(address)
+                | InterfaceInfoAttribute::PermanentAddress(address) => {
+                    if let HardwareAddress::Other(content) = address {
+                        *address = HardwareAddress::from_bytes(device_type, content);
+                    }
+                }
+                _ => {}
+            }
+        }
+
         Some(InterfaceInfoMessage {
             family,
             device_type,
@@ -150,6 +182,24 @@ impl Payload for GetLink {
     }
 }
 
+/// A message that is of the `RTM_SETLINK` type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetLink(pub InterfaceInfoMessage);
+
+impl Payload for SetLink {
+    fn message_type() -> Type {
+        Type::Route(RouteType::SetLink)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(InterfaceInfoMessage::deserialize(bytes)?))
+    }
+}
+
 /// A message that is of the `RTM_DELLINK` type.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DeleteLink(pub InterfaceInfoMessage);
@@ -167,3 +217,44 @@ impl Payload for DeleteLink {
         Some(Self(InterfaceInfoMessage::deserialize(bytes)?))
     }
 }
+
+/// A message that is of the `RTM_NEWLINKPROP` type.
+///
+/// Adds the properties found in the `PropertiesList` attribute to a link,
+/// e.g. alternative names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewLinkProperty(pub InterfaceInfoMessage);
+
+impl Payload for NewLinkProperty {
+    fn message_type() -> Type {
+        Type::Route(RouteType::NewLinkProperty)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(InterfaceInfoMessage::deserialize(bytes)?))
+    }
+}
+
+/// A message that is of the `RTM_DELLINKPROP` type.
+///
+/// Deletes the properties found in the `PropertiesList` attribute from a link.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeleteLinkProperty(pub InterfaceInfoMessage);
+
+impl Payload for DeleteLinkProperty {
+    fn message_type() -> Type {
+        Type::Route(RouteType::DeleteLinkProperty)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(InterfaceInfoMessage::deserialize(bytes)?))
+    }
+}
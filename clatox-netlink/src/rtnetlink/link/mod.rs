@@ -1,10 +1,30 @@
+mod address;
 mod af_spec;
 mod attribute;
+mod bond;
+mod bridge;
 mod device_type;
+mod event;
 mod flags;
+mod inet;
+mod inet6;
+mod ipvlan;
 mod link_info;
+mod lookup;
+mod macvlan;
+mod map;
 mod message;
+mod protocol_down;
+mod protocol_info;
+mod state;
 mod stats;
+mod tun;
+mod vlan;
+mod vxlan;
+mod xdp;
+
+#[doc(inline)]
+pub use address::*;
 
 #[doc(inline)]
 pub use af_spec::*;
@@ -12,17 +32,65 @@ pub use af_spec::*;
 #[doc(inline)]
 pub use attribute::*;
 
+#[doc(inline)]
+pub use bond::*;
+
+#[doc(inline)]
+pub use bridge::*;
+
 #[doc(inline)]
 pub use device_type::*;
 
+#[doc(inline)]
+pub use event::*;
+
 #[doc(inline)]
 pub use flags::*;
 
+#[doc(inline)]
+pub use inet::*;
+
+#[doc(inline)]
+pub use inet6::*;
+
+#[doc(inline)]
+pub use ipvlan::*;
+
 #[doc(inline)]
 pub use link_info::*;
 
+#[doc(inline)]
+pub use lookup::*;
+
+#[doc(inline)]
+pub use macvlan::*;
+
+#[doc(inline)]
+pub use map::*;
+
 #[doc(inline)]
 pub use message::*;
 
+#[doc(inline)]
+pub use protocol_down::*;
+
+#[doc(inline)]
+pub use protocol_info::*;
+
+#[doc(inline)]
+pub use state::*;
+
 #[doc(inline)]
 pub use stats::*;
+
+#[doc(inline)]
+pub use tun::*;
+
+#[doc(inline)]
+pub use vlan::*;
+
+#[doc(inline)]
+pub use vxlan::*;
+
+#[doc(inline)]
+pub use xdp::*;
@@ -0,0 +1,72 @@
+use crate::netlink::{Attribute, RawAttribute};
+use crate::utils;
+
+pub const IFLA_PROTO_DOWN_REASON_UNSPEC: u16 = 0;
+pub const IFLA_PROTO_DOWN_REASON_MASK: u16 = 1;
+pub const IFLA_PROTO_DOWN_REASON_VALUE: u16 = 2;
+
+/// Attributes nested in `IFLA_PROTO_DOWN_REASON`. They correspond to
+/// `IFLA_PROTO_DOWN_REASON_*` in libc.
+///
+/// The reasons form a bitfield of 32 user-defined bits. To change them, send
+/// a `Mask` of the bits to change together with their new `Value`. The kernel
+/// only reports the `Value`.
+#[doc(alias("IFLA_PROTO_DOWN_REASON_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProtocolDownReasonAttribute {
+    /// `IFLA_PROTO_DOWN_REASON_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `IFLA_PROTO_DOWN_REASON_MASK`
+    Mask(u32),
+
+    /// `IFLA_PROTO_DOWN_REASON_VALUE`
+    Value(u32),
+
+    Other(u16, Vec<u8>),
+}
+
+impl ProtocolDownReasonAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                IFLA_PROTO_DOWN_REASON_UNSPEC
+            }
+            Self::Mask(mask) => {
+                buffer.extend(mask.to_ne_bytes());
+                IFLA_PROTO_DOWN_REASON_MASK
+            }
+            Self::Value(value) => {
+                buffer.extend(value.to_ne_bytes());
+                IFLA_PROTO_DOWN_REASON_VALUE
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for ProtocolDownReasonAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            IFLA_PROTO_DOWN_REASON_UNSPEC => Self::Unspecified(content),
+            IFLA_PROTO_DOWN_REASON_MASK => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Mask(u32::from_ne_bytes(content))
+            }
+            IFLA_PROTO_DOWN_REASON_VALUE => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Value(u32::from_ne_bytes(content))
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
@@ -0,0 +1,24 @@
+use super::BridgePortAttribute;
+
+/// Protocol-specific information of a link, carried in `IFLA_PROTINFO`.
+///
+/// The content depends on the address family of the `InterfaceInfoMessage` it
+/// is found in, hence it is only decoded once the whole message is known.
+#[doc(alias("IFLA_PROTINFO"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProtocolInfo {
+    /// `AF_BRIDGE` - the attributes of a bridge port.
+    Bridge(Vec<BridgePortAttribute>),
+
+    /// Information of other (or yet unknown) address families.
+    Other(Vec<u8>),
+}
+
+impl ProtocolInfo {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        match self {
+            Self::Bridge(attrs) => attrs.iter().for_each(|a| a.serialize_into(buffer)),
+            Self::Other(content) => buffer.extend(content.iter()),
+        }
+    }
+}
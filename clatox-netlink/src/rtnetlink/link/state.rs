@@ -0,0 +1,158 @@
+use super::{InterfaceInfoAttribute, InterfaceInfoMessage};
+
+pub const IF_OPER_UNKNOWN: u8 = 0;
+pub const IF_OPER_NOTPRESENT: u8 = 1;
+pub const IF_OPER_DOWN: u8 = 2;
+pub const IF_OPER_LOWERLAYERDOWN: u8 = 3;
+pub const IF_OPER_TESTING: u8 = 4;
+pub const IF_OPER_DORMANT: u8 = 5;
+pub const IF_OPER_UP: u8 = 6;
+
+pub const IF_LINK_MODE_DEFAULT: u8 = 0;
+pub const IF_LINK_MODE_DORMANT: u8 = 1;
+pub const IF_LINK_MODE_TESTING: u8 = 2;
+
+/// The operational state of an interface as defined in RFC 2863. It
+/// corresponds to `IF_OPER_*` in libc.
+///
+/// Userspace may only set `Up` or `Dormant` (and only on links in
+/// `LinkMode::Dormant` for the latter); the kernel derives the other states
+/// from the carrier and the administrative state.
+#[doc(alias("IF_OPER_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum OperState {
+    /// The state is not known, e.g. because the driver does not report it.
+    Unknown = IF_OPER_UNKNOWN,
+
+    /// A component of the interface is missing. Unused by the kernel.
+    NotPresent = IF_OPER_NOTPRESENT,
+
+    Down = IF_OPER_DOWN,
+
+    /// The interface is down because a lower interface it is stacked on is
+    /// down.
+    LowerLayerDown = IF_OPER_LOWERLAYERDOWN,
+
+    Testing = IF_OPER_TESTING,
+
+    /// The interface is up, but waits for an external event (e.g. 802.1X
+    /// authentication, or the userspace side of a TUN device) to pass
+    /// traffic.
+    Dormant = IF_OPER_DORMANT,
+
+    Up = IF_OPER_UP,
+}
+
+impl OperState {
+    pub const fn raw_value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub const fn from_raw_value(value: u8) -> Option<Self> {
+        match value {
+            IF_OPER_UNKNOWN => Some(Self::Unknown),
+            IF_OPER_NOTPRESENT => Some(Self::NotPresent),
+            IF_OPER_DOWN => Some(Self::Down),
+            IF_OPER_LOWERLAYERDOWN => Some(Self::LowerLayerDown),
+            IF_OPER_TESTING => Some(Self::Testing),
+            IF_OPER_DORMANT => Some(Self::Dormant),
+            IF_OPER_UP => Some(Self::Up),
+            _ => None,
+        }
+    }
+}
+
+/// How the operational state of an interface is determined. It corresponds
+/// to `IF_LINK_MODE_*` in libc.
+#[doc(alias("IF_LINK_MODE_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum LinkMode {
+    /// The operational state follows the carrier.
+    Default = IF_LINK_MODE_DEFAULT,
+
+    /// The interface stays `OperState::Dormant` until userspace sets it
+    /// `OperState::Up`. This is meant for links managed by a userspace
+    /// daemon, e.g. a supplicant or the process behind a TUN device.
+    Dormant = IF_LINK_MODE_DORMANT,
+
+    /// The interface stays `OperState::Testing` until userspace sets it
+    /// `OperState::Up`.
+    Testing = IF_LINK_MODE_TESTING,
+}
+
+impl LinkMode {
+    pub const fn raw_value(&self) -> u8 {
+        *self as u8
+    }
+
+    pub const fn from_raw_value(value: u8) -> Option<Self> {
+        match value {
+            IF_LINK_MODE_DEFAULT => Some(Self::Default),
+            IF_LINK_MODE_DORMANT => Some(Self::Dormant),
+            IF_LINK_MODE_TESTING => Some(Self::Testing),
+            _ => None,
+        }
+    }
+}
+
+/// The state and carrier history of a link, collected from the attributes of
+/// an `InterfaceInfoMessage`. Fields whose attribute was not reported are
+/// `None`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LinkHealth {
+    pub operational_state: Option<OperState>,
+    pub link_mode: Option<LinkMode>,
+    pub carrier: Option<bool>,
+    pub protocol_down: Option<bool>,
+
+    /// The number of times the carrier changed, i.e. the sum of
+    /// `carrier_up_count` and `carrier_down_count`.
+    pub carrier_changes: Option<u32>,
+    pub carrier_up_count: Option<u32>,
+    pub carrier_down_count: Option<u32>,
+}
+
+impl LinkHealth {
+    /// Whether the link can pass traffic. Links whose driver does not report
+    /// an operational state count as running if they have a carrier.
+    pub fn is_running(&self) -> bool {
+        match self.operational_state {
+            Some(OperState::Up) => true,
+            Some(OperState::Unknown) | None => self.carrier.unwrap_or(false),
+            Some(_) => false,
+        }
+    }
+}
+
+impl InterfaceInfoMessage {
+    /// Collects the `LinkHealth` of the link from the attributes of the
+    /// message, typically a `NewLink` reply or notification.
+    pub fn health(&self) -> LinkHealth {
+        let mut health = LinkHealth::default();
+
+        for attr in self.attributes() {
+            match attr {
+                InterfaceInfoAttribute::OperationalState(state) => {
+                    health.operational_state = Some(*state)
+                }
+                InterfaceInfoAttribute::LinkMode(mode) => health.link_mode = Some(*mode),
+                InterfaceInfoAttribute::Carrier(carrier) => health.carrier = Some(*carrier),
+                InterfaceInfoAttribute::ProtocolDown(down) => health.protocol_down = Some(*down),
+                InterfaceInfoAttribute::CarrierChanges(changes) => {
+                    health.carrier_changes = Some(*changes)
+                }
+                InterfaceInfoAttribute::CarrierUpCount(count) => {
+                    health.carrier_up_count = Some(*count)
+                }
+                InterfaceInfoAttribute::CarrierDownCount(count) => {
+                    health.carrier_down_count = Some(*count)
+                }
+                _ => {}
+            }
+        }
+
+        health
+    }
+}
@@ -0,0 +1,240 @@
+use libc::*;
+
+use crate::netlink::{Attribute, RawAttribute};
+use crate::utils;
+
+use bitflags::bitflags;
+
+pub const IFLA_TUN_UNSPEC: u16 = 0;
+pub const IFLA_TUN_OWNER: u16 = 1;
+pub const IFLA_TUN_GROUP: u16 = 2;
+pub const IFLA_TUN_TYPE: u16 = 3;
+pub const IFLA_TUN_PI: u16 = 4;
+pub const IFLA_TUN_VNET_HDR: u16 = 5;
+pub const IFLA_TUN_PERSIST: u16 = 6;
+pub const IFLA_TUN_MULTI_QUEUE: u16 = 7;
+pub const IFLA_TUN_NUM_QUEUES: u16 = 8;
+pub const IFLA_TUN_NUM_DISABLED_QUEUES: u16 = 9;
+
+/// Whether a TUN/TAP device carries IP packets or Ethernet frames.
+#[doc(alias("IFF_TUN", "IFF_TAP"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TunType {
+    /// `IFF_TUN` - a L3 device that carries IP packets.
+    Tun,
+
+    /// `IFF_TAP` - a L2 device that carries Ethernet frames.
+    Tap,
+
+    /// Any other value
+    Other(u8),
+}
+
+impl TunType {
+    pub const fn raw_value(&self) -> u8 {
+        match self {
+            Self::Tun => IFF_TUN as u8,
+            Self::Tap => IFF_TAP as u8,
+            Self::Other(x) => *x,
+        }
+    }
+
+    pub const fn from_raw_value(value: u8) -> Self {
+        match value as c_int {
+            IFF_TUN => Self::Tun,
+            IFF_TAP => Self::Tap,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+bitflags! {
+    /// A bitfield of flags used when creating a TUN/TAP device, apart from
+    /// its `TunType`.
+    ///
+    /// It corresponds to `IFF_*` in <linux/if_tun.h>.
+    #[doc(alias("IFF_NO_PI", "IFF_VNET_HDR", "IFF_MULTI_QUEUE"))]
+    #[derive(Copy, Debug, Clone, PartialEq, Eq)]
+    pub struct TunFlags: u16 {
+        /// Do not prepend the `struct tun_pi` packet information header to
+        /// the packets
+        const NoPacketInfo = IFF_NO_PI as u16;
+        /// Prepend the `struct virtio_net_hdr` offload header to the packets
+        const VnetHeader = IFF_VNET_HDR as u16;
+        /// Allow more than one queue, each with its own file descriptor
+        const MultiQueue = IFF_MULTI_QUEUE as u16;
+    }
+}
+
+/// Attributes of `tun` links, nested in `IFLA_INFO_DATA`. They correspond to
+/// `IFLA_TUN_*` in libc.
+///
+/// The kernel only reports them; TUN/TAP devices cannot be created with
+/// `AddLink`, use `TunOptions` instead.
+#[doc(alias("IFLA_TUN_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TunAttribute {
+    /// `IFLA_TUN_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `IFLA_TUN_OWNER` - the user allowed to attach to the device. Only
+    /// reported if set.
+    Owner(uid_t),
+
+    /// `IFLA_TUN_GROUP` - the group allowed to attach to the device. Only
+    /// reported if set.
+    Group(gid_t),
+
+    /// `IFLA_TUN_TYPE`
+    Type(TunType),
+
+    /// `IFLA_TUN_PI` - whether the packets are prepended with a
+    /// `struct tun_pi`, i.e. the opposite of `TunFlags::NoPacketInfo`.
+    PacketInfo(bool),
+
+    /// `IFLA_TUN_VNET_HDR`
+    VnetHeader(bool),
+
+    /// `IFLA_TUN_PERSIST` - whether the device outlives its file descriptors.
+    Persist(bool),
+
+    /// `IFLA_TUN_MULTI_QUEUE`
+    MultiQueue(bool),
+
+    /// `IFLA_TUN_NUM_QUEUES` - the number of attached queues.
+    NumQueues(u32),
+
+    /// `IFLA_TUN_NUM_DISABLED_QUEUES` - the number of queues detached with
+    /// `IFF_DETACH_QUEUE`.
+    NumDisabledQueues(u32),
+
+    Other(u16, Vec<u8>),
+}
+
+impl TunAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                IFLA_TUN_UNSPEC
+            }
+            Self::Owner(uid) => {
+                buffer.extend(uid.to_ne_bytes());
+                IFLA_TUN_OWNER
+            }
+            Self::Group(gid) => {
+                buffer.extend(gid.to_ne_bytes());
+                IFLA_TUN_GROUP
+            }
+            Self::Type(tun_type) => {
+                buffer.push(tun_type.raw_value());
+                IFLA_TUN_TYPE
+            }
+            Self::PacketInfo(enabled) => {
+                buffer.push(*enabled as u8);
+                IFLA_TUN_PI
+            }
+            Self::VnetHeader(enabled) => {
+                buffer.push(*enabled as u8);
+                IFLA_TUN_VNET_HDR
+            }
+            Self::Persist(enabled) => {
+                buffer.push(*enabled as u8);
+                IFLA_TUN_PERSIST
+            }
+            Self::MultiQueue(enabled) => {
+                buffer.push(*enabled as u8);
+                IFLA_TUN_MULTI_QUEUE
+            }
+            Self::NumQueues(count) => {
+                buffer.extend(count.to_ne_bytes());
+                IFLA_TUN_NUM_QUEUES
+            }
+            Self::NumDisabledQueues(count) => {
+                buffer.extend(count.to_ne_bytes());
+                IFLA_TUN_NUM_DISABLED_QUEUES
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for TunAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            IFLA_TUN_UNSPEC => Self::Unspecified(content),
+            IFLA_TUN_OWNER => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Owner(uid_t::from_ne_bytes(content))
+            }
+            IFLA_TUN_GROUP => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Group(gid_t::from_ne_bytes(content))
+            }
+            IFLA_TUN_TYPE => {
+                let content = <[u8; 1]>::try_from(content).ok()?;
+                Self::Type(TunType::from_raw_value(content[0]))
+            }
+            IFLA_TUN_PI => {
+                let content = <[u8; 1]>::try_from(content).ok()?;
+                Self::PacketInfo(content[0] != 0)
+            }
+            IFLA_TUN_VNET_HDR => {
+                let content = <[u8; 1]>::try_from(content).ok()?;
+                Self::VnetHeader(content[0] != 0)
+            }
+            IFLA_TUN_PERSIST => {
+                let content = <[u8; 1]>::try_from(content).ok()?;
+                Self::Persist(content[0] != 0)
+            }
+            IFLA_TUN_MULTI_QUEUE => {
+                let content = <[u8; 1]>::try_from(content).ok()?;
+                Self::MultiQueue(content[0] != 0)
+            }
+            IFLA_TUN_NUM_QUEUES => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::NumQueues(u32::from_ne_bytes(content))
+            }
+            IFLA_TUN_NUM_DISABLED_QUEUES => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::NumDisabledQueues(u32::from_ne_bytes(content))
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::netlink::RawAttributeIter;
+
+    #[test]
+    fn attribute_round_trip() {
+        let attrs = vec![
+            TunAttribute::Owner(1000),
+            TunAttribute::Type(TunType::Tap),
+            TunAttribute::Type(TunType::Other(0x10)),
+            TunAttribute::PacketInfo(false),
+            TunAttribute::MultiQueue(true),
+            TunAttribute::NumQueues(4),
+            TunAttribute::Other(0x42, vec![1, 2, 3, 4]),
+        ];
+
+        let mut buffer = Vec::new();
+        attrs.iter().for_each(|attr| attr.serialize_into(&mut buffer));
+
+        let decoded: Option<Vec<_>> = RawAttributeIter::new(buffer.into_iter())
+            .map(TunAttribute::from_raw)
+            .collect();
+        assert_eq!(decoded, Some(attrs));
+    }
+}
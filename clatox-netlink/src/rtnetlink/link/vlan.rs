@@ -0,0 +1,246 @@
+use crate::netlink::{Attribute, RawAttribute, RawAttributeIter};
+use crate::utils;
+
+use bitflags::bitflags;
+
+pub const IFLA_VLAN_UNSPEC: u16 = 0;
+pub const IFLA_VLAN_ID: u16 = 1;
+pub const IFLA_VLAN_FLAGS: u16 = 2;
+pub const IFLA_VLAN_EGRESS_QOS: u16 = 3;
+pub const IFLA_VLAN_INGRESS_QOS: u16 = 4;
+pub const IFLA_VLAN_PROTOCOL: u16 = 5;
+
+pub const IFLA_VLAN_QOS_UNSPEC: u16 = 0;
+pub const IFLA_VLAN_QOS_MAPPING: u16 = 1;
+
+pub const VLAN_FLAG_REORDER_HDR: u32 = 0x1;
+pub const VLAN_FLAG_GVRP: u32 = 0x2;
+pub const VLAN_FLAG_LOOSE_BINDING: u32 = 0x4;
+pub const VLAN_FLAG_MVRP: u32 = 0x8;
+pub const VLAN_FLAG_BRIDGE_BINDING: u32 = 0x10;
+
+bitflags! {
+    /// A bitfield of VLAN device flags.
+    ///
+    /// It corresponds to `VLAN_FLAG_*` in <linux/if_vlan.h>.
+    #[doc(alias("ifla_vlan_flags", "VLAN_FLAG_"))]
+    #[derive(Copy, Debug, Clone, PartialEq, Eq)]
+    pub struct VlanFlags: u32 {
+        const ReorderHeader = VLAN_FLAG_REORDER_HDR;
+        /// GARP VLAN Registration Protocol
+        const Gvrp = VLAN_FLAG_GVRP;
+        /// Don't follow the operational state of the parent device
+        const LooseBinding = VLAN_FLAG_LOOSE_BINDING;
+        /// Multiple VLAN Registration Protocol
+        const Mvrp = VLAN_FLAG_MVRP;
+        const BridgeBinding = VLAN_FLAG_BRIDGE_BINDING;
+    }
+}
+
+/// The VLAN tagging protocol, i.e. the TPID of the outer tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VlanProtocol {
+    /// `ETH_P_8021Q`
+    Ieee8021Q,
+
+    /// `ETH_P_8021AD` (QinQ)
+    Ieee8021AD,
+
+    /// Any other EtherType
+    Other(u16),
+}
+
+impl VlanProtocol {
+    pub const fn raw_value(&self) -> u16 {
+        match self {
+            Self::Ieee8021Q => 0x8100,
+            Self::Ieee8021AD => 0x88A8,
+            Self::Other(x) => *x,
+        }
+    }
+
+    pub const fn from_raw_value(value: u16) -> Self {
+        match value {
+            0x8100 => Self::Ieee8021Q,
+            0x88A8 => Self::Ieee8021AD,
+            x => Self::Other(x),
+        }
+    }
+}
+
+/// A priority mapping between the skb priority and the VLAN PCP (or the other
+/// way around, for ingress mappings).
+///
+/// Corresponds to `struct ifla_vlan_qos_mapping` in libc.
+#[doc(alias("ifla_vlan_qos_mapping"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VlanQosMapping {
+    pub from: u32,
+    pub to: u32,
+}
+
+/// Attributes nested in `IFLA_INFO_DATA` for links of the `vlan` kind.
+/// They correspond to `IFLA_VLAN_*` in libc.
+#[doc(alias("IFLA_VLAN_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VlanAttribute {
+    /// `IFLA_VLAN_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `IFLA_VLAN_ID` - the VLAN ID, ranging from 0 to 4095.
+    Id(u16),
+
+    /// `IFLA_VLAN_FLAGS` - the flags to be changed, and the mask of the flags
+    /// that are to be changed.
+    Flags(VlanFlags, VlanFlags),
+
+    /// `IFLA_VLAN_EGRESS_QOS`
+    EgressQos(Vec<VlanQosMapping>),
+
+    /// `IFLA_VLAN_INGRESS_QOS`
+    IngressQos(Vec<VlanQosMapping>),
+
+    /// `IFLA_VLAN_PROTOCOL`
+    Protocol(VlanProtocol),
+
+    Other(u16, Vec<u8>),
+}
+
+fn serialize_qos_mappings(mappings: &[VlanQosMapping], buffer: &mut Vec<u8>) {
+    for mapping in mappings {
+        utils::serialize_attribute_into(buffer, |buffer| {
+            buffer.extend(mapping.from.to_ne_bytes());
+            buffer.extend(mapping.to.to_ne_bytes());
+            IFLA_VLAN_QOS_MAPPING
+        });
+    }
+}
+
+fn deserialize_qos_mappings(bytes: &[u8]) -> Option<Vec<VlanQosMapping>> {
+    RawAttributeIter::new(bytes.iter().cloned())
+        .filter(|attr| attr.attr_type() == IFLA_VLAN_QOS_MAPPING)
+        .map(|attr| {
+            let mut iter = attr.payload().iter().cloned();
+            let from = utils::read_u32(iter.by_ref())?;
+            let to = utils::read_u32(iter.by_ref())?;
+            Some(VlanQosMapping { from, to })
+        })
+        .try_collect()
+}
+
+impl VlanAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                IFLA_VLAN_UNSPEC
+            }
+            Self::Id(id) => {
+                buffer.extend(id.to_ne_bytes());
+                IFLA_VLAN_ID
+            }
+            Self::Flags(flags, mask) => {
+                buffer.extend(flags.bits().to_ne_bytes());
+                buffer.extend(mask.bits().to_ne_bytes());
+                IFLA_VLAN_FLAGS
+            }
+            Self::EgressQos(mappings) => {
+                serialize_qos_mappings(mappings, buffer);
+                IFLA_VLAN_EGRESS_QOS
+            }
+            Self::IngressQos(mappings) => {
+                serialize_qos_mappings(mappings, buffer);
+                IFLA_VLAN_INGRESS_QOS
+            }
+            Self::Protocol(protocol) => {
+                // The protocol is an EtherType, and is thus in network byte order
+                buffer.extend(protocol.raw_value().to_be_bytes());
+                IFLA_VLAN_PROTOCOL
+            }
+            Self::Other(typ, content) => {
+                buffer.extend(content.iter());
+                *typ
+            }
+        })
+    }
+}
+
+impl Attribute for VlanAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            IFLA_VLAN_UNSPEC => Self::Unspecified(content),
+            IFLA_VLAN_ID => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::Id(u16::from_ne_bytes(content))
+            }
+            IFLA_VLAN_FLAGS => {
+                let mut iter = content.into_iter();
+                let flags = VlanFlags::from_bits_retain(utils::read_u32(iter.by_ref())?);
+                let mask = VlanFlags::from_bits_retain(utils::read_u32(iter.by_ref())?);
+                Self::Flags(flags, mask)
+            }
+            IFLA_VLAN_EGRESS_QOS => Self::EgressQos(deserialize_qos_mappings(&content)?),
+            IFLA_VLAN_INGRESS_QOS => Self::IngressQos(deserialize_qos_mappings(&content)?),
+            IFLA_VLAN_PROTOCOL => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::Protocol(VlanProtocol::from_raw_value(u16::from_be_bytes(content)))
+            }
+            typ => Self::Other(typ, content),
+        };
+
+        Some(attr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn serialize(attr: &VlanAttribute) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        attr.serialize_into(&mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn protocol_in_network_byte_order() {
+        let raw = RawAttribute::new(IFLA_VLAN_PROTOCOL, vec![0x88, 0xa8]);
+        let attr = VlanAttribute::from_raw(raw).unwrap();
+        assert_eq!(attr, VlanAttribute::Protocol(VlanProtocol::Ieee8021AD));
+
+        let raw = RawAttribute::new(IFLA_VLAN_PROTOCOL, vec![0x91, 0x00]);
+        let attr = VlanAttribute::from_raw(raw).unwrap();
+        assert_eq!(attr, VlanAttribute::Protocol(VlanProtocol::Other(0x9100)));
+
+        let buffer = serialize(&VlanAttribute::Protocol(VlanProtocol::Ieee8021Q));
+        assert_eq!(&buffer[2..6], &[IFLA_VLAN_PROTOCOL as u8, 0, 0x81, 0x00]);
+    }
+
+    #[test]
+    fn flags_and_mask() {
+        let flags = VlanFlags::ReorderHeader | VlanFlags::Mvrp;
+        let buffer = serialize(&VlanAttribute::Flags(flags, VlanFlags::all()));
+        assert_eq!(&buffer[4..8], &0x9u32.to_ne_bytes());
+        assert_eq!(&buffer[8..12], &0x1fu32.to_ne_bytes());
+    }
+
+    #[test]
+    fn qos_mappings() {
+        let mappings = vec![
+            VlanQosMapping { from: 1, to: 2 },
+            VlanQosMapping { from: 7, to: 3 },
+        ];
+        let buffer = serialize(&VlanAttribute::EgressQos(mappings.clone()));
+
+        // Each mapping is an IFLA_VLAN_QOS_MAPPING attribute of its own
+        assert_eq!(buffer.len(), 4 + 2 * 12);
+        let nested: Vec<_> = RawAttributeIter::new(buffer[4..].iter().cloned()).collect();
+        assert!(nested.iter().all(|x| x.attr_type() == IFLA_VLAN_QOS_MAPPING));
+
+        let raw = RawAttributeIter::new(buffer.into_iter()).next().unwrap();
+        assert_eq!(VlanAttribute::from_raw(raw), Some(VlanAttribute::EgressQos(mappings)));
+    }
+}
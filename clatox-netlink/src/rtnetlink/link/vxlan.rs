@@ -0,0 +1,434 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::netlink::{Attribute, RawAttribute};
+use crate::utils;
+
+pub const IFLA_VXLAN_UNSPEC: u16 = 0;
+pub const IFLA_VXLAN_ID: u16 = 1;
+pub const IFLA_VXLAN_GROUP: u16 = 2;
+pub const IFLA_VXLAN_LINK: u16 = 3;
+pub const IFLA_VXLAN_LOCAL: u16 = 4;
+pub const IFLA_VXLAN_TTL: u16 = 5;
+pub const IFLA_VXLAN_TOS: u16 = 6;
+pub const IFLA_VXLAN_LEARNING: u16 = 7;
+pub const IFLA_VXLAN_AGEING: u16 = 8;
+pub const IFLA_VXLAN_LIMIT: u16 = 9;
+pub const IFLA_VXLAN_PORT_RANGE: u16 = 10;
+pub const IFLA_VXLAN_PROXY: u16 = 11;
+pub const IFLA_VXLAN_RSC: u16 = 12;
+pub const IFLA_VXLAN_L2MISS: u16 = 13;
+pub const IFLA_VXLAN_L3MISS: u16 = 14;
+pub const IFLA_VXLAN_PORT: u16 = 15;
+pub const IFLA_VXLAN_GROUP6: u16 = 16;
+pub const IFLA_VXLAN_LOCAL6: u16 = 17;
+pub const IFLA_VXLAN_UDP_CSUM: u16 = 18;
+pub const IFLA_VXLAN_UDP_ZERO_CSUM6_TX: u16 = 19;
+pub const IFLA_VXLAN_UDP_ZERO_CSUM6_RX: u16 = 20;
+pub const IFLA_VXLAN_REMCSUM_TX: u16 = 21;
+pub const IFLA_VXLAN_REMCSUM_RX: u16 = 22;
+pub const IFLA_VXLAN_GBP: u16 = 23;
+pub const IFLA_VXLAN_REMCSUM_NOPARTIAL: u16 = 24;
+pub const IFLA_VXLAN_COLLECT_METADATA: u16 = 25;
+pub const IFLA_VXLAN_LABEL: u16 = 26;
+pub const IFLA_VXLAN_GPE: u16 = 27;
+pub const IFLA_VXLAN_TTL_INHERIT: u16 = 28;
+pub const IFLA_VXLAN_DF: u16 = 29;
+pub const IFLA_VXLAN_VNIFILTER: u16 = 30;
+pub const IFLA_VXLAN_LOCALBYPASS: u16 = 31;
+
+pub const VXLAN_DF_UNSET: u8 = 0;
+pub const VXLAN_DF_SET: u8 = 1;
+pub const VXLAN_DF_INHERIT: u8 = 2;
+
+/// How the Don't Fragment bit of the outer IPv4 header is set. Corresponds to
+/// `VXLAN_DF_*` in libc.
+#[doc(alias("ifla_vxlan_df", "VXLAN_DF_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VxlanDf {
+    Unset,
+    Set,
+
+    /// Copy the DF bit from the encapsulated IPv4 header.
+    Inherit,
+
+    /// Any other value
+    Other(u8),
+}
+
+impl VxlanDf {
+    pub const fn raw_value(&self) -> u8 {
+        match self {
+            Self::Unset => VXLAN_DF_UNSET,
+            Self::Set => VXLAN_DF_SET,
+            Self::Inherit => VXLAN_DF_INHERIT,
+            Self::Other(x) => *x,
+        }
+    }
+
+    pub const fn from_raw_value(value: u8) -> Self {
+        match value {
+            VXLAN_DF_UNSET => Self::Unset,
+            VXLAN_DF_SET => Self::Set,
+            VXLAN_DF_INHERIT => Self::Inherit,
+            x => Self::Other(x),
+        }
+    }
+}
+
+/// Attributes nested in `IFLA_INFO_DATA` for links of the `vxlan` kind.
+/// They correspond to `IFLA_VXLAN_*` in libc.
+///
+/// Port numbers are given in host byte order; they are converted to and from
+/// network byte order when (de)serializing.
+#[doc(alias("IFLA_VXLAN_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VxlanAttribute {
+    /// `IFLA_VXLAN_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `IFLA_VXLAN_ID` - the VXLAN Network Identifier (24 bits).
+    Id(u32),
+
+    /// `IFLA_VXLAN_GROUP` - the multicast group or unicast remote address.
+    Group(Ipv4Addr),
+
+    /// `IFLA_VXLAN_LINK` - the index of the underlying interface.
+    Link(u32),
+
+    /// `IFLA_VXLAN_LOCAL`
+    Local(Ipv4Addr),
+
+    /// `IFLA_VXLAN_TTL`
+    Ttl(u8),
+
+    /// `IFLA_VXLAN_TOS`
+    Tos(u8),
+
+    /// `IFLA_VXLAN_LEARNING`
+    Learning(bool),
+
+    /// `IFLA_VXLAN_AGEING` - the FDB entry lifetime in seconds.
+    Ageing(u32),
+
+    /// `IFLA_VXLAN_LIMIT` - the maximum number of FDB entries.
+    Limit(u32),
+
+    /// `IFLA_VXLAN_PORT_RANGE` - the range of UDP source ports, (low, high).
+    PortRange(u16, u16),
+
+    /// `IFLA_VXLAN_PROXY`
+    Proxy(bool),
+
+    /// `IFLA_VXLAN_RSC`
+    RouteShortCircuit(bool),
+
+    /// `IFLA_VXLAN_L2MISS`
+    L2Miss(bool),
+
+    /// `IFLA_VXLAN_L3MISS`
+    L3Miss(bool),
+
+    /// `IFLA_VXLAN_PORT` - the UDP destination port.
+    Port(u16),
+
+    /// `IFLA_VXLAN_GROUP6`
+    Group6(Ipv6Addr),
+
+    /// `IFLA_VXLAN_LOCAL6`
+    Local6(Ipv6Addr),
+
+    /// `IFLA_VXLAN_UDP_CSUM`
+    UdpChecksum(bool),
+
+    /// `IFLA_VXLAN_UDP_ZERO_CSUM6_TX`
+    UdpZeroChecksum6Tx(bool),
+
+    /// `IFLA_VXLAN_UDP_ZERO_CSUM6_RX`
+    UdpZeroChecksum6Rx(bool),
+
+    /// `IFLA_VXLAN_REMCSUM_TX`
+    RemoteChecksumTx(bool),
+
+    /// `IFLA_VXLAN_REMCSUM_RX`
+    RemoteChecksumRx(bool),
+
+    /// `IFLA_VXLAN_GBP` - Group Based Policy extension
+    GroupBasedPolicy,
+
+    /// `IFLA_VXLAN_REMCSUM_NOPARTIAL`
+    RemoteChecksumNoPartial,
+
+    /// `IFLA_VXLAN_COLLECT_METADATA`
+    CollectMetadata(bool),
+
+    /// `IFLA_VXLAN_LABEL` - the IPv6 flow label.
+    Label(u32),
+
+    /// `IFLA_VXLAN_GPE` - Generic Protocol Extension
+    GenericProtocolExtension,
+
+    /// `IFLA_VXLAN_TTL_INHERIT`
+    TtlInherit(bool),
+
+    /// `IFLA_VXLAN_DF`
+    Df(VxlanDf),
+
+    /// `IFLA_VXLAN_VNIFILTER`
+    VniFilter(bool),
+
+    /// `IFLA_VXLAN_LOCALBYPASS`
+    LocalBypass(bool),
+
+    Other(u16, Vec<u8>),
+}
+
+impl VxlanAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                IFLA_VXLAN_UNSPEC
+            }
+            Self::Id(id) => {
+                buffer.extend(id.to_ne_bytes());
+                IFLA_VXLAN_ID
+            }
+            Self::Group(addr) => {
+                buffer.extend(addr.octets());
+                IFLA_VXLAN_GROUP
+            }
+            Self::Link(link) => {
+                buffer.extend(link.to_ne_bytes());
+                IFLA_VXLAN_LINK
+            }
+            Self::Local(addr) => {
+                buffer.extend(addr.octets());
+                IFLA_VXLAN_LOCAL
+            }
+            Self::Ttl(ttl) => {
+                buffer.push(*ttl);
+                IFLA_VXLAN_TTL
+            }
+            Self::Tos(tos) => {
+                buffer.push(*tos);
+                IFLA_VXLAN_TOS
+            }
+            Self::Learning(b) => {
+                buffer.push(*b as u8);
+                IFLA_VXLAN_LEARNING
+            }
+            Self::Ageing(ageing) => {
+                buffer.extend(ageing.to_ne_bytes());
+                IFLA_VXLAN_AGEING
+            }
+            Self::Limit(limit) => {
+                buffer.extend(limit.to_ne_bytes());
+                IFLA_VXLAN_LIMIT
+            }
+            Self::PortRange(low, high) => {
+                buffer.extend(low.to_be_bytes());
+                buffer.extend(high.to_be_bytes());
+                IFLA_VXLAN_PORT_RANGE
+            }
+            Self::Proxy(b) => {
+                buffer.push(*b as u8);
+                IFLA_VXLAN_PROXY
+            }
+            Self::RouteShortCircuit(b) => {
+                buffer.push(*b as u8);
+                IFLA_VXLAN_RSC
+            }
+            Self::L2Miss(b) => {
+                buffer.push(*b as u8);
+                IFLA_VXLAN_L2MISS
+            }
+            Self::L3Miss(b) => {
+                buffer.push(*b as u8);
+                IFLA_VXLAN_L3MISS
+            }
+            Self::Port(port) => {
+                buffer.extend(port.to_be_bytes());
+                IFLA_VXLAN_PORT
+            }
+            Self::Group6(addr) => {
+                buffer.extend(addr.octets());
+                IFLA_VXLAN_GROUP6
+            }
+            Self::Local6(addr) => {
+                buffer.extend(addr.octets());
+                IFLA_VXLAN_LOCAL6
+            }
+            Self::UdpChecksum(b) => {
+                buffer.push(*b as u8);
+                IFLA_VXLAN_UDP_CSUM
+            }
+            Self::UdpZeroChecksum6Tx(b) => {
+                buffer.push(*b as u8);
+                IFLA_VXLAN_UDP_ZERO_CSUM6_TX
+            }
+            Self::UdpZeroChecksum6Rx(b) => {
+                buffer.push(*b as u8);
+                IFLA_VXLAN_UDP_ZERO_CSUM6_RX
+            }
+            Self::RemoteChecksumTx(b) => {
+                buffer.push(*b as u8);
+                IFLA_VXLAN_REMCSUM_TX
+            }
+            Self::RemoteChecksumRx(b) => {
+                buffer.push(*b as u8);
+                IFLA_VXLAN_REMCSUM_RX
+            }
+            Self::GroupBasedPolicy => IFLA_VXLAN_GBP,
+            Self::RemoteChecksumNoPartial => IFLA_VXLAN_REMCSUM_NOPARTIAL,
+            Self::CollectMetadata(b) => {
+                buffer.push(*b as u8);
+                IFLA_VXLAN_COLLECT_METADATA
+            }
+            Self::Label(label) => {
+                buffer.extend(label.to_be_bytes());
+                IFLA_VXLAN_LABEL
+            }
+            Self::GenericProtocolExtension => IFLA_VXLAN_GPE,
+            Self::TtlInherit(b) => {
+                buffer.push(*b as u8);
+                IFLA_VXLAN_TTL_INHERIT
+            }
+            Self::Df(df) => {
+                buffer.push(df.raw_value());
+                IFLA_VXLAN_DF
+            }
+            Self::VniFilter(b) => {
+                buffer.push(*b as u8);
+                IFLA_VXLAN_VNIFILTER
+            }
+            Self::LocalBypass(b) => {
+                buffer.push(*b as u8);
+                IFLA_VXLAN_LOCALBYPASS
+            }
+            Self::Other(typ, content) => {
+                buffer.extend(content.iter());
+                *typ
+            }
+        })
+    }
+}
+
+impl Attribute for VxlanAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let read_bool = |content: &[u8]| content.first().map(|b| *b != 0);
+
+        let attr = match attr_type {
+            IFLA_VXLAN_UNSPEC => Self::Unspecified(content),
+            IFLA_VXLAN_ID => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Id(u32::from_ne_bytes(content))
+            }
+            IFLA_VXLAN_GROUP => Self::Group(<[u8; 4]>::try_from(content).ok()?.into()),
+            IFLA_VXLAN_LINK => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Link(u32::from_ne_bytes(content))
+            }
+            IFLA_VXLAN_LOCAL => Self::Local(<[u8; 4]>::try_from(content).ok()?.into()),
+            IFLA_VXLAN_TTL => Self::Ttl(*content.first()?),
+            IFLA_VXLAN_TOS => Self::Tos(*content.first()?),
+            IFLA_VXLAN_LEARNING => Self::Learning(read_bool(&content)?),
+            IFLA_VXLAN_AGEING => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Ageing(u32::from_ne_bytes(content))
+            }
+            IFLA_VXLAN_LIMIT => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Limit(u32::from_ne_bytes(content))
+            }
+            IFLA_VXLAN_PORT_RANGE => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                let low = u16::from_be_bytes([content[0], content[1]]);
+                let high = u16::from_be_bytes([content[2], content[3]]);
+                Self::PortRange(low, high)
+            }
+            IFLA_VXLAN_PROXY => Self::Proxy(read_bool(&content)?),
+            IFLA_VXLAN_RSC => Self::RouteShortCircuit(read_bool(&content)?),
+            IFLA_VXLAN_L2MISS => Self::L2Miss(read_bool(&content)?),
+            IFLA_VXLAN_L3MISS => Self::L3Miss(read_bool(&content)?),
+            IFLA_VXLAN_PORT => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::Port(u16::from_be_bytes(content))
+            }
+            IFLA_VXLAN_GROUP6 => Self::Group6(<[u8; 16]>::try_from(content).ok()?.into()),
+            IFLA_VXLAN_LOCAL6 => Self::Local6(<[u8; 16]>::try_from(content).ok()?.into()),
+            IFLA_VXLAN_UDP_CSUM => Self::UdpChecksum(read_bool(&content)?),
+            IFLA_VXLAN_UDP_ZERO_CSUM6_TX => Self::UdpZeroChecksum6Tx(read_bool(&content)?),
+            IFLA_VXLAN_UDP_ZERO_CSUM6_RX => Self::UdpZeroChecksum6Rx(read_bool(&content)?),
+            IFLA_VXLAN_REMCSUM_TX => Self::RemoteChecksumTx(read_bool(&content)?),
+            IFLA_VXLAN_REMCSUM_RX => Self::RemoteChecksumRx(read_bool(&content)?),
+            IFLA_VXLAN_GBP => Self::GroupBasedPolicy,
+            IFLA_VXLAN_REMCSUM_NOPARTIAL => Self::RemoteChecksumNoPartial,
+            IFLA_VXLAN_COLLECT_METADATA => Self::CollectMetadata(read_bool(&content)?),
+            IFLA_VXLAN_LABEL => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Label(u32::from_be_bytes(content))
+            }
+            IFLA_VXLAN_GPE => Self::GenericProtocolExtension,
+            IFLA_VXLAN_TTL_INHERIT => Self::TtlInherit(read_bool(&content)?),
+            IFLA_VXLAN_DF => Self::Df(VxlanDf::from_raw_value(*content.first()?)),
+            IFLA_VXLAN_VNIFILTER => Self::VniFilter(read_bool(&content)?),
+            IFLA_VXLAN_LOCALBYPASS => Self::LocalBypass(read_bool(&content)?),
+            typ => Self::Other(typ, content),
+        };
+
+        Some(attr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(attr_type: u16, payload: &[u8]) -> Option<VxlanAttribute> {
+        VxlanAttribute::from_raw(RawAttribute::new(attr_type, payload.to_vec()))
+    }
+
+    fn payload(attr: VxlanAttribute) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        attr.serialize_into(&mut buffer);
+        let length = u16::from_ne_bytes([buffer[0], buffer[1]]) as usize;
+        buffer[4..length].to_vec()
+    }
+
+    #[test]
+    fn ports_in_network_byte_order() {
+        assert_eq!(decode(IFLA_VXLAN_PORT, &[0x12, 0xb5]), Some(VxlanAttribute::Port(4789)));
+        assert_eq!(payload(VxlanAttribute::Port(4789)), [0x12, 0xb5]);
+
+        let range = decode(IFLA_VXLAN_PORT_RANGE, &[0x80, 0x00, 0xff, 0xff]);
+        assert_eq!(range, Some(VxlanAttribute::PortRange(32768, 65535)));
+        assert_eq!(payload(VxlanAttribute::PortRange(1000, 2000)), [0x03, 0xe8, 0x07, 0xd0]);
+
+        assert_eq!(decode(IFLA_VXLAN_PORT, &[0x12]), None);
+    }
+
+    #[test]
+    fn group() {
+        let group = decode(IFLA_VXLAN_GROUP, &[239, 1, 1, 1]);
+        assert_eq!(group, Some(VxlanAttribute::Group(Ipv4Addr::new(239, 1, 1, 1))));
+
+        let address: Ipv6Addr = "ff05::100".parse().unwrap();
+        assert_eq!(payload(VxlanAttribute::Group6(address)), address.octets());
+        assert_eq!(decode(IFLA_VXLAN_GROUP6, &address.octets()), Some(VxlanAttribute::Group6(address)));
+    }
+
+    #[test]
+    fn flags_and_labels() {
+        assert_eq!(decode(IFLA_VXLAN_LEARNING, &[0]), Some(VxlanAttribute::Learning(false)));
+        assert_eq!(decode(IFLA_VXLAN_GBP, &[]), Some(VxlanAttribute::GroupBasedPolicy));
+        assert_eq!(payload(VxlanAttribute::GroupBasedPolicy), []);
+
+        // The flow label is in network byte order
+        assert_eq!(payload(VxlanAttribute::Label(0x12345)), [0, 0x01, 0x23, 0x45]);
+        assert_eq!(decode(IFLA_VXLAN_DF, &[2]), Some(VxlanAttribute::Df(VxlanDf::Inherit)));
+        assert_eq!(decode(IFLA_VXLAN_DF, &[9]), Some(VxlanAttribute::Df(VxlanDf::Other(9))));
+    }
+}
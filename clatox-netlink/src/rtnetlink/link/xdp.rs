@@ -0,0 +1,234 @@
+use std::os::fd::RawFd;
+
+use crate::netlink::{Attribute, RawAttribute};
+use crate::utils;
+
+use bitflags::bitflags;
+
+pub const IFLA_XDP_UNSPEC: u16 = 0;
+pub const IFLA_XDP_FD: u16 = 1;
+pub const IFLA_XDP_ATTACHED: u16 = 2;
+pub const IFLA_XDP_FLAGS: u16 = 3;
+pub const IFLA_XDP_PROG_ID: u16 = 4;
+pub const IFLA_XDP_DRV_PROG_ID: u16 = 5;
+pub const IFLA_XDP_SKB_PROG_ID: u16 = 6;
+pub const IFLA_XDP_HW_PROG_ID: u16 = 7;
+pub const IFLA_XDP_EXPECTED_FD: u16 = 8;
+
+pub const XDP_ATTACHED_NONE: u8 = 0;
+pub const XDP_ATTACHED_DRV: u8 = 1;
+pub const XDP_ATTACHED_SKB: u8 = 2;
+pub const XDP_ATTACHED_HW: u8 = 3;
+pub const XDP_ATTACHED_MULTI: u8 = 4;
+
+pub const XDP_FLAGS_UPDATE_IF_NOEXIST: u32 = 1 << 0;
+pub const XDP_FLAGS_SKB_MODE: u32 = 1 << 1;
+pub const XDP_FLAGS_DRV_MODE: u32 = 1 << 2;
+pub const XDP_FLAGS_HW_MODE: u32 = 1 << 3;
+pub const XDP_FLAGS_REPLACE: u32 = 1 << 4;
+
+bitflags! {
+    /// A bitfield of flags controlling how an XDP program is attached.
+    ///
+    /// At most one of the mode flags may be given. Without any, the kernel
+    /// picks the driver mode if supported, and the generic mode otherwise.
+    ///
+    /// It corresponds to `XDP_FLAGS_*` in <linux/if_link.h>.
+    #[doc(alias("XDP_FLAGS_"))]
+    #[derive(Copy, Debug, Clone, PartialEq, Eq)]
+    pub struct XdpFlags: u32 {
+        /// Fail if a program is already attached
+        const UpdateIfNoExist = XDP_FLAGS_UPDATE_IF_NOEXIST;
+        /// Generic mode, which works on any device but is slower
+        const GenericMode = XDP_FLAGS_SKB_MODE;
+        /// Native mode, run by the driver
+        const DriverMode = XDP_FLAGS_DRV_MODE;
+        /// Offloaded to the hardware
+        const HardwareMode = XDP_FLAGS_HW_MODE;
+        /// Only replace the program given by `XdpAttribute::ExpectedFd`
+        const Replace = XDP_FLAGS_REPLACE;
+    }
+}
+
+/// How the XDP programs of a link are attached. Corresponds to
+/// `XDP_ATTACHED_*` in libc.
+#[doc(alias("XDP_ATTACHED_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XdpAttachMode {
+    /// No program is attached.
+    None,
+
+    /// Native mode.
+    Driver,
+
+    /// Generic mode.
+    Generic,
+
+    /// Offloaded mode.
+    Hardware,
+
+    /// Programs are attached in more than one mode, see the `*ProgramId`
+    /// attributes for the programs of each mode.
+    Multiple,
+
+    /// Any other value
+    Other(u8),
+}
+
+impl XdpAttachMode {
+    pub const fn raw_value(&self) -> u8 {
+        match self {
+            Self::None => XDP_ATTACHED_NONE,
+            Self::Driver => XDP_ATTACHED_DRV,
+            Self::Generic => XDP_ATTACHED_SKB,
+            Self::Hardware => XDP_ATTACHED_HW,
+            Self::Multiple => XDP_ATTACHED_MULTI,
+            Self::Other(x) => *x,
+        }
+    }
+
+    pub const fn from_raw_value(value: u8) -> Self {
+        match value {
+            XDP_ATTACHED_NONE => Self::None,
+            XDP_ATTACHED_DRV => Self::Driver,
+            XDP_ATTACHED_SKB => Self::Generic,
+            XDP_ATTACHED_HW => Self::Hardware,
+            XDP_ATTACHED_MULTI => Self::Multiple,
+            x => Self::Other(x),
+        }
+    }
+}
+
+/// Attributes nested in `IFLA_XDP`. They correspond to `IFLA_XDP_*` in libc.
+///
+/// To attach a program, send `Fd` (and optionally `Flags` and `ExpectedFd`) in
+/// a `SetLink` request. To detach it, send an `Fd` of -1 with the same mode
+/// flags. The kernel reports `Attached` and the program ids in link dumps.
+#[doc(alias("IFLA_XDP_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum XdpAttribute {
+    /// `IFLA_XDP_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `IFLA_XDP_FD` - the file descriptor of a loaded BPF program of the
+    /// `BPF_PROG_TYPE_XDP` type, or -1 to detach.
+    Fd(RawFd),
+
+    /// `IFLA_XDP_ATTACHED`
+    Attached(XdpAttachMode),
+
+    /// `IFLA_XDP_FLAGS`
+    Flags(XdpFlags),
+
+    /// `IFLA_XDP_PROG_ID` - the id of the attached program, if only one is
+    /// attached.
+    ProgramId(u32),
+
+    /// `IFLA_XDP_DRV_PROG_ID`
+    DriverProgramId(u32),
+
+    /// `IFLA_XDP_SKB_PROG_ID`
+    GenericProgramId(u32),
+
+    /// `IFLA_XDP_HW_PROG_ID`
+    HardwareProgramId(u32),
+
+    /// `IFLA_XDP_EXPECTED_FD` - the file descriptor of the program that is
+    /// expected to be replaced. Used with `XdpFlags::Replace`.
+    ExpectedFd(RawFd),
+
+    Other(u16, Vec<u8>),
+}
+
+impl XdpAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                IFLA_XDP_UNSPEC
+            }
+            Self::Fd(fd) => {
+                buffer.extend(fd.to_ne_bytes());
+                IFLA_XDP_FD
+            }
+            Self::Attached(mode) => {
+                buffer.push(mode.raw_value());
+                IFLA_XDP_ATTACHED
+            }
+            Self::Flags(flags) => {
+                buffer.extend(flags.bits().to_ne_bytes());
+                IFLA_XDP_FLAGS
+            }
+            Self::ProgramId(id) => {
+                buffer.extend(id.to_ne_bytes());
+                IFLA_XDP_PROG_ID
+            }
+            Self::DriverProgramId(id) => {
+                buffer.extend(id.to_ne_bytes());
+                IFLA_XDP_DRV_PROG_ID
+            }
+            Self::GenericProgramId(id) => {
+                buffer.extend(id.to_ne_bytes());
+                IFLA_XDP_SKB_PROG_ID
+            }
+            Self::HardwareProgramId(id) => {
+                buffer.extend(id.to_ne_bytes());
+                IFLA_XDP_HW_PROG_ID
+            }
+            Self::ExpectedFd(fd) => {
+                buffer.extend(fd.to_ne_bytes());
+                IFLA_XDP_EXPECTED_FD
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for XdpAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            IFLA_XDP_UNSPEC => Self::Unspecified(content),
+            IFLA_XDP_FD => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Fd(RawFd::from_ne_bytes(content))
+            }
+            IFLA_XDP_ATTACHED => {
+                let content = <[u8; 1]>::try_from(content).ok()?;
+                Self::Attached(XdpAttachMode::from_raw_value(content[0]))
+            }
+            IFLA_XDP_FLAGS => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Flags(XdpFlags::from_bits_retain(u32::from_ne_bytes(content)))
+            }
+            IFLA_XDP_PROG_ID => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::ProgramId(u32::from_ne_bytes(content))
+            }
+            IFLA_XDP_DRV_PROG_ID => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::DriverProgramId(u32::from_ne_bytes(content))
+            }
+            IFLA_XDP_SKB_PROG_ID => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::GenericProgramId(u32::from_ne_bytes(content))
+            }
+            IFLA_XDP_HW_PROG_ID => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::HardwareProgramId(u32::from_ne_bytes(content))
+            }
+            IFLA_XDP_EXPECTED_FD => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::ExpectedFd(RawFd::from_ne_bytes(content))
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
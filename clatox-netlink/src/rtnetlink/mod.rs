@@ -1,12 +1,30 @@
 mod address;
 mod address_family;
+mod address_label;
+mod ip_prefix;
 mod link;
+mod network_address;
+mod next_hop;
 mod route;
+mod rule;
+mod stats;
 
 pub use address::*;
 
 pub use link::*;
 
+pub use network_address::*;
+
+pub use next_hop::*;
+
 pub use address_family::*;
 
+pub use address_label::*;
+
+pub use ip_prefix::*;
+
 pub use route::*;
+
+pub use rule::*;
+
+pub use stats::*;
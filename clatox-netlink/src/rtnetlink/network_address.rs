@@ -0,0 +1,223 @@
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use super::{AddressFamily, MacAddress};
+
+/// The bottom of stack bit of an MPLS label stack entry.
+const MPLS_LS_S_MASK: u32 = 0x0000_0100;
+const MPLS_LS_LABEL_SHIFT: u32 = 12;
+
+/// A network layer address, as carried in the address attributes of address
+/// and route messages, e.g. `IFA_ADDRESS` or `RTA_GATEWAY`. Its format is
+/// given by the address family of the message.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NetworkAddress {
+    Ipv4(Ipv4Addr),
+
+    Ipv6(Ipv6Addr),
+
+    /// A stack of MPLS labels, the outermost first. The traffic class and
+    /// TTL of the entries are not kept.
+    Mpls(Vec<u32>),
+
+    /// The MAC address of a bridge entry.
+    Bridge(MacAddress),
+
+    /// An address of another family, or an address which does not match the
+    /// length of its family.
+    Other(Vec<u8>),
+}
+
+impl NetworkAddress {
+    /// Interprets the content of an address attribute of a message of the
+    /// given address family.
+    pub fn from_bytes(family: AddressFamily, bytes: &[u8]) -> Self {
+        match family {
+            AddressFamily::Inet | AddressFamily::Ipmr => match <[u8; 4]>::try_from(bytes) {
+                Ok(octets) => Self::Ipv4(Ipv4Addr::from(octets)),
+                Err(_) => Self::Other(bytes.to_vec()),
+            },
+            AddressFamily::Inet6 | AddressFamily::Ip6mr => match <[u8; 16]>::try_from(bytes) {
+                Ok(octets) => Self::Ipv6(Ipv6Addr::from(octets)),
+                Err(_) => Self::Other(bytes.to_vec()),
+            },
+            AddressFamily::Mpls if !bytes.is_empty() && bytes.len().is_multiple_of(4) => {
+                let labels = bytes
+                    .chunks_exact(4)
+                    .map(|x| u32::from_be_bytes([x[0], x[1], x[2], x[3]]) >> MPLS_LS_LABEL_SHIFT)
+                    .collect();
+                Self::Mpls(labels)
+            }
+            AddressFamily::Bridge => match <[u8; 6]>::try_from(bytes) {
+                Ok(octets) => Self::Bridge(MacAddress(octets)),
+                Err(_) => Self::Other(bytes.to_vec()),
+            },
+            _ => Self::Other(bytes.to_vec()),
+        }
+    }
+
+    /// Interprets an address that was decoded on its own, as `Other`, once
+    /// the address family of its message is known.
+    pub(crate) fn resolve(&mut self, family: AddressFamily) {
+        if let Self::Other(bytes) = self {
+            *self = Self::from_bytes(family, bytes);
+        }
+    }
+
+    /// The address as sent in an address attribute. The last entry of an
+    /// MPLS label stack is marked as the bottom of the stack.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::Ipv4(address) => address.octets().to_vec(),
+            Self::Ipv6(address) => address.octets().to_vec(),
+            Self::Mpls(labels) => {
+                let mut bytes = Vec::with_capacity(labels.len() * 4);
+                for (i, label) in labels.iter().enumerate() {
+                    let mut entry = label << MPLS_LS_LABEL_SHIFT;
+                    if i == labels.len() - 1 {
+                        entry |= MPLS_LS_S_MASK;
+                    }
+                    bytes.extend(entry.to_be_bytes());
+                }
+                bytes
+            }
+            Self::Bridge(address) => address.octets().to_vec(),
+            Self::Other(bytes) => bytes.clone(),
+        }
+    }
+
+    /// The address family of the address, or `None` for `Other` addresses.
+    pub const fn family(&self) -> Option<AddressFamily> {
+        match self {
+            Self::Ipv4(_) => Some(AddressFamily::Inet),
+            Self::Ipv6(_) => Some(AddressFamily::Inet6),
+            Self::Mpls(_) => Some(AddressFamily::Mpls),
+            Self::Bridge(_) => Some(AddressFamily::Bridge),
+            Self::Other(_) => None,
+        }
+    }
+
+    /// The IP address, if it is one.
+    pub const fn ip(&self) -> Option<IpAddr> {
+        match self {
+            Self::Ipv4(address) => Some(IpAddr::V4(*address)),
+            Self::Ipv6(address) => Some(IpAddr::V6(*address)),
+            _ => None,
+        }
+    }
+}
+
+/// An attribute which may carry an address. Address attributes are decoded
+/// as `NetworkAddress::Other`, since their format is given by the address
+/// family of the message they belong to.
+pub(crate) trait AddressAttribute {
+    fn address_mut(&mut self) -> Option<&mut NetworkAddress>;
+}
+
+/// Interprets the addresses of the attributes of a message of the given
+/// address family.
+pub(crate) fn resolve_addresses<T: AddressAttribute>(family: AddressFamily, attributes: &mut [T]) {
+    for address in attributes.iter_mut().filter_map(T::address_mut) {
+        address.resolve(family);
+    }
+}
+
+impl From<IpAddr> for NetworkAddress {
+    fn from(address: IpAddr) -> Self {
+        match address {
+            IpAddr::V4(address) => Self::Ipv4(address),
+            IpAddr::V6(address) => Self::Ipv6(address),
+        }
+    }
+}
+
+impl From<Ipv4Addr> for NetworkAddress {
+    fn from(address: Ipv4Addr) -> Self {
+        Self::Ipv4(address)
+    }
+}
+
+impl From<Ipv6Addr> for NetworkAddress {
+    fn from(address: Ipv6Addr) -> Self {
+        Self::Ipv6(address)
+    }
+}
+
+/// IP and MAC addresses are displayed in their usual form, MPLS label stacks
+/// as slash-separated labels like `ip` does, and other addresses as
+/// colon-separated hexadecimal octets.
+impl fmt::Display for NetworkAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ipv4(address) => address.fmt(f),
+            Self::Ipv6(address) => address.fmt(f),
+            Self::Mpls(labels) => {
+                let labels: Vec<_> = labels.iter().map(u32::to_string).collect();
+                f.write_str(&labels.join("/"))
+            }
+            Self::Bridge(address) => address.fmt(f),
+            Self::Other(bytes) => {
+                let octets: Vec<_> = bytes.iter().map(|x| format!("{x:02x}")).collect();
+                f.write_str(&octets.join(":"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rtnetlink::RuleAttribute;
+
+    #[test]
+    fn from_bytes_by_family() {
+        let address = NetworkAddress::from_bytes(AddressFamily::Inet, &[192, 0, 2, 1]);
+        assert_eq!(address, NetworkAddress::Ipv4(Ipv4Addr::new(192, 0, 2, 1)));
+
+        let octets = "2001:db8::1".parse::<Ipv6Addr>().unwrap().octets();
+        let address = NetworkAddress::from_bytes(AddressFamily::Inet6, &octets);
+        assert_eq!(address.ip(), Some("2001:db8::1".parse().unwrap()));
+
+        // The same bytes in a message of another family are not an IPv6 address
+        let address = NetworkAddress::from_bytes(AddressFamily::Inet, &octets);
+        assert_eq!(address, NetworkAddress::Other(octets.to_vec()));
+
+        let address = NetworkAddress::from_bytes(AddressFamily::Bridge, &[2, 0, 0, 0, 0, 1]);
+        assert_eq!(address.to_string(), "02:00:00:00:00:01");
+    }
+
+    #[test]
+    fn mpls_label_stack() {
+        // Labels 100 and 200 with a traffic class and TTL, 200 at the bottom
+        let bytes = [0x00, 0x06, 0x40, 0x40, 0x00, 0x0c, 0x83, 0x40];
+        let address = NetworkAddress::from_bytes(AddressFamily::Mpls, &bytes);
+        assert_eq!(address, NetworkAddress::Mpls(vec![100, 200]));
+        assert_eq!(address.to_string(), "100/200");
+
+        assert_eq!(address.to_bytes(), [0x00, 0x06, 0x40, 0x00, 0x00, 0x0c, 0x81, 0x00]);
+        assert_eq!(
+            NetworkAddress::from_bytes(AddressFamily::Mpls, &bytes[..3]),
+            NetworkAddress::Other(bytes[..3].to_vec())
+        );
+    }
+
+    #[test]
+    fn resolve_addresses_of_attributes() {
+        let mut attributes = vec![
+            RuleAttribute::Source(NetworkAddress::Other(vec![192, 0, 2, 0])),
+            RuleAttribute::Priority(100),
+            RuleAttribute::Destination(NetworkAddress::Other(vec![192, 0, 2])),
+        ];
+        resolve_addresses(AddressFamily::Inet, &mut attributes);
+
+        // Addresses not matching the length of the family are left as they are
+        assert_eq!(
+            attributes,
+            [
+                RuleAttribute::Source(NetworkAddress::Ipv4(Ipv4Addr::new(192, 0, 2, 0))),
+                RuleAttribute::Priority(100),
+                RuleAttribute::Destination(NetworkAddress::Other(vec![192, 0, 2])),
+            ]
+        );
+    }
+}
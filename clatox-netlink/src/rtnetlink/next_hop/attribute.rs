@@ -0,0 +1,209 @@
+use crate::netlink::{Attribute, RawAttribute, RawAttributeIter};
+use crate::rtnetlink::{AddressAttribute, NetworkAddress};
+use crate::utils;
+
+use super::{NextHopGroupEntry, NextHopGroupType, NextHopResilientGroupAttribute};
+
+pub const NHA_UNSPEC: u16 = 0;
+pub const NHA_ID: u16 = 1;
+pub const NHA_GROUP: u16 = 2;
+pub const NHA_GROUP_TYPE: u16 = 3;
+pub const NHA_BLACKHOLE: u16 = 4;
+pub const NHA_OIF: u16 = 5;
+pub const NHA_GATEWAY: u16 = 6;
+pub const NHA_ENCAP_TYPE: u16 = 7;
+pub const NHA_ENCAP: u16 = 8;
+pub const NHA_GROUPS: u16 = 9;
+pub const NHA_MASTER: u16 = 10;
+pub const NHA_FDB: u16 = 11;
+pub const NHA_RES_GROUP: u16 = 12;
+pub const NHA_RES_BUCKET: u16 = 13;
+pub const NHA_OP_FLAGS: u16 = 14;
+
+/// Attributes of a nexthop message. They correspond to `NHA_*` in
+/// <linux/nexthop.h>.
+///
+/// A nexthop is either a single next hop, given by `OutgoingInterface` and
+/// `Gateway`, a `Blackhole`, or a group of other nexthops, given by `Group`.
+///
+/// The gateway is interpreted according to the address family of the
+/// message; on its own, it is decoded as `NetworkAddress::Other`.
+#[doc(alias("nhmsg", "NHA_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NextHopAttribute {
+    /// `NHA_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `NHA_ID` - the id routes refer to the nexthop by. The kernel picks
+    /// one if it is absent when the nexthop is created.
+    Id(u32),
+
+    /// `NHA_GROUP`
+    Group(Vec<NextHopGroupEntry>),
+
+    /// `NHA_GROUP_TYPE`
+    GroupType(NextHopGroupType),
+
+    /// `NHA_BLACKHOLE` - the packets are dropped.
+    Blackhole,
+
+    /// `NHA_OIF`
+    OutgoingInterface(u32),
+
+    /// `NHA_GATEWAY`
+    Gateway(NetworkAddress),
+
+    /// `NHA_ENCAP_TYPE`
+    EncapsulationType(u16),
+
+    /// `NHA_ENCAP`
+    Encapsulation(Vec<u8>),
+
+    /// `NHA_GROUPS` - dumps only the groups.
+    Groups,
+
+    /// `NHA_MASTER` - dumps only the nexthops whose interface is enslaved to
+    /// the interface of the given index.
+    Master(u32),
+
+    /// `NHA_FDB` - the nexthop is used by the bridge FDB entries of VXLAN
+    /// devices rather than by routes.
+    Fdb,
+
+    /// `NHA_RES_GROUP`
+    ResilientGroup(Vec<NextHopResilientGroupAttribute>),
+
+    /// `NHA_RES_BUCKET`
+    ResilientBucket(Vec<u8>),
+
+    /// `NHA_OP_FLAGS`
+    OperationFlags(u32),
+
+    Other(u16, Vec<u8>),
+}
+
+impl NextHopAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                NHA_UNSPEC
+            }
+            Self::Id(id) => {
+                buffer.extend(id.to_ne_bytes());
+                NHA_ID
+            }
+            Self::Group(entries) => {
+                for entry in entries {
+                    entry.serialize_into(buffer);
+                }
+                NHA_GROUP
+            }
+            Self::GroupType(group_type) => {
+                buffer.extend(group_type.raw_value().to_ne_bytes());
+                NHA_GROUP_TYPE
+            }
+            Self::Blackhole => NHA_BLACKHOLE,
+            Self::OutgoingInterface(index) => {
+                buffer.extend(index.to_ne_bytes());
+                NHA_OIF
+            }
+            Self::Gateway(address) => {
+                buffer.extend(address.to_bytes());
+                NHA_GATEWAY
+            }
+            Self::EncapsulationType(encap_type) => {
+                buffer.extend(encap_type.to_ne_bytes());
+                NHA_ENCAP_TYPE
+            }
+            Self::Encapsulation(content) => {
+                buffer.extend(content.iter());
+                NHA_ENCAP | libc::NLA_F_NESTED as u16
+            }
+            Self::Groups => NHA_GROUPS,
+            Self::Master(index) => {
+                buffer.extend(index.to_ne_bytes());
+                NHA_MASTER
+            }
+            Self::Fdb => NHA_FDB,
+            Self::ResilientGroup(attributes) => {
+                for attr in attributes {
+                    attr.serialize_into(buffer);
+                }
+                NHA_RES_GROUP | libc::NLA_F_NESTED as u16
+            }
+            Self::ResilientBucket(content) => {
+                buffer.extend(content.iter());
+                NHA_RES_BUCKET | libc::NLA_F_NESTED as u16
+            }
+            Self::OperationFlags(flags) => {
+                buffer.extend(flags.to_ne_bytes());
+                NHA_OP_FLAGS
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for NextHopAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            NHA_UNSPEC => Self::Unspecified(content),
+            NHA_ID => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Id(u32::from_ne_bytes(content))
+            }
+            NHA_GROUP => Self::Group(NextHopGroupEntry::deserialize_list(&content)?),
+            NHA_GROUP_TYPE => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::GroupType(NextHopGroupType::from_raw_value(u16::from_ne_bytes(content)))
+            }
+            NHA_BLACKHOLE => Self::Blackhole,
+            NHA_OIF => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::OutgoingInterface(u32::from_ne_bytes(content))
+            }
+            NHA_GATEWAY => Self::Gateway(NetworkAddress::Other(content)),
+            NHA_ENCAP_TYPE => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::EncapsulationType(u16::from_ne_bytes(content))
+            }
+            NHA_ENCAP => Self::Encapsulation(content),
+            NHA_GROUPS => Self::Groups,
+            NHA_MASTER => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::Master(u32::from_ne_bytes(content))
+            }
+            NHA_FDB => Self::Fdb,
+            NHA_RES_GROUP => {
+                let attributes = RawAttributeIter::new(content.iter().cloned())
+                    .map(NextHopResilientGroupAttribute::from_raw)
+                    .try_collect()?;
+                Self::ResilientGroup(attributes)
+            }
+            NHA_RES_BUCKET => Self::ResilientBucket(content),
+            NHA_OP_FLAGS => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::OperationFlags(u32::from_ne_bytes(content))
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+impl AddressAttribute for NextHopAttribute {
+    fn address_mut(&mut self) -> Option<&mut NetworkAddress> {
+        match self {
+            Self::Gateway(address) => Some(address),
+            _ => None,
+        }
+    }
+}
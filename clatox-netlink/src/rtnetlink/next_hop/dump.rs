@@ -0,0 +1,51 @@
+use std::io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult};
+
+use crate::netlink::{Flags, Message, ReceivedMessage, Socket};
+use crate::rtnetlink::{AddressFamily, RouteProtocol};
+
+use super::{GetNextHop, NewNextHop, NextHopAttribute, NextHopMessage, NextHopMessageFlags};
+
+/// Dumps the nexthops, like `ip nexthop show` does. The dump can be narrowed
+/// down with the `OutgoingInterface`, `Master`, `Groups` and `Fdb`
+/// attributes.
+pub fn dump_next_hops(
+    socket: &mut Socket,
+    filter: Vec<NextHopAttribute>,
+) -> IoResult<Vec<NextHopMessage>> {
+    let request = GetNextHop(NextHopMessage::new(
+        AddressFamily::Unspecified,
+        RouteProtocol::Unspecified,
+        NextHopMessageFlags::empty(),
+        filter,
+    ));
+    socket.send_message(&Message::new(Flags::Request | Flags::Dump, request))?;
+
+    match socket.receive_message::<NewNextHop>()? {
+        ReceivedMessage::Multipart(messages) => {
+            Ok(messages.into_iter().map(|x| x.into_payload().0).collect())
+        }
+        ReceivedMessage::Message(message) => Ok(vec![message.into_payload().0]),
+        ReceivedMessage::Error(error) => {
+            Err(IoError::from_raw_os_error(-error.payload().error_code()))
+        }
+    }
+}
+
+/// Gets the nexthop of the given id. Fails with `ENOENT` if there is none.
+pub fn get_next_hop(socket: &mut Socket, id: u32) -> IoResult<NextHopMessage> {
+    let request = GetNextHop(NextHopMessage::new(
+        AddressFamily::Unspecified,
+        RouteProtocol::Unspecified,
+        NextHopMessageFlags::empty(),
+        vec![NextHopAttribute::Id(id)],
+    ));
+    socket.send_message(&Message::new(Flags::Request, request))?;
+
+    match socket.receive_message::<NewNextHop>()? {
+        ReceivedMessage::Message(message) => Ok(message.into_payload().0),
+        ReceivedMessage::Error(error) => {
+            Err(IoError::from_raw_os_error(-error.payload().error_code()))
+        }
+        ReceivedMessage::Multipart(_) => Err(IoErrorKind::InvalidData)?,
+    }
+}
@@ -0,0 +1,27 @@
+use bitflags::bitflags;
+
+use crate::rtnetlink::{
+    RTNH_F_DEAD, RTNH_F_LINKDOWN, RTNH_F_OFFLOAD, RTNH_F_ONLINK, RTNH_F_PERVASIVE, RTNH_F_TRAP,
+    RTNH_F_UNRESOLVED,
+};
+
+bitflags! {
+    /// The flags of a nexthop object. They are the `RTNH_F_*` flags of the
+    /// next hops of routes, but `nhmsg` has room for 32 bits of them, while
+    /// `rtnexthop` only has 8, see `NextHopFlags`.
+    #[doc(alias("nhmsg", "nh_flags", "RTNH_F_"))]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct NextHopMessageFlags: u32 {
+        /// The nexthop is unusable, e.g. because its interface is down.
+        const Dead = RTNH_F_DEAD as u32;
+        const Pervasive = RTNH_F_PERVASIVE as u32;
+
+        /// The gateway is reachable through the interface even though no
+        /// prefix of the interface contains it.
+        const OnLink = RTNH_F_ONLINK as u32;
+        const Offload = RTNH_F_OFFLOAD as u32;
+        const LinkDown = RTNH_F_LINKDOWN as u32;
+        const Unresolved = RTNH_F_UNRESOLVED as u32;
+        const Trap = RTNH_F_TRAP as u32;
+    }
+}
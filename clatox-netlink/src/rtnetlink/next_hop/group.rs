@@ -0,0 +1,209 @@
+use crate::netlink::{Attribute, RawAttribute};
+use crate::utils;
+
+pub const NEXTHOP_GRP_TYPE_MPATH: u16 = 0;
+pub const NEXTHOP_GRP_TYPE_RES: u16 = 1;
+
+pub const NHA_RES_GROUP_PAD: u16 = 0;
+pub const NHA_RES_GROUP_BUCKETS: u16 = 1;
+pub const NHA_RES_GROUP_IDLE_TIMER: u16 = 2;
+pub const NHA_RES_GROUP_UNBALANCED_TIMER: u16 = 3;
+pub const NHA_RES_GROUP_UNBALANCED_TIME: u16 = 4;
+
+/// A member of a nexthop group, i.e. an entry of `NHA_GROUP`. It corresponds
+/// to the `nexthop_grp` struct in <linux/nexthop.h>.
+#[doc(alias("nexthop_grp"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NextHopGroupEntry {
+    /// The id of the member nexthop.
+    pub id: u32,
+
+    /// The weight of the member, which is at least 1. Weights above 256 need
+    /// Linux 6.12 or later.
+    pub weight: u16,
+}
+
+impl NextHopGroupEntry {
+    pub const fn new(id: u32, weight: u16) -> Self {
+        Self { id, weight }
+    }
+
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        // The weight is sent minus one, its upper byte in weight_high
+        let weight = self.weight.max(1) - 1;
+
+        buffer.extend(self.id.to_ne_bytes());
+        buffer.push(weight as u8);
+        buffer.push((weight >> 8) as u8);
+        buffer.extend(0u16.to_ne_bytes()); // resvd2
+    }
+
+    /// Deserializes the payload of `NHA_GROUP`, i.e. a list of entries.
+    pub fn deserialize_list(bytes: &[u8]) -> Option<Vec<Self>> {
+        if !bytes.len().is_multiple_of(8) {
+            return None;
+        }
+
+        let entries = bytes
+            .chunks_exact(8)
+            .map(|x| {
+                let id = u32::from_ne_bytes([x[0], x[1], x[2], x[3]]);
+                let weight = u16::from_le_bytes([x[4], x[5]]).saturating_add(1);
+                Self { id, weight }
+            })
+            .collect();
+
+        Some(entries)
+    }
+}
+
+/// The type of a nexthop group (`NHA_GROUP_TYPE`).
+#[doc(alias("NEXTHOP_GRP_TYPE_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NextHopGroupType {
+    /// `NEXTHOP_GRP_TYPE_MPATH` - the members are picked by hashing the
+    /// flows, like the next hops of a multipath route.
+    Multipath,
+
+    /// `NEXTHOP_GRP_TYPE_RES` - the flows are assigned to a fixed number of
+    /// buckets, which are only migrated to other members once idle, so that
+    /// flows keep their member when others are added or removed.
+    Resilient,
+
+    Other(u16),
+}
+
+impl NextHopGroupType {
+    pub const fn raw_value(&self) -> u16 {
+        match self {
+            Self::Multipath => NEXTHOP_GRP_TYPE_MPATH,
+            Self::Resilient => NEXTHOP_GRP_TYPE_RES,
+            Self::Other(x) => *x,
+        }
+    }
+
+    pub const fn from_raw_value(value: u16) -> Self {
+        match value {
+            NEXTHOP_GRP_TYPE_MPATH => Self::Multipath,
+            NEXTHOP_GRP_TYPE_RES => Self::Resilient,
+            x => Self::Other(x),
+        }
+    }
+}
+
+/// Attributes nested in `NHA_RES_GROUP`, which configure a resilient group.
+/// They correspond to `NHA_RES_GROUP_*` in <linux/nexthop.h>.
+///
+/// The timers are in clock ticks (`USER_HZ`), i.e. usually in 1/100 s.
+#[doc(alias("NHA_RES_GROUP_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NextHopResilientGroupAttribute {
+    /// `NHA_RES_GROUP_PAD`
+    Pad(Vec<u8>),
+
+    /// `NHA_RES_GROUP_BUCKETS` - the number of buckets, which cannot be
+    /// changed once the group is created.
+    Buckets(u16),
+
+    /// `NHA_RES_GROUP_IDLE_TIMER` - how long a bucket has to be idle before
+    /// it is migrated to another member.
+    IdleTimer(u32),
+
+    /// `NHA_RES_GROUP_UNBALANCED_TIMER` - how long the group may be
+    /// unbalanced before buckets are migrated regardless of their activity,
+    /// or 0 to never force the migration.
+    UnbalancedTimer(u32),
+
+    /// `NHA_RES_GROUP_UNBALANCED_TIME` - how long the group has been
+    /// unbalanced. It is only reported by the kernel.
+    UnbalancedTime(u64),
+
+    Other(u16, Vec<u8>),
+}
+
+impl NextHopResilientGroupAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Pad(content) => {
+                buffer.extend(content.iter());
+                NHA_RES_GROUP_PAD
+            }
+            Self::Buckets(buckets) => {
+                buffer.extend(buckets.to_ne_bytes());
+                NHA_RES_GROUP_BUCKETS
+            }
+            Self::IdleTimer(timer) => {
+                buffer.extend(timer.to_ne_bytes());
+                NHA_RES_GROUP_IDLE_TIMER
+            }
+            Self::UnbalancedTimer(timer) => {
+                buffer.extend(timer.to_ne_bytes());
+                NHA_RES_GROUP_UNBALANCED_TIMER
+            }
+            Self::UnbalancedTime(time) => {
+                buffer.extend(time.to_ne_bytes());
+                NHA_RES_GROUP_UNBALANCED_TIME
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for NextHopResilientGroupAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            NHA_RES_GROUP_PAD => Self::Pad(content),
+            NHA_RES_GROUP_BUCKETS => {
+                let content = <[u8; 2]>::try_from(content).ok()?;
+                Self::Buckets(u16::from_ne_bytes(content))
+            }
+            NHA_RES_GROUP_IDLE_TIMER => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::IdleTimer(u32::from_ne_bytes(content))
+            }
+            NHA_RES_GROUP_UNBALANCED_TIMER => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                Self::UnbalancedTimer(u32::from_ne_bytes(content))
+            }
+            NHA_RES_GROUP_UNBALANCED_TIME => {
+                let content = <[u8; 8]>::try_from(content).ok()?;
+                Self::UnbalancedTime(u64::from_ne_bytes(content))
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weights() {
+        let entries = [
+            NextHopGroupEntry::new(1, 1),
+            NextHopGroupEntry::new(2, 256),
+            NextHopGroupEntry::new(3, 300),
+        ];
+
+        let mut buffer = Vec::new();
+        entries.iter().for_each(|x| x.serialize_into(&mut buffer));
+
+        // struct nexthop_grp with the weight minus one split into weight and
+        // weight_high
+        let weights: Vec<_> = buffer.chunks_exact(8).map(|x| [x[4], x[5]]).collect();
+        assert_eq!(weights, [[0, 0], [255, 0], [43, 1]]);
+        assert_eq!(&buffer[16..20], &3u32.to_ne_bytes());
+
+        assert_eq!(NextHopGroupEntry::deserialize_list(&buffer).unwrap(), entries);
+        assert_eq!(NextHopGroupEntry::deserialize_list(&buffer[..12]), None);
+    }
+}
@@ -0,0 +1,217 @@
+use crate::netlink::{Attribute, Payload, RawAttributeIter, RouteType, Type};
+use crate::rtnetlink::{resolve_addresses, AddressFamily, RouteProtocol, RouteScope};
+use crate::utils::read_u32;
+
+use super::{NextHopAttribute, NextHopMessageFlags};
+
+/// The message on which the `GetNextHop`, `NewNextHop` and `DeleteNextHop`
+/// requests are based on. It corresponds to the `nhmsg` struct in
+/// <linux/nexthop.h>.
+///
+/// Nexthop objects are shared by the routes referring to them with
+/// `RouteAttribute::NextHopId`, so that a change of the nexthop applies to
+/// all of them at once. Groups have the `Unspecified` family.
+#[doc(alias("nhmsg"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NextHopMessage {
+    family: AddressFamily,
+    scope: RouteScope,
+    protocol: RouteProtocol,
+    flags: NextHopMessageFlags,
+    attributes: Vec<NextHopAttribute>,
+}
+
+impl NextHopMessage {
+    pub const fn new(
+        family: AddressFamily,
+        protocol: RouteProtocol,
+        flags: NextHopMessageFlags,
+        attributes: Vec<NextHopAttribute>,
+    ) -> Self {
+        Self {
+            family,
+            scope: RouteScope::Universe,
+            protocol,
+            flags,
+            attributes,
+        }
+    }
+
+    pub const fn family(&self) -> AddressFamily {
+        self.family
+    }
+
+    /// The scope of the nexthop, which is set by the kernel.
+    pub const fn scope(&self) -> &RouteScope {
+        &self.scope
+    }
+
+    pub const fn protocol(&self) -> &RouteProtocol {
+        &self.protocol
+    }
+
+    pub const fn flags(&self) -> NextHopMessageFlags {
+        self.flags
+    }
+
+    pub fn attributes(&self) -> &[NextHopAttribute] {
+        &self.attributes
+    }
+
+    /// The `Id` attribute.
+    pub fn id(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|x| match x {
+            NextHopAttribute::Id(id) => Some(*id),
+            _ => None,
+        })
+    }
+
+    pub fn serialize(&self) -> Box<[u8]> {
+        let mut buffer = Vec::with_capacity(8);
+        buffer.push(self.family.raw_value());
+        buffer.push(self.scope.raw_value());
+        buffer.push(self.protocol.raw_value());
+        buffer.push(0u8); // resvd
+        buffer.extend(self.flags.bits().to_ne_bytes());
+
+        for attr in &self.attributes {
+            attr.serialize_into(&mut buffer);
+        }
+
+        buffer.into_boxed_slice()
+    }
+
+    pub fn deserialize(bytes: &[u8]) -> Option<Self> {
+        // The header is 8 bytes. If the data we receive is shorter than that
+        // it's not going to be valid
+        if bytes.len() < 8 {
+            return None;
+        }
+
+        let mut iter = bytes.iter().cloned();
+
+        let family = iter.next()?;
+        let scope = iter.next()?;
+        let protocol = iter.next()?;
+        let _reserved = iter.next()?;
+        let flags = read_u32(iter.by_ref())?;
+
+        let family = AddressFamily::from_raw_value(family)?;
+        let scope = RouteScope::from_raw_value(scope);
+        let protocol = RouteProtocol::from_raw_value(protocol);
+        let flags = NextHopMessageFlags::from_bits_retain(flags);
+
+        let mut attributes: Vec<_> = RawAttributeIter::new(iter)
+            .map(NextHopAttribute::from_raw)
+            .try_collect()?;
+
+        resolve_addresses(family, &mut attributes);
+
+        Some(Self {
+            family,
+            scope,
+            protocol,
+            flags,
+            attributes,
+        })
+    }
+}
+
+/// A message that is of the `RTM_GETNEXTHOP` type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetNextHop(pub NextHopMessage);
+
+impl Payload for GetNextHop {
+    fn message_type() -> Type {
+        Type::Route(RouteType::GetNextHop)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(NextHopMessage::deserialize(bytes)?))
+    }
+}
+
+/// A message that is of the `RTM_NEWNEXTHOP` type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewNextHop(pub NextHopMessage);
+
+impl Payload for NewNextHop {
+    fn message_type() -> Type {
+        Type::Route(RouteType::NewNextHop)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(NextHopMessage::deserialize(bytes)?))
+    }
+}
+
+/// A message that is of the `RTM_DELNEXTHOP` type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeleteNextHop(pub NextHopMessage);
+
+impl Payload for DeleteNextHop {
+    fn message_type() -> Type {
+        Type::Route(RouteType::DeleteNextHop)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(NextHopMessage::deserialize(bytes)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rtnetlink::{NHA_GATEWAY, RTNH_F_ONLINK};
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn nhmsg_layout() {
+        // struct nhmsg of an on-link IPv4 nexthop installed by `ip nexthop`,
+        // followed by NHA_GATEWAY
+        let mut bytes = vec![libc::AF_INET as u8, libc::RT_SCOPE_LINK, libc::RTPROT_BOOT, 0];
+        bytes.extend((RTNH_F_ONLINK as u32).to_ne_bytes());
+        bytes.extend(8u16.to_ne_bytes());
+        bytes.extend(NHA_GATEWAY.to_ne_bytes());
+        bytes.extend([192, 0, 2, 1]);
+
+        let message = NextHopMessage::deserialize(&bytes).unwrap();
+        assert_eq!(message.family(), AddressFamily::Inet);
+        assert_eq!(message.scope(), &RouteScope::Link);
+        assert_eq!(message.protocol(), &RouteProtocol::Boot);
+        assert_eq!(message.flags(), NextHopMessageFlags::OnLink);
+        assert_eq!(
+            message.attributes(),
+            [NextHopAttribute::Gateway(Ipv4Addr::new(192, 0, 2, 1).into())]
+        );
+
+        assert_eq!(NextHopMessage::deserialize(&bytes[..6]), None);
+    }
+
+    #[test]
+    fn unknown_values() {
+        // struct nhmsg with a scope of /etc/iproute2/rt_scopes, a protocol of
+        // /etc/iproute2/rt_protos and a flag beyond the 8 bits of rtnexthop
+        let mut bytes = vec![libc::AF_INET as u8, 100, 77, 0];
+        bytes.extend((0x0001_0000 | RTNH_F_ONLINK as u32).to_ne_bytes());
+
+        let message = NextHopMessage::deserialize(&bytes).unwrap();
+        assert_eq!(message.scope(), &RouteScope::Other(100));
+        assert_eq!(message.protocol(), &RouteProtocol::Other(77));
+        assert_eq!(message.flags().bits(), 0x0001_0004);
+        assert!(message.flags().contains(NextHopMessageFlags::OnLink));
+        assert_eq!(&message.serialize()[..], &bytes[..]);
+    }
+}
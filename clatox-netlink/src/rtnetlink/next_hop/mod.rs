@@ -0,0 +1,24 @@
+mod attribute;
+mod dump;
+mod flag;
+mod group;
+mod message;
+mod request;
+
+#[doc(inline)]
+pub use attribute::*;
+
+#[doc(inline)]
+pub use dump::*;
+
+#[doc(inline)]
+pub use flag::*;
+
+#[doc(inline)]
+pub use group::*;
+
+#[doc(inline)]
+pub use message::*;
+
+#[doc(inline)]
+pub use request::*;
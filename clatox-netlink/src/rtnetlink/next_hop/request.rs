@@ -0,0 +1,183 @@
+use std::io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult};
+use std::net::IpAddr;
+
+use crate::netlink::{Flags, Socket};
+use crate::rtnetlink::{AddressFamily, IpPrefix, RouteProtocol};
+
+use super::{
+    DeleteNextHop, NewNextHop, NextHopAttribute, NextHopGroupEntry, NextHopGroupType,
+    NextHopMessage, NextHopMessageFlags, NextHopResilientGroupAttribute,
+};
+
+/// What a nexthop object forwards the packets to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NextHopKind {
+    /// A single next hop through the interface of the given index, with an
+    /// optional gateway.
+    Single {
+        index: u32,
+        gateway: Option<IpAddr>,
+    },
+
+    /// A gateway whose interface is resolved by the kernel. This needs the
+    /// `Fdb` flag, i.e. it is only for the FDB entries of VXLAN devices.
+    Gateway(IpAddr),
+
+    /// The packets are dropped.
+    Blackhole(AddressFamily),
+
+    /// A group of other nexthops.
+    Group {
+        entries: Vec<NextHopGroupEntry>,
+        group_type: NextHopGroupType,
+    },
+}
+
+/// A nexthop object to be added, replaced or deleted, like `ip nexthop` does.
+///
+/// Routes refer to it with `RouteAttribute::NextHopId`, e.g. added through
+/// `RouteRequest::with_next_hop_id`. Deleting a nexthop removes it from the
+/// groups containing it, and deletes the routes referring to it.
+///
+/// By default, the nexthop is installed by `RouteProtocol::Boot`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NextHopRequest {
+    id: u32,
+    kind: NextHopKind,
+    flags: NextHopMessageFlags,
+    protocol: RouteProtocol,
+    fdb: bool,
+    resilient: Vec<NextHopResilientGroupAttribute>,
+}
+
+impl NextHopRequest {
+    /// A nexthop of the given id, which has to be non-zero.
+    pub const fn new(id: u32, kind: NextHopKind) -> Self {
+        Self {
+            id,
+            kind,
+            flags: NextHopMessageFlags::empty(),
+            protocol: RouteProtocol::Boot,
+            fdb: false,
+            resilient: Vec::new(),
+        }
+    }
+
+    /// Sets the flags of a single next hop, e.g.
+    /// `NextHopMessageFlags::OnLink`.
+    pub fn with_flags(mut self, flags: NextHopMessageFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    pub fn with_protocol(mut self, protocol: RouteProtocol) -> Self {
+        self.protocol = protocol;
+        self
+    }
+
+    /// Makes the nexthop one for the FDB entries of VXLAN devices.
+    pub fn with_fdb(mut self, fdb: bool) -> Self {
+        self.fdb = fdb;
+        self
+    }
+
+    /// Sets the parameters of a resilient group, e.g. the number of buckets.
+    pub fn with_resilient_group(mut self, attributes: Vec<NextHopResilientGroupAttribute>) -> Self {
+        self.resilient = attributes;
+        self
+    }
+
+    pub const fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub const fn kind(&self) -> &NextHopKind {
+        &self.kind
+    }
+
+    /// Builds the message of the request.
+    pub fn to_message(&self) -> IoResult<NextHopMessage> {
+        let mut attributes = vec![NextHopAttribute::Id(self.id)];
+
+        let family = match &self.kind {
+            NextHopKind::Single { index, gateway } => {
+                attributes.push(NextHopAttribute::OutgoingInterface(*index));
+
+                match gateway {
+                    Some(gateway) => {
+                        attributes.push(NextHopAttribute::Gateway((*gateway).into()));
+                        IpPrefix::host(*gateway).family()
+                    }
+                    // ip uses AF_INET for nexthops without an address
+                    None => AddressFamily::Inet,
+                }
+            }
+            NextHopKind::Gateway(gateway) => {
+                attributes.push(NextHopAttribute::Gateway((*gateway).into()));
+                IpPrefix::host(*gateway).family()
+            }
+            NextHopKind::Blackhole(family) => {
+                attributes.push(NextHopAttribute::Blackhole);
+                *family
+            }
+            NextHopKind::Group {
+                entries,
+                group_type,
+            } => {
+                if entries.is_empty() {
+                    return Err(IoError::new(IoErrorKind::InvalidInput, "empty nexthop group"));
+                }
+
+                attributes.push(NextHopAttribute::Group(entries.clone()));
+                attributes.push(NextHopAttribute::GroupType(*group_type));
+
+                if !self.resilient.is_empty() {
+                    attributes.push(NextHopAttribute::ResilientGroup(self.resilient.clone()));
+                }
+
+                AddressFamily::Unspecified
+            }
+        };
+
+        if self.fdb {
+            attributes.push(NextHopAttribute::Fdb);
+        }
+
+        Ok(NextHopMessage::new(
+            family,
+            self.protocol.clone(),
+            self.flags,
+            attributes,
+        ))
+    }
+
+    /// Adds the nexthop. Fails with `EEXIST` if there is a nexthop of the id.
+    pub fn add(&self, socket: &mut Socket) -> IoResult<()> {
+        let flags = Flags::Create | Flags::Excl;
+        socket.send_acknowledged(flags, NewNextHop(self.to_message()?))
+    }
+
+    /// Adds the nexthop, or replaces the nexthop of the id. The routes
+    /// referring to it are kept, and use the new nexthop.
+    pub fn replace(&self, socket: &mut Socket) -> IoResult<()> {
+        let flags = Flags::Create | Flags::Replace;
+        socket.send_acknowledged(flags, NewNextHop(self.to_message()?))
+    }
+
+    /// Deletes the nexthop. Fails with `ENOENT` if there is none of the id.
+    pub fn delete(&self, socket: &mut Socket) -> IoResult<()> {
+        delete_next_hop(socket, self.id)
+    }
+}
+
+/// Deletes the nexthop of the given id, along with the routes referring to
+/// it.
+pub fn delete_next_hop(socket: &mut Socket, id: u32) -> IoResult<()> {
+    let message = NextHopMessage::new(
+        AddressFamily::Unspecified,
+        RouteProtocol::Unspecified,
+        NextHopMessageFlags::empty(),
+        vec![NextHopAttribute::Id(id)],
+    );
+    socket.send_acknowledged(Flags::empty(), DeleteNextHop(message))
+}
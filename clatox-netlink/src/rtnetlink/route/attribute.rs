@@ -1,8 +1,17 @@
 use libc::*;
 
 use crate::netlink::{Attribute, RawAttribute};
+use crate::rtnetlink::{AddressAttribute, AddressFamily, NetworkAddress};
 use crate::utils;
 
+use super::{RouteMetrics, RouteNextHop};
+
+pub const RTA_NH_ID: u16 = 30;
+
+/// Attributes of a route message. They correspond to `RTA_*` in libc.
+///
+/// The addresses are interpreted according to the address family of the
+/// message; on their own, they are decoded as `NetworkAddress::Other`.
 #[doc(alias("rtmsg", "RTA_"))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum RouteAttribute {
@@ -10,10 +19,10 @@ pub enum RouteAttribute {
     Unspecified(Vec<u8>),
 
     /// `RTA_DST`
-    Destination(Vec<u8>),
+    Destination(NetworkAddress),
 
     /// `RTA_SRC`
-    Source(Vec<u8>),
+    Source(NetworkAddress),
 
     /// `RTA_IIF`
     IncomingInterface(i32),
@@ -22,19 +31,19 @@ pub enum RouteAttribute {
     OutgoingInterface(i32),
 
     /// `RTA_GATEWAY`
-    Gateway(Vec<u8>),
+    Gateway(NetworkAddress),
 
     /// `RTA_PRIORITY`
     RoutePriority(i32),
 
     /// `RTA_PREFSRC`
-    PreferredSource(Vec<u8>),
+    PreferredSource(NetworkAddress),
 
     /// `RTA_METRICS`
-    Metrics(i32),
+    Metrics(RouteMetrics),
 
     /// `RTA_MULTIPATH`
-    Multipath(Vec<u8>),
+    Multipath(Vec<RouteNextHop>),
 
     /// `RTA_PROTOINFO`
     ProtocolInfo(Vec<u8>),
@@ -60,8 +69,10 @@ pub enum RouteAttribute {
     /// `RTA_MFC_STATS`
     MfcStats(Vec<u8>),
 
-    /// `RTA_VIA`
-    Via(Vec<u8>),
+    /// `RTA_VIA` - a gateway of another address family than the route,
+    /// e.g. an IPv6 gateway of an IPv4 route. Its family is given by the
+    /// attribute itself, so it is always decoded.
+    Via(NetworkAddress),
 
     /// `RTA_NEWDST`
     NewDestination(Vec<u8>),
@@ -78,6 +89,13 @@ pub enum RouteAttribute {
     /// `RTA_EXPIRES`
     Expires(u32),
 
+    /// `RTA_UID` - the user whose routing rules apply to a lookup.
+    Uid(u32),
+
+    /// `RTA_NH_ID` - the id of the nexthop object the route forwards to,
+    /// instead of a gateway or next hops of its own.
+    NextHopId(u32),
+
     Other(u16, Vec<u8>)
 }
 
@@ -88,12 +106,12 @@ impl RouteAttribute {
                 buffer.extend(content.iter());
                 RTA_UNSPEC
             }
-            RouteAttribute::Destination(content) => {
-                buffer.extend(content.iter());
+            RouteAttribute::Destination(address) => {
+                buffer.extend(address.to_bytes());
                 RTA_DST
             }
-            RouteAttribute::Source(content) => {
-                buffer.extend(content.iter());
+            RouteAttribute::Source(address) => {
+                buffer.extend(address.to_bytes());
                 RTA_SRC
             }
             RouteAttribute::IncomingInterface(iif) => {
@@ -104,24 +122,26 @@ impl RouteAttribute {
                 buffer.extend(oif.to_ne_bytes().into_iter());
                 RTA_OIF
             }
-            RouteAttribute::Gateway(content) => {
-                buffer.extend(content.iter());
+            RouteAttribute::Gateway(address) => {
+                buffer.extend(address.to_bytes());
                 RTA_GATEWAY
             }
             RouteAttribute::RoutePriority(priority) => {
                 buffer.extend(priority.to_ne_bytes().into_iter());
                 RTA_PRIORITY
             }
-            RouteAttribute::PreferredSource(content) => {
-                buffer.extend(content.iter());
+            RouteAttribute::PreferredSource(address) => {
+                buffer.extend(address.to_bytes());
                 RTA_PREFSRC
             }
             RouteAttribute::Metrics(metrics) => {
-                buffer.extend(metrics.to_ne_bytes().into_iter());
-                RTA_METRICS
+                metrics.serialize_into(buffer);
+                RTA_METRICS | NLA_F_NESTED as u16
             }
-            RouteAttribute::Multipath(content) => {
-                buffer.extend(content.iter());
+            RouteAttribute::Multipath(next_hops) => {
+                for next_hop in next_hops {
+                    next_hop.serialize_into(buffer);
+                }
                 RTA_MULTIPATH
             }
             RouteAttribute::ProtocolInfo(content) => {
@@ -156,8 +176,13 @@ impl RouteAttribute {
                 buffer.extend(content.iter());
                 RTA_MFC_STATS
             }
-            RouteAttribute::Via(content) => {
-                buffer.extend(content.iter());
+            RouteAttribute::Via(address) => {
+                // struct rtvia, whose family is a __kernel_sa_family_t. Other
+                // addresses hold the whole struct.
+                if let Some(family) = address.family() {
+                    buffer.extend((family.raw_value() as u16).to_ne_bytes());
+                }
+                buffer.extend(address.to_bytes());
                 RTA_VIA
             }
             RouteAttribute::NewDestination(content) => {
@@ -180,6 +205,14 @@ impl RouteAttribute {
                 buffer.extend(exp.to_ne_bytes().into_iter());
                 RTA_EXPIRES
             }
+            RouteAttribute::Uid(uid) => {
+                buffer.extend(uid.to_ne_bytes());
+                RTA_UID
+            }
+            RouteAttribute::NextHopId(id) => {
+                buffer.extend(id.to_ne_bytes());
+                RTA_NH_ID
+            }
             RouteAttribute::Other(typ, content) => {
                 buffer.extend(content.iter());
                 *typ
@@ -195,8 +228,8 @@ impl Attribute for RouteAttribute {
 
         let attr = match attr_type {
             RTA_UNSPEC => RouteAttribute::Unspecified(content),
-            RTA_DST => RouteAttribute::Destination(content),
-            RTA_SRC => RouteAttribute::Source(content),
+            RTA_DST => RouteAttribute::Destination(NetworkAddress::Other(content)),
+            RTA_SRC => RouteAttribute::Source(NetworkAddress::Other(content)),
             RTA_IIF => { 
                 let content = <[u8; 4]>::try_from(content).ok()?;
                 let iif = i32::from_ne_bytes(content);
@@ -207,19 +240,15 @@ impl Attribute for RouteAttribute {
                 let oif = i32::from_ne_bytes(content);
                 RouteAttribute::OutgoingInterface(oif)
             },
-            RTA_GATEWAY => RouteAttribute::Gateway(content),
+            RTA_GATEWAY => RouteAttribute::Gateway(NetworkAddress::Other(content)),
             RTA_PRIORITY => {
                 let content = <[u8; 4]>::try_from(content).ok()?;
                 let priority = i32::from_ne_bytes(content);
                 RouteAttribute::RoutePriority(priority)
             },
-            RTA_PREFSRC => RouteAttribute::PreferredSource(content),
-            RTA_METRICS => {
-                let content = <[u8; 4]>::try_from(content).ok()?;
-                let metrics = i32::from_ne_bytes(content);
-                RouteAttribute::Metrics(metrics)
-            },
-            RTA_MULTIPATH => RouteAttribute::Multipath(content),
+            RTA_PREFSRC => RouteAttribute::PreferredSource(NetworkAddress::Other(content)),
+            RTA_METRICS => RouteAttribute::Metrics(RouteMetrics::deserialize(&content)?),
+            RTA_MULTIPATH => RouteAttribute::Multipath(RouteNextHop::deserialize_list(&content)?),
             RTA_PROTOINFO => RouteAttribute::ProtocolInfo(content),
             RTA_FLOW => {
                 let content = <[u8; 4]>::try_from(content).ok()?;
@@ -240,7 +269,15 @@ impl Attribute for RouteAttribute {
                 RouteAttribute::Mark(mark)
             },
             RTA_MFC_STATS => RouteAttribute::MfcStats(content),
-            RTA_VIA => RouteAttribute::Via(content),
+            RTA_VIA => {
+                let family = u16::from_ne_bytes([*content.first()?, *content.get(1)?]);
+                let family = u8::try_from(family).ok().and_then(AddressFamily::from_raw_value);
+                let address = match family.map(|x| NetworkAddress::from_bytes(x, &content[2..])) {
+                    Some(NetworkAddress::Other(_)) | None => NetworkAddress::Other(content),
+                    Some(address) => address,
+                };
+                RouteAttribute::Via(address)
+            },
             RTA_NEWDST => RouteAttribute::NewDestination(content),
             RTA_PREF => { 
                 let pref = *content.iter().next()?;
@@ -257,9 +294,31 @@ impl Attribute for RouteAttribute {
                 let exp = u32::from_ne_bytes(content);
                 RouteAttribute::Expires(exp)
             },
+            RTA_UID => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                let uid = u32::from_ne_bytes(content);
+                RouteAttribute::Uid(uid)
+            },
+            RTA_NH_ID => {
+                let content = <[u8; 4]>::try_from(content).ok()?;
+                let id = u32::from_ne_bytes(content);
+                RouteAttribute::NextHopId(id)
+            },
             typ => RouteAttribute::Other(typ, content),
         };
 
         Some(attr)
     }
 }
+
+impl AddressAttribute for RouteAttribute {
+    fn address_mut(&mut self) -> Option<&mut NetworkAddress> {
+        match self {
+            Self::Destination(address)
+            | Self::Source(address)
+            | Self::Gateway(address)
+            | Self::PreferredSource(address) => Some(address),
+            _ => None,
+        }
+    }
+}
@@ -2,6 +2,10 @@ use libc::*;
 
 use bitflags::bitflags;
 
+pub const RTM_F_OFFLOAD: c_uint = 0x4000;
+pub const RTM_F_TRAP: c_uint = 0x8000;
+pub const RTM_F_OFFLOAD_FAILED: c_uint = 0x20000000;
+
 bitflags! {
     #[doc(alias("rtmsg", "RTM_F_"))]
     #[derive(Clone, Debug, PartialEq, Eq)]
@@ -10,5 +14,17 @@ bitflags! {
         const Cloned = RTM_F_CLONED;
         const Equalize = RTM_F_EQUALIZE;
         const Prefix = RTM_F_PREFIX;
+
+        /// Reports the table of the route found by a lookup in the header,
+        /// instead of `RT_TABLE_MAIN`.
+        const LookupTable = RTM_F_LOOKUP_TABLE;
+
+        /// Reports the route found by a lookup as it is in the table,
+        /// instead of the resolved destination.
+        const FibMatch = RTM_F_FIB_MATCH;
+
+        const Offload = RTM_F_OFFLOAD;
+        const Trap = RTM_F_TRAP;
+        const OffloadFailed = RTM_F_OFFLOAD_FAILED;
     }
 }
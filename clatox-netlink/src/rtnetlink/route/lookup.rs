@@ -0,0 +1,159 @@
+use std::io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult};
+use std::net::IpAddr;
+
+use crate::netlink::{Flags, Message, ReceivedMessage, Socket};
+use crate::rtnetlink::{IpPrefix, RouteScope};
+
+use super::{
+    GetRoute, NewRoute, RouteAttribute, RouteFlags, RouteMessage, RouteProtocol, RouteTable,
+    RouteType,
+};
+
+/// A lookup of the route the kernel would use for a single destination, like
+/// `ip route get` does.
+///
+/// By default, the lookup is done for a packet sent by the host. With an
+/// incoming interface, it is done for a packet forwarded from it instead,
+/// which needs a source address.
+///
+/// The resolved route is returned as a `RouteMessage` of the destination
+/// address. Its `PreferredSource` attribute gives the source address the
+/// kernel picks, and `RouteMessage::table_id()` the table the route was found
+/// in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteLookup {
+    destination: IpAddr,
+    source: Option<IpAddr>,
+    input_interface: Option<u32>,
+    output_interface: Option<u32>,
+    mark: Option<u32>,
+    uid: Option<u32>,
+    fib_match: bool,
+}
+
+impl RouteLookup {
+    pub const fn new(destination: IpAddr) -> Self {
+        Self {
+            destination,
+            source: None,
+            input_interface: None,
+            output_interface: None,
+            mark: None,
+            uid: None,
+            fib_match: false,
+        }
+    }
+
+    /// Sets the source address of the packet. It has to be of the family of
+    /// the destination.
+    pub fn with_source(mut self, source: IpAddr) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Looks the route up for a packet received on the interface of the
+    /// given index.
+    pub fn with_input_interface(mut self, index: u32) -> Self {
+        self.input_interface = Some(index);
+        self
+    }
+
+    /// Restricts the lookup to routes through the interface of the given
+    /// index.
+    pub fn with_output_interface(mut self, index: u32) -> Self {
+        self.output_interface = Some(index);
+        self
+    }
+
+    /// Sets the firewall mark of the packet, which routing rules may match.
+    pub fn with_mark(mut self, mark: u32) -> Self {
+        self.mark = Some(mark);
+        self
+    }
+
+    /// Sets the user sending the packet, which routing rules may match.
+    pub fn with_uid(mut self, uid: u32) -> Self {
+        self.uid = Some(uid);
+        self
+    }
+
+    /// Returns the matching route as it is in the table, e.g. with the
+    /// prefix length of the table entry, instead of the route resolved for
+    /// the destination address (`RTM_F_FIB_MATCH`).
+    pub fn with_fib_match(mut self, fib_match: bool) -> Self {
+        self.fib_match = fib_match;
+        self
+    }
+
+    /// Builds the message of the request.
+    ///
+    /// Fails with `InvalidInput` if the source is not of the family of the
+    /// destination.
+    pub fn to_message(&self) -> IoResult<RouteMessage> {
+        let destination = IpPrefix::host(self.destination);
+        let mut attributes = vec![RouteAttribute::Destination(self.destination.into())];
+        let mut src_len = 0;
+
+        if let Some(source) = self.source {
+            let source = IpPrefix::host(source);
+            if source.family() != destination.family() {
+                return Err(IoError::new(
+                    IoErrorKind::InvalidInput,
+                    "address family differs from the destination",
+                ));
+            }
+
+            attributes.push(RouteAttribute::Source(source.address().into()));
+            src_len = source.prefix_len();
+        }
+
+        if let Some(index) = self.input_interface {
+            attributes.push(RouteAttribute::IncomingInterface(index as i32));
+        }
+
+        if let Some(index) = self.output_interface {
+            attributes.push(RouteAttribute::OutgoingInterface(index as i32));
+        }
+
+        if let Some(mark) = self.mark {
+            attributes.push(RouteAttribute::Mark(mark as i32));
+        }
+
+        if let Some(uid) = self.uid {
+            attributes.push(RouteAttribute::Uid(uid));
+        }
+
+        let mut flags = RouteFlags::LookupTable;
+        if self.fib_match {
+            flags |= RouteFlags::FibMatch;
+        }
+
+        Ok(RouteMessage::new(
+            destination.family(),
+            destination.prefix_len(),
+            src_len,
+            0,
+            RouteTable::Unspecified,
+            RouteProtocol::Unspecified,
+            RouteScope::Universe,
+            RouteType::Unspecified,
+            flags,
+            attributes,
+        ))
+    }
+
+    /// Looks the route up. Fails with the error the kernel reports, e.g.
+    /// `ENETUNREACH` if there is no route to the destination.
+    pub fn lookup(&self, socket: &mut Socket) -> IoResult<RouteMessage> {
+        let message = Message::new(Flags::Request, GetRoute(self.to_message()?));
+        socket.send_message(&message)?;
+
+        match socket.receive_message::<NewRoute>()? {
+            ReceivedMessage::Message(message) => Ok(message.into_payload().0),
+            ReceivedMessage::Error(error) => {
+                Err(IoError::from_raw_os_error(-error.payload().error_code()))
+            }
+            ReceivedMessage::Multipart(_) => Err(IoErrorKind::InvalidData)?,
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use crate::netlink::{Attribute, Payload, RawAttributeIter, RouteType as RouteMessageType, Type};
-use crate::rtnetlink::AddressFamily;
+use crate::rtnetlink::{resolve_addresses, AddressFamily, NetworkAddress};
 use crate::utils::{align_attribute_len, read_u32};
 
-use super::{RouteAttribute, RouteFlags, RouteProtocol, RouteScope, RouteTable, RouteType};
+use super::{RouteAttribute, RouteFlags, RouteMetrics, RouteNextHop, RouteProtocol, RouteScope, RouteTable, RouteType};
 
 #[doc(alias("rtmsg"))]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -86,6 +86,79 @@ impl RouteMessage {
         &self.attributes
     }
 
+    /// The id of the table of the route, given by `RTA_TABLE` if present,
+    /// since the header can only hold ids up to 255.
+    pub fn table_id(&self) -> u32 {
+        self.attributes
+            .iter()
+            .find_map(|x| match x {
+                RouteAttribute::Table(table) => Some(*table as u32),
+                _ => None,
+            })
+            .unwrap_or(self.table.raw_value() as u32)
+    }
+
+    /// The `Destination` attribute, which is absent from default routes.
+    pub fn destination(&self) -> Option<&NetworkAddress> {
+        self.attributes.iter().find_map(|x| match x {
+            RouteAttribute::Destination(address) => Some(address),
+            _ => None,
+        })
+    }
+
+    /// The `Gateway` attribute.
+    pub fn gateway(&self) -> Option<&NetworkAddress> {
+        self.attributes.iter().find_map(|x| match x {
+            RouteAttribute::Gateway(address) => Some(address),
+            _ => None,
+        })
+    }
+
+    /// The `PreferredSource` attribute.
+    pub fn preferred_source(&self) -> Option<&NetworkAddress> {
+        self.attributes.iter().find_map(|x| match x {
+            RouteAttribute::PreferredSource(address) => Some(address),
+            _ => None,
+        })
+    }
+
+    /// The index of the interface given by the `OutgoingInterface`
+    /// attribute.
+    pub fn output_interface(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|x| match x {
+            RouteAttribute::OutgoingInterface(index) => Some(*index as u32),
+            _ => None,
+        })
+    }
+
+    /// The next hops of a multipath route, given by the `Multipath`
+    /// attribute.
+    pub fn next_hops(&self) -> &[RouteNextHop] {
+        self.attributes
+            .iter()
+            .find_map(|x| match x {
+                RouteAttribute::Multipath(next_hops) => Some(next_hops.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// The `Metrics` attribute, e.g. holding the MTU of the route.
+    pub fn metrics(&self) -> Option<&RouteMetrics> {
+        self.attributes.iter().find_map(|x| match x {
+            RouteAttribute::Metrics(metrics) => Some(metrics),
+            _ => None,
+        })
+    }
+
+    /// The metric given by the `RoutePriority` attribute.
+    pub fn priority(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|x| match x {
+            RouteAttribute::RoutePriority(priority) => Some(*priority as u32),
+            _ => None,
+        })
+    }
+
     pub fn serialize(&self) -> Box<[u8]> {
         let mut buffer = Vec::with_capacity(12);
         buffer.push(self.family.raw_value());
@@ -130,21 +203,33 @@ impl RouteMessage {
         let flags = read_u32(iter.by_ref())?;
 
         let family = AddressFamily::from_raw_value(family)?;
-        let table = RouteTable::from_raw_value(table)?;
-        let protocol = RouteProtocol::from_raw_value(protocol)?;
-        let scope = RouteScope::from_raw_value(scope)?;
+        let table = RouteTable::from_raw_value(table);
+        let protocol = RouteProtocol::from_raw_value(protocol);
+        let scope = RouteScope::from_raw_value(scope);
         let route_type = RouteType::from_raw_value(route_type)?;
-        let flags = RouteFlags::from_bits(flags)?;
+        // IPv4 lookups report the flags of the resolved route, e.g. RTCF_LOCAL,
+        // in the upper bits, so the unknown ones are kept
+        let flags = RouteFlags::from_bits_retain(flags);
 
         let aligned_len = align_attribute_len(12);
         for _ in 0..(aligned_len - 12) {
             iter.next()?;
         }
 
-        let attributes = RawAttributeIter::new(iter)
+        let mut attributes: Vec<_> = RawAttributeIter::new(iter)
             .map(RouteAttribute::from_raw)
             .try_collect()?;
 
+        // The next hops of a multipath route carry addresses of their own
+        resolve_addresses(family, &mut attributes);
+        for attr in attributes.iter_mut() {
+            if let RouteAttribute::Multipath(next_hops) = attr {
+                for next_hop in next_hops.iter_mut() {
+                    resolve_addresses(family, &mut next_hop.attributes);
+                }
+            }
+        }
+
         Some(RouteMessage {
             family,
             dst_len,
@@ -213,3 +298,36 @@ impl Payload for DeleteRoute {
         Some(Self(RouteMessage::deserialize(bytes)?))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv6Addr;
+
+    #[test]
+    fn next_hop_addresses_follow_the_family() {
+        let gateway: Ipv6Addr = "fe80::1".parse().unwrap();
+        let next_hop = RouteNextHop::new(
+            2,
+            vec![RouteAttribute::Gateway(NetworkAddress::Other(gateway.octets().to_vec()))],
+        );
+        let message = RouteMessage::new(
+            AddressFamily::Inet6,
+            0,
+            0,
+            0,
+            RouteTable::Main,
+            RouteProtocol::Boot,
+            RouteScope::Universe,
+            RouteType::Unicast,
+            RouteFlags::empty(),
+            vec![RouteAttribute::Multipath(vec![next_hop])],
+        );
+
+        let decoded = RouteMessage::deserialize(&message.serialize()).unwrap();
+        assert_eq!(
+            decoded.next_hops()[0].attributes,
+            [RouteAttribute::Gateway(NetworkAddress::Ipv6(gateway))]
+        );
+    }
+}
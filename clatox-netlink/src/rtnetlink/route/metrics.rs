@@ -0,0 +1,228 @@
+use crate::netlink::RawAttributeIter;
+use crate::utils;
+
+pub const RTAX_UNSPEC: u16 = 0;
+pub const RTAX_LOCK: u16 = 1;
+pub const RTAX_MTU: u16 = 2;
+pub const RTAX_WINDOW: u16 = 3;
+pub const RTAX_RTT: u16 = 4;
+pub const RTAX_RTTVAR: u16 = 5;
+pub const RTAX_SSTHRESH: u16 = 6;
+pub const RTAX_CWND: u16 = 7;
+pub const RTAX_ADVMSS: u16 = 8;
+pub const RTAX_REORDERING: u16 = 9;
+pub const RTAX_HOPLIMIT: u16 = 10;
+pub const RTAX_INITCWND: u16 = 11;
+pub const RTAX_FEATURES: u16 = 12;
+pub const RTAX_RTO_MIN: u16 = 13;
+pub const RTAX_INITRWND: u16 = 14;
+pub const RTAX_QUICKACK: u16 = 15;
+pub const RTAX_CC_ALGO: u16 = 16;
+pub const RTAX_FASTOPEN_NO_COOKIE: u16 = 17;
+
+pub const RTAX_FEATURE_ECN: u32 = 1 << 0;
+pub const RTAX_FEATURE_SACK: u32 = 1 << 1;
+pub const RTAX_FEATURE_TIMESTAMP: u32 = 1 << 2;
+pub const RTAX_FEATURE_ALLFRAG: u32 = 1 << 3;
+pub const RTAX_FEATURE_TCP_USEC_TS: u32 = 1 << 4;
+
+/// The metrics of a route, i.e. the attributes nested in `RTA_METRICS`. They
+/// correspond to `RTAX_*` in <linux/rtnetlink.h>.
+///
+/// Metrics which are `None` are not set on the route, so the defaults of the
+/// interface or of the TCP stack apply. The values are those stored by the
+/// kernel: `rtt` is in units of 1/8 ms, `rtt_variance` in units of 1/4 ms, and
+/// `rto_min` in ms. Metrics unknown to this crate are kept in `other`.
+#[doc(alias("RTA_METRICS", "RTAX_"))]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RouteMetrics {
+    /// `RTAX_LOCK` - the metrics which TCP must not change, as a bitmask of
+    /// `1 << RTAX_*`.
+    pub lock: Option<u32>,
+
+    /// `RTAX_MTU`
+    pub mtu: Option<u32>,
+
+    /// `RTAX_WINDOW`
+    pub window: Option<u32>,
+
+    /// `RTAX_RTT`
+    pub rtt: Option<u32>,
+
+    /// `RTAX_RTTVAR`
+    pub rtt_variance: Option<u32>,
+
+    /// `RTAX_SSTHRESH`
+    pub slow_start_threshold: Option<u32>,
+
+    /// `RTAX_CWND`
+    pub congestion_window: Option<u32>,
+
+    /// `RTAX_ADVMSS` - the MSS advertised to peers.
+    pub advertised_mss: Option<u32>,
+
+    /// `RTAX_REORDERING`
+    pub reordering: Option<u32>,
+
+    /// `RTAX_HOPLIMIT`
+    pub hop_limit: Option<u32>,
+
+    /// `RTAX_INITCWND`
+    pub initial_congestion_window: Option<u32>,
+
+    /// `RTAX_FEATURES` - a bitmask of `RTAX_FEATURE_*`.
+    pub features: Option<u32>,
+
+    /// `RTAX_RTO_MIN`
+    pub rto_min: Option<u32>,
+
+    /// `RTAX_INITRWND`
+    pub initial_receive_window: Option<u32>,
+
+    /// `RTAX_QUICKACK`
+    pub quick_ack: Option<u32>,
+
+    /// `RTAX_CC_ALGO` - the name of the congestion control algorithm, e.g.
+    /// `bbr`.
+    pub congestion_control: Option<String>,
+
+    /// `RTAX_FASTOPEN_NO_COOKIE`
+    pub fastopen_no_cookie: Option<u32>,
+
+    pub other: Vec<(u16, Vec<u8>)>,
+}
+
+impl RouteMetrics {
+    fn u32_metrics(&self) -> [(u16, Option<u32>); 16] {
+        [
+            (RTAX_LOCK, self.lock),
+            (RTAX_MTU, self.mtu),
+            (RTAX_WINDOW, self.window),
+            (RTAX_RTT, self.rtt),
+            (RTAX_RTTVAR, self.rtt_variance),
+            (RTAX_SSTHRESH, self.slow_start_threshold),
+            (RTAX_CWND, self.congestion_window),
+            (RTAX_ADVMSS, self.advertised_mss),
+            (RTAX_REORDERING, self.reordering),
+            (RTAX_HOPLIMIT, self.hop_limit),
+            (RTAX_INITCWND, self.initial_congestion_window),
+            (RTAX_FEATURES, self.features),
+            (RTAX_RTO_MIN, self.rto_min),
+            (RTAX_INITRWND, self.initial_receive_window),
+            (RTAX_QUICKACK, self.quick_ack),
+            (RTAX_FASTOPEN_NO_COOKIE, self.fastopen_no_cookie),
+        ]
+    }
+
+    /// Serializes the metrics as the payload of `RTA_METRICS`.
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        for (metric, value) in self.u32_metrics() {
+            if let Some(value) = value {
+                utils::serialize_attribute_into(buffer, |buffer| {
+                    buffer.extend(value.to_ne_bytes());
+                    metric
+                });
+            }
+        }
+
+        if let Some(name) = &self.congestion_control {
+            utils::serialize_attribute_into(buffer, |buffer| {
+                buffer.extend(name.bytes());
+                buffer.push(0u8); // zero-terminated string
+                RTAX_CC_ALGO
+            });
+        }
+
+        for (metric, content) in &self.other {
+            utils::serialize_attribute_into(buffer, |buffer| {
+                buffer.extend(content.iter());
+                *metric
+            });
+        }
+    }
+
+    /// Deserializes the payload of `RTA_METRICS`.
+    pub fn deserialize(bytes: &[u8]) -> Option<Self> {
+        let mut metrics = Self::default();
+
+        for raw in RawAttributeIter::new(bytes.iter().cloned()) {
+            let metric = raw.attr_type();
+            let content = raw.into_payload();
+
+            if metric == RTAX_CC_ALGO {
+                let name = String::from_utf8(content).ok()?;
+                metrics.congestion_control = Some(name.trim_end_matches('\0').to_owned());
+                continue;
+            }
+
+            let field = match metric {
+                RTAX_LOCK => &mut metrics.lock,
+                RTAX_MTU => &mut metrics.mtu,
+                RTAX_WINDOW => &mut metrics.window,
+                RTAX_RTT => &mut metrics.rtt,
+                RTAX_RTTVAR => &mut metrics.rtt_variance,
+                RTAX_SSTHRESH => &mut metrics.slow_start_threshold,
+                RTAX_CWND => &mut metrics.congestion_window,
+                RTAX_ADVMSS => &mut metrics.advertised_mss,
+                RTAX_REORDERING => &mut metrics.reordering,
+                RTAX_HOPLIMIT => &mut metrics.hop_limit,
+                RTAX_INITCWND => &mut metrics.initial_congestion_window,
+                RTAX_FEATURES => &mut metrics.features,
+                RTAX_RTO_MIN => &mut metrics.rto_min,
+                RTAX_INITRWND => &mut metrics.initial_receive_window,
+                RTAX_QUICKACK => &mut metrics.quick_ack,
+                RTAX_FASTOPEN_NO_COOKIE => &mut metrics.fastopen_no_cookie,
+                _ => {
+                    metrics.other.push((metric, content));
+                    continue;
+                }
+            };
+
+            let content = <[u8; 4]>::try_from(content).ok()?;
+            *field = Some(u32::from_ne_bytes(content));
+        }
+
+        Some(metrics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metric(buffer: &mut Vec<u8>, metric: u16, content: &[u8]) {
+        utils::serialize_attribute_into(buffer, |buffer| {
+            buffer.extend(content);
+            metric
+        });
+    }
+
+    #[test]
+    fn metrics_of_a_dumped_route() {
+        // The payload of RTA_METRICS as dumped for e.g.
+        // `ip route add ... mtu lock 1400 congctl bbr`
+        let mut bytes = Vec::new();
+        metric(&mut bytes, RTAX_LOCK, &(1u32 << RTAX_MTU).to_ne_bytes());
+        metric(&mut bytes, RTAX_MTU, &1400u32.to_ne_bytes());
+        metric(&mut bytes, RTAX_CC_ALGO, b"bbr\0");
+        metric(&mut bytes, 42, &[1, 2]);
+
+        let metrics = RouteMetrics::deserialize(&bytes).unwrap();
+        assert_eq!(metrics.lock, Some(1 << RTAX_MTU));
+        assert_eq!(metrics.mtu, Some(1400));
+        assert_eq!(metrics.congestion_control.as_deref(), Some("bbr"));
+        assert_eq!(metrics.other, [(42, vec![1, 2])]);
+        assert_eq!(metrics.hop_limit, None);
+
+        let mut buffer = Vec::new();
+        metrics.serialize_into(&mut buffer);
+        assert_eq!(buffer, bytes);
+    }
+
+    #[test]
+    fn malformed_metric() {
+        let mut bytes = Vec::new();
+        metric(&mut bytes, RTAX_HOPLIMIT, &[64]);
+        assert_eq!(RouteMetrics::deserialize(&bytes), None);
+    }
+}
@@ -1,7 +1,11 @@
 mod attribute;
 mod flag;
+mod lookup;
 mod message;
+mod metrics;
+mod next_hop;
 mod protocol;
+mod request;
 mod scope;
 mod table;
 mod types;
@@ -12,12 +16,24 @@ pub use attribute::*;
 #[doc(inline)]
 pub use flag::*;
 
+#[doc(inline)]
+pub use lookup::*;
+
 #[doc(inline)]
 pub use message::*;
 
+#[doc(inline)]
+pub use metrics::*;
+
+#[doc(inline)]
+pub use next_hop::*;
+
 #[doc(inline)]
 pub use protocol::*;
 
+#[doc(inline)]
+pub use request::*;
+
 #[doc(inline)]
 pub use scope::*;
 
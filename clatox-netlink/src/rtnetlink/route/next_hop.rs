@@ -0,0 +1,183 @@
+use bitflags::bitflags;
+
+use crate::netlink::{Attribute, RawAttributeIter};
+use crate::utils::{align_attribute_len, read_u16, read_u32};
+
+use super::RouteAttribute;
+
+pub const RTNH_F_DEAD: u8 = 1;
+pub const RTNH_F_PERVASIVE: u8 = 2;
+pub const RTNH_F_ONLINK: u8 = 4;
+pub const RTNH_F_OFFLOAD: u8 = 8;
+pub const RTNH_F_LINKDOWN: u8 = 16;
+pub const RTNH_F_UNRESOLVED: u8 = 32;
+pub const RTNH_F_TRAP: u8 = 64;
+
+bitflags! {
+    #[doc(alias("rtnexthop", "RTNH_F_"))]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct NextHopFlags: u8 {
+        /// The next hop is unusable, e.g. because its interface is down.
+        const Dead = RTNH_F_DEAD;
+        const Pervasive = RTNH_F_PERVASIVE;
+
+        /// The gateway is reachable through the interface even though no
+        /// prefix of the interface contains it.
+        const OnLink = RTNH_F_ONLINK;
+        const Offload = RTNH_F_OFFLOAD;
+        const LinkDown = RTNH_F_LINKDOWN;
+        const Unresolved = RTNH_F_UNRESOLVED;
+        const Trap = RTNH_F_TRAP;
+    }
+}
+
+/// A next hop of a multipath route, i.e. an entry of `RTA_MULTIPATH`. It
+/// corresponds to the `rtnexthop` struct in <linux/rtnetlink.h>, followed by
+/// its attributes, e.g. `Gateway`, `Via` or `Encapsulation`.
+///
+/// The traffic is spread over the next hops in proportion to their weights.
+#[doc(alias("rtnexthop", "RTA_MULTIPATH"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteNextHop {
+    pub flags: NextHopFlags,
+
+    /// The weight of the next hop minus one (`rtnh_hops`).
+    pub hops: u8,
+
+    /// The index of the outgoing interface.
+    pub index: u32,
+
+    pub attributes: Vec<RouteAttribute>,
+}
+
+impl RouteNextHop {
+    /// A next hop of weight 1 through the interface of the given index.
+    pub const fn new(index: u32, attributes: Vec<RouteAttribute>) -> Self {
+        Self {
+            flags: NextHopFlags::empty(),
+            hops: 0,
+            index,
+            attributes,
+        }
+    }
+
+    /// Sets the weight of the next hop, which ranges from 1 to 256.
+    pub fn with_weight(mut self, weight: u16) -> Self {
+        self.hops = weight.clamp(1, 256).saturating_sub(1) as u8;
+        self
+    }
+
+    pub fn with_flags(mut self, flags: NextHopFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    pub const fn weight(&self) -> u16 {
+        self.hops as u16 + 1
+    }
+
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        let original_len = buffer.len();
+
+        // The length is filled in once the attributes are written
+        buffer.extend(0u16.to_ne_bytes());
+        buffer.push(self.flags.bits());
+        buffer.push(self.hops);
+        buffer.extend(self.index.to_ne_bytes());
+
+        for attr in &self.attributes {
+            attr.serialize_into(buffer);
+        }
+
+        let length = (buffer.len() - original_len) as u16;
+        buffer[original_len..original_len + 2].copy_from_slice(&length.to_ne_bytes());
+
+        for _ in 0..(align_attribute_len(length as i32) - length as i32) {
+            buffer.push(0u8);
+        }
+    }
+
+    /// Deserializes the payload of `RTA_MULTIPATH`, i.e. a list of next hops.
+    ///
+    /// Like the other route attributes, the addresses are decoded as
+    /// `NetworkAddress::Other` until the address family is known.
+    pub fn deserialize_list(mut bytes: &[u8]) -> Option<Vec<Self>> {
+        let mut next_hops = Vec::new();
+
+        while !bytes.is_empty() {
+            let mut iter = bytes.iter().cloned();
+
+            let length = read_u16(iter.by_ref())? as usize;
+            let flags = iter.next()?;
+            let hops = iter.next()?;
+            let index = read_u32(iter.by_ref())?;
+
+            if length < 8 || length > bytes.len() {
+                return None;
+            }
+
+            let attributes = RawAttributeIter::new(bytes[8..length].iter().cloned())
+                .map(RouteAttribute::from_raw)
+                .try_collect()?;
+
+            next_hops.push(Self {
+                flags: NextHopFlags::from_bits_retain(flags),
+                hops,
+                index,
+                attributes,
+            });
+
+            let length = (align_attribute_len(length as i32) as usize).min(bytes.len());
+            bytes = &bytes[length..];
+        }
+
+        Some(next_hops)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rtnetlink::NetworkAddress;
+
+    #[test]
+    fn rtnexthop_layout() {
+        // Two struct rtnexthop as dumped for
+        // `nexthop via 192.0.2.1 dev 2 weight 3 nexthop dev 3 onlink`
+        let mut bytes = Vec::new();
+        bytes.extend(16u16.to_ne_bytes());
+        bytes.extend([0, 2]);
+        bytes.extend(2u32.to_ne_bytes());
+        bytes.extend(8u16.to_ne_bytes());
+        bytes.extend(libc::RTA_GATEWAY.to_ne_bytes());
+        bytes.extend([192, 0, 2, 1]);
+        bytes.extend(8u16.to_ne_bytes());
+        bytes.extend([RTNH_F_ONLINK, 0]);
+        bytes.extend(3u32.to_ne_bytes());
+
+        let next_hops = RouteNextHop::deserialize_list(&bytes).unwrap();
+        assert_eq!(next_hops.len(), 2);
+        assert_eq!(next_hops[0].weight(), 3);
+        assert_eq!(next_hops[0].index, 2);
+        assert_eq!(
+            next_hops[0].attributes,
+            [RouteAttribute::Gateway(NetworkAddress::Other(vec![192, 0, 2, 1]))]
+        );
+        assert_eq!(next_hops[1].flags, NextHopFlags::OnLink);
+        assert_eq!(next_hops[1].index, 3);
+
+        let mut buffer = Vec::new();
+        next_hops.iter().for_each(|x| x.serialize_into(&mut buffer));
+        assert_eq!(buffer, bytes);
+
+        // The length of an entry exceeds the payload
+        assert_eq!(RouteNextHop::deserialize_list(&bytes[..12]), None);
+    }
+
+    #[test]
+    fn weight() {
+        assert_eq!(RouteNextHop::new(1, vec![]).with_weight(256).hops, 255);
+        assert_eq!(RouteNextHop::new(1, vec![]).with_weight(1000).weight(), 256);
+        assert_eq!(RouteNextHop::new(1, vec![]).with_weight(0).weight(), 1);
+    }
+}
@@ -91,13 +91,17 @@ pub enum RouteProtocol {
     
     /// `RTPROT_EIGRP`
     EIgrp,
+
+    /// A protocol without a name of its own, e.g. one of those named in
+    /// `/etc/iproute2/rt_protos`.
+    Other(u8),
 }
 
 impl RouteProtocol {
-    pub fn from_raw_value(value: u8) -> Option<Self> {
+    pub fn from_raw_value(value: u8) -> Self {
         use RouteProtocol::*;
 
-        Some(match value {
+        match value {
             RTPROT_UNSPEC => Unspecified,
             RTPROT_REDIRECT => Redirect,
             RTPROT_KERNEL => Kernel,
@@ -121,8 +125,8 @@ impl RouteProtocol {
             RTPROT_OSPF => Ospf,
             RTPROT_RIP => Rip,
             RTPROT_EIGRP => EIgrp,
-            _ => None?
-        })
+            x => Other(x),
+        }
     }
 
     pub fn raw_value(&self) -> u8 {
@@ -152,6 +156,7 @@ impl RouteProtocol {
             Ospf => RTPROT_OSPF,
             Rip => RTPROT_RIP,
             EIgrp => RTPROT_EIGRP,
+            Other(x) => *x,
         }
     }
 }
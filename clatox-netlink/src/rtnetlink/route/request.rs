@@ -0,0 +1,350 @@
+use std::io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult};
+use std::net::IpAddr;
+
+use crate::netlink::{Flags, Socket};
+use crate::rtnetlink::IpPrefix;
+
+use super::{
+    DeleteRoute, NewRoute, RouteAttribute, RouteFlags, RouteMessage, RouteMetrics, RouteNextHop,
+    RouteProtocol, RouteScope, RouteTable, RouteType,
+};
+
+/// A route to be added to, replaced in or deleted from a routing table.
+///
+/// The defaults follow `ip route`: routes are added to the main table as
+/// unicast routes installed by `RouteProtocol::Boot`. Unless set, the scope
+/// is derived from the type, so that unicast routes without a gateway are
+/// on-link routes of `RouteScope::Link`, and local routes are of
+/// `RouteScope::Host`.
+///
+/// When deleting, only the properties that have been set are compared, so
+/// that e.g. a route is found without knowing its protocol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteRequest {
+    destination: IpPrefix,
+    gateway: Option<IpAddr>,
+    output_interface: Option<u32>,
+    preferred_source: Option<IpAddr>,
+    priority: Option<u32>,
+    table: Option<u32>,
+    protocol: Option<RouteProtocol>,
+    scope: Option<RouteScope>,
+    route_type: Option<RouteType>,
+    metrics: Option<RouteMetrics>,
+    next_hops: Vec<RouteNextHop>,
+    next_hop_id: Option<u32>,
+}
+
+impl RouteRequest {
+    pub const fn new(destination: IpPrefix) -> Self {
+        Self {
+            destination,
+            gateway: None,
+            output_interface: None,
+            preferred_source: None,
+            priority: None,
+            table: None,
+            protocol: None,
+            scope: None,
+            route_type: None,
+            metrics: None,
+            next_hops: Vec::new(),
+            next_hop_id: None,
+        }
+    }
+
+    /// Sets the next hop of the route. A gateway of another family than the
+    /// destination, e.g. an IPv6 gateway of an IPv4 route, is sent as `Via`.
+    pub fn with_gateway(mut self, gateway: IpAddr) -> Self {
+        self.gateway = Some(gateway);
+        self
+    }
+
+    /// Sets the index of the interface the packets are sent through.
+    pub fn with_output_interface(mut self, index: u32) -> Self {
+        self.output_interface = Some(index);
+        self
+    }
+
+    /// Sets the source address preferred for the packets sent along the
+    /// route.
+    pub fn with_preferred_source(mut self, address: IpAddr) -> Self {
+        self.preferred_source = Some(address);
+        self
+    }
+
+    /// Sets the metric of the route. Among routes of the same prefix, the one
+    /// with the lowest metric wins.
+    pub fn with_priority(mut self, priority: u32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Sets the id of the routing table, e.g. `RT_TABLE_MAIN` or a user
+    /// defined one.
+    pub fn with_table(mut self, table: u32) -> Self {
+        self.table = Some(table);
+        self
+    }
+
+    pub fn with_protocol(mut self, protocol: RouteProtocol) -> Self {
+        self.protocol = Some(protocol);
+        self
+    }
+
+    pub fn with_scope(mut self, scope: RouteScope) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Sets the type of the route, e.g. `RouteType::Blackhole` or
+    /// `RouteType::Unreachable`.
+    pub fn with_route_type(mut self, route_type: RouteType) -> Self {
+        self.route_type = Some(route_type);
+        self
+    }
+
+    /// Sets the metrics of the route, e.g. a lower MTU than the one of the
+    /// interface.
+    pub fn with_metrics(mut self, metrics: RouteMetrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Makes the route a multipath route over the given next hops, which
+    /// take the place of the gateway and the outgoing interface.
+    pub fn with_next_hops(mut self, next_hops: Vec<RouteNextHop>) -> Self {
+        self.next_hops = next_hops;
+        self
+    }
+
+    /// Makes the route forward to the nexthop object of the given id, which
+    /// takes the place of the gateway and the outgoing interface.
+    pub fn with_next_hop_id(mut self, id: u32) -> Self {
+        self.next_hop_id = Some(id);
+        self
+    }
+
+    pub const fn destination(&self) -> IpPrefix {
+        self.destination
+    }
+
+    pub const fn gateway(&self) -> Option<IpAddr> {
+        self.gateway
+    }
+
+    pub const fn output_interface(&self) -> Option<u32> {
+        self.output_interface
+    }
+
+    /// The type of the route. It defaults to `RouteType::Unicast`.
+    pub fn route_type(&self) -> RouteType {
+        self.route_type.clone().unwrap_or(RouteType::Unicast)
+    }
+
+    /// The scope of the route, which is derived from its type and gateway
+    /// unless it has been set.
+    pub fn scope(&self) -> RouteScope {
+        if let Some(scope) = &self.scope {
+            return scope.clone();
+        }
+
+        match self.route_type() {
+            RouteType::Local | RouteType::Nat => RouteScope::Host,
+            RouteType::Broadcast | RouteType::Multicast | RouteType::Anycast => RouteScope::Link,
+            RouteType::Unicast | RouteType::Unspecified
+                if self.gateway.is_none()
+                    && self.next_hops.is_empty()
+                    && self.next_hop_id.is_none() =>
+            {
+                RouteScope::Link
+            }
+            _ => RouteScope::Universe,
+        }
+    }
+
+    /// Builds the message of a request adding or replacing the route.
+    ///
+    /// Fails with `InvalidInput` if the preferred source is not of the family
+    /// of the destination.
+    pub fn to_message(&self) -> IoResult<RouteMessage> {
+        self.build_message(
+            self.protocol.clone().unwrap_or(RouteProtocol::Boot),
+            self.scope(),
+            self.route_type(),
+        )
+    }
+
+    /// Builds the message of a request deleting the route, in which the
+    /// protocol, scope and type are left unspecified unless set.
+    pub fn to_delete_message(&self) -> IoResult<RouteMessage> {
+        self.build_message(
+            self.protocol.clone().unwrap_or(RouteProtocol::Unspecified),
+            self.scope.clone().unwrap_or(RouteScope::Nowhere),
+            self.route_type.clone().unwrap_or(RouteType::Unspecified),
+        )
+    }
+
+    fn build_message(
+        &self,
+        protocol: RouteProtocol,
+        scope: RouteScope,
+        route_type: RouteType,
+    ) -> IoResult<RouteMessage> {
+        let family = self.destination.family();
+        let mut attributes = Vec::new();
+
+        // The kernel expects the prefix itself, without host bits
+        let destination = self.destination.network();
+        if destination.prefix_len() > 0 {
+            attributes.push(RouteAttribute::Destination(destination.address().into()));
+        }
+
+        if let Some(gateway) = self.gateway {
+            if IpPrefix::host(gateway).family() == family {
+                attributes.push(RouteAttribute::Gateway(gateway.into()));
+            } else {
+                attributes.push(RouteAttribute::Via(gateway.into()));
+            }
+        }
+
+        if !self.next_hops.is_empty() {
+            attributes.push(RouteAttribute::Multipath(self.next_hops.clone()));
+        }
+
+        if let Some(id) = self.next_hop_id {
+            attributes.push(RouteAttribute::NextHopId(id));
+        }
+
+        if let Some(index) = self.output_interface {
+            attributes.push(RouteAttribute::OutgoingInterface(index as i32));
+        }
+
+        if let Some(source) = self.preferred_source {
+            if IpPrefix::host(source).family() != family {
+                return Err(IoError::new(
+                    IoErrorKind::InvalidInput,
+                    "address family differs from the destination",
+                ));
+            }
+
+            attributes.push(RouteAttribute::PreferredSource(source.into()));
+        }
+
+        if let Some(priority) = self.priority {
+            attributes.push(RouteAttribute::RoutePriority(priority as i32));
+        }
+
+        if let Some(metrics) = &self.metrics {
+            attributes.push(RouteAttribute::Metrics(metrics.clone()));
+        }
+
+        // Like ip does, tables that do not fit into rtm_table are given by
+        // RTA_TABLE only
+        let table = match self.table {
+            None => RouteTable::Main,
+            Some(table) => match u8::try_from(table) {
+                Ok(table) => RouteTable::from_raw_value(table),
+                Err(_) => {
+                    attributes.push(RouteAttribute::Table(table as i32));
+                    RouteTable::Unspecified
+                }
+            },
+        };
+
+        Ok(RouteMessage::new(
+            family,
+            destination.prefix_len(),
+            0,
+            0,
+            table,
+            protocol,
+            scope,
+            route_type,
+            RouteFlags::empty(),
+            attributes,
+        ))
+    }
+
+    /// Adds the route. Fails with `EEXIST` if the table already has a route
+    /// to the destination with the same priority.
+    pub fn add(&self, socket: &mut Socket) -> IoResult<()> {
+        let flags = Flags::Create | Flags::Excl;
+        socket.send_acknowledged(flags, NewRoute(self.to_message()?))
+    }
+
+    /// Adds the route, or replaces the route to the destination with the
+    /// same priority.
+    pub fn replace(&self, socket: &mut Socket) -> IoResult<()> {
+        let flags = Flags::Create | Flags::Replace;
+        socket.send_acknowledged(flags, NewRoute(self.to_message()?))
+    }
+
+    /// Adds the route after the existing routes to the destination with the
+    /// same priority. IPv6 routes are merged into a multipath route with them.
+    pub fn append(&self, socket: &mut Socket) -> IoResult<()> {
+        let flags = Flags::Create | Flags::Append;
+        socket.send_acknowledged(flags, NewRoute(self.to_message()?))
+    }
+
+    /// Deletes the route. Fails with `ESRCH` if there is no such route.
+    pub fn delete(&self, socket: &mut Socket) -> IoResult<()> {
+        socket.send_acknowledged(Flags::empty(), DeleteRoute(self.to_delete_message()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rtnetlink::{AddressFamily, NetworkAddress};
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn defaults_follow_ip_route() {
+        let gateway = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+        let request = RouteRequest::new("198.51.100.7/24".parse().unwrap()).with_gateway(gateway);
+        let message = request.to_message().unwrap();
+
+        assert_eq!(message.dst_len(), 24);
+        assert_eq!(message.table(), &RouteTable::Main);
+        assert_eq!(message.protocol(), &RouteProtocol::Boot);
+        assert_eq!(message.scope(), &RouteScope::Universe);
+        assert_eq!(message.route_type(), &RouteType::Unicast);
+        assert_eq!(
+            message.attributes(),
+            [
+                RouteAttribute::Destination(NetworkAddress::Ipv4(Ipv4Addr::new(198, 51, 100, 0))),
+                RouteAttribute::Gateway(NetworkAddress::Ipv4(Ipv4Addr::new(192, 0, 2, 1))),
+            ]
+        );
+
+        // Routes without a gateway are on-link
+        let request = RouteRequest::new("198.51.100.0/24".parse().unwrap()).with_output_interface(2);
+        assert_eq!(request.to_message().unwrap().scope(), &RouteScope::Link);
+
+        let message = request.to_delete_message().unwrap();
+        assert_eq!(message.protocol(), &RouteProtocol::Unspecified);
+        assert_eq!(message.scope(), &RouteScope::Nowhere);
+    }
+
+    #[test]
+    fn table_and_family() {
+        let default = IpPrefix::default_route(AddressFamily::Inet6).unwrap();
+
+        // Tables above 255 are only given by RTA_TABLE
+        let message = RouteRequest::new(default).with_table(1000).to_message().unwrap();
+        assert_eq!(message.table(), &RouteTable::Unspecified);
+        assert_eq!(message.attributes(), [RouteAttribute::Table(1000)]);
+
+        let message = RouteRequest::new(default).with_table(100).to_message().unwrap();
+        assert_eq!(message.table(), &RouteTable::Other(100));
+
+        // An IPv4 gateway of an IPv6 route is given by RTA_VIA
+        let gateway = "192.0.2.1".parse().unwrap();
+        let message = RouteRequest::new(default).with_gateway(gateway).to_message().unwrap();
+        assert_eq!(message.attributes(), [RouteAttribute::Via(gateway.into())]);
+
+        let request = RouteRequest::new(default).with_preferred_source(gateway);
+        assert_eq!(request.to_message().unwrap_err().kind(), IoErrorKind::InvalidInput);
+    }
+}
@@ -9,20 +9,24 @@ pub enum RouteScope {
     Link,
     Host,
     Nowhere,
+
+    /// A scope between the named ones, e.g. one given by its number to
+    /// `ip address add ... scope`. Smaller values are wider scopes.
+    Other(u8),
 }
 
 impl RouteScope {
-    pub fn from_raw_value(value: u8) -> Option<Self> {
+    pub fn from_raw_value(value: u8) -> Self {
         use RouteScope::*;
 
-        Some(match value {
+        match value {
             RT_SCOPE_UNIVERSE => Universe,
             RT_SCOPE_SITE => Site,
             RT_SCOPE_LINK => Link,
             RT_SCOPE_HOST => Host,
             RT_SCOPE_NOWHERE => Nowhere,
-            _ => None?
-        })
+            x => Other(x),
+        }
     }
 
     pub fn raw_value(&self) -> u8 {
@@ -34,6 +38,7 @@ impl RouteScope {
             Link => RT_SCOPE_LINK,
             Host => RT_SCOPE_HOST,
             Nowhere => RT_SCOPE_NOWHERE,
+            Other(x) => *x,
         }
     }
 }
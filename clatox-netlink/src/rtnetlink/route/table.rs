@@ -9,20 +9,26 @@ pub enum RouteTable {
     Default,
     Main,
     Local,
+
+    /// A user defined table, e.g. one of those named in
+    /// `/etc/iproute2/rt_tables`. The id of every table is given by the
+    /// `RTA_TABLE` attribute, since ids which do not fit into `u8` are
+    /// reported as `Compat` here.
+    Other(u8),
 }
 
 impl RouteTable {
-    pub fn from_raw_value(value: u8) -> Option<Self> {
+    pub fn from_raw_value(value: u8) -> Self {
         use RouteTable::*;
 
-        Some(match value {
+        match value {
             RT_TABLE_UNSPEC => Unspecified,
             RT_TABLE_COMPAT => Compat,
             RT_TABLE_DEFAULT => Default,
             RT_TABLE_MAIN => Main,
             RT_TABLE_LOCAL => Local,
-            _ => None?
-        })
+            x => Other(x),
+        }
     }
 
     pub fn raw_value(&self) -> u8 {
@@ -34,6 +40,7 @@ impl RouteTable {
             Default => RT_TABLE_DEFAULT,
             Main => RT_TABLE_MAIN,
             Local => RT_TABLE_LOCAL,
+            Other(x) => *x,
         }
     }
 }
@@ -0,0 +1,63 @@
+pub const FR_ACT_UNSPEC: u8 = 0;
+pub const FR_ACT_TO_TBL: u8 = 1;
+pub const FR_ACT_GOTO: u8 = 2;
+pub const FR_ACT_NOP: u8 = 3;
+pub const FR_ACT_BLACKHOLE: u8 = 6;
+pub const FR_ACT_UNREACHABLE: u8 = 7;
+pub const FR_ACT_PROHIBIT: u8 = 8;
+
+/// What a routing rule does with the packets it matches.
+#[doc(alias("fib_rule_hdr", "FR_ACT_"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleAction {
+    /// `FR_ACT_UNSPEC`
+    Unspecified,
+
+    /// `FR_ACT_TO_TBL` - looks the route up in the table of the rule.
+    ToTable,
+
+    /// `FR_ACT_GOTO` - continues with the rule given by `RuleAttribute::Goto`.
+    Goto,
+
+    /// `FR_ACT_NOP`
+    Nop,
+
+    /// `FR_ACT_BLACKHOLE` - drops the packets silently.
+    Blackhole,
+
+    /// `FR_ACT_UNREACHABLE` - drops the packets with `ENETUNREACH`.
+    Unreachable,
+
+    /// `FR_ACT_PROHIBIT` - drops the packets with `EACCES`.
+    Prohibit,
+
+    Other(u8),
+}
+
+impl RuleAction {
+    pub const fn raw_value(&self) -> u8 {
+        match self {
+            Self::Unspecified => FR_ACT_UNSPEC,
+            Self::ToTable => FR_ACT_TO_TBL,
+            Self::Goto => FR_ACT_GOTO,
+            Self::Nop => FR_ACT_NOP,
+            Self::Blackhole => FR_ACT_BLACKHOLE,
+            Self::Unreachable => FR_ACT_UNREACHABLE,
+            Self::Prohibit => FR_ACT_PROHIBIT,
+            Self::Other(x) => *x,
+        }
+    }
+
+    pub const fn from_raw_value(value: u8) -> Self {
+        match value {
+            FR_ACT_UNSPEC => Self::Unspecified,
+            FR_ACT_TO_TBL => Self::ToTable,
+            FR_ACT_GOTO => Self::Goto,
+            FR_ACT_NOP => Self::Nop,
+            FR_ACT_BLACKHOLE => Self::Blackhole,
+            FR_ACT_UNREACHABLE => Self::Unreachable,
+            FR_ACT_PROHIBIT => Self::Prohibit,
+            x => Self::Other(x),
+        }
+    }
+}
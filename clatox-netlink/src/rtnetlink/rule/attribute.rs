@@ -0,0 +1,309 @@
+use crate::netlink::{Attribute, RawAttribute};
+use crate::rtnetlink::{AddressAttribute, NetworkAddress, RouteProtocol};
+use crate::utils;
+
+pub const FRA_UNSPEC: u16 = 0;
+pub const FRA_DST: u16 = 1;
+pub const FRA_SRC: u16 = 2;
+pub const FRA_IIFNAME: u16 = 3;
+pub const FRA_GOTO: u16 = 4;
+pub const FRA_PRIORITY: u16 = 6;
+pub const FRA_FWMARK: u16 = 10;
+pub const FRA_FLOW: u16 = 11;
+pub const FRA_TUN_ID: u16 = 12;
+pub const FRA_SUPPRESS_IFGROUP: u16 = 13;
+pub const FRA_SUPPRESS_PREFIXLEN: u16 = 14;
+pub const FRA_TABLE: u16 = 15;
+pub const FRA_FWMASK: u16 = 16;
+pub const FRA_OIFNAME: u16 = 17;
+pub const FRA_PAD: u16 = 18;
+pub const FRA_L3MDEV: u16 = 19;
+pub const FRA_UID_RANGE: u16 = 20;
+pub const FRA_PROTOCOL: u16 = 21;
+pub const FRA_IP_PROTO: u16 = 22;
+pub const FRA_SPORT_RANGE: u16 = 23;
+pub const FRA_DPORT_RANGE: u16 = 24;
+pub const FRA_DSCP: u16 = 25;
+
+/// An inclusive range of user ids, which corresponds to the
+/// `fib_rule_uid_range` struct in <linux/fib_rules.h>.
+#[doc(alias("fib_rule_uid_range"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct RuleUidRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+/// An inclusive range of ports, which corresponds to the
+/// `fib_rule_port_range` struct in <linux/fib_rules.h>.
+#[doc(alias("fib_rule_port_range"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct RulePortRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+/// Attributes of a routing rule message. They correspond to `FRA_*` in
+/// <linux/fib_rules.h>.
+///
+/// The addresses are interpreted according to the address family of the
+/// message; on their own, they are decoded as `NetworkAddress::Other`.
+#[doc(alias("fib_rule_hdr", "FRA_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleAttribute {
+    /// `FRA_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `FRA_DST` - the destination prefix, whose length is given in the
+    /// header.
+    Destination(NetworkAddress),
+
+    /// `FRA_SRC` - the source prefix, whose length is given in the header.
+    Source(NetworkAddress),
+
+    /// `FRA_IIFNAME` - the name of the interface the packets are received
+    /// on. Packets sent by the host match `lo`.
+    InputInterfaceName(String),
+
+    /// `FRA_GOTO` - the priority of the rule a `RuleAction::Goto` continues
+    /// with.
+    Goto(u32),
+
+    /// `FRA_PRIORITY` - the rules are evaluated in the order of their
+    /// priorities, the lowest first.
+    Priority(u32),
+
+    /// `FRA_FWMARK`
+    FirewallMark(u32),
+
+    /// `FRA_FLOW` - the realms of the packets.
+    Flow(u32),
+
+    /// `FRA_TUN_ID` - the id of the tunnel the packets were received from.
+    TunnelId(u64),
+
+    /// `FRA_SUPPRESS_IFGROUP` - rejects the routes found through an
+    /// interface of the given group.
+    SuppressInterfaceGroup(u32),
+
+    /// `FRA_SUPPRESS_PREFIXLEN` - rejects the routes found whose prefix is
+    /// not longer than the given length, e.g. 0 to skip the default route.
+    SuppressPrefixLength(u32),
+
+    /// `FRA_TABLE` - the id of the table, which takes precedence over the one
+    /// in the header.
+    Table(u32),
+
+    /// `FRA_FWMASK` - the bits of the firewall mark compared with
+    /// `FirewallMark`.
+    FirewallMask(u32),
+
+    /// `FRA_OIFNAME` - the name of the interface the packets are sent
+    /// through, which is known only for sockets bound to an interface.
+    OutputInterfaceName(String),
+
+    /// `FRA_PAD`
+    Pad(Vec<u8>),
+
+    /// `FRA_L3MDEV` - looks the route up in the table of the VRF device of
+    /// the packets.
+    L3mdev(u8),
+
+    /// `FRA_UID_RANGE` - the users sending the packets.
+    UidRange(RuleUidRange),
+
+    /// `FRA_PROTOCOL` - the protocol which installed the rule.
+    Protocol(RouteProtocol),
+
+    /// `FRA_IP_PROTO` - the IP protocol of the packets, e.g.
+    /// `libc::IPPROTO_TCP`.
+    IpProtocol(u8),
+
+    /// `FRA_SPORT_RANGE`
+    SourcePortRange(RulePortRange),
+
+    /// `FRA_DPORT_RANGE`
+    DestinationPortRange(RulePortRange),
+
+    /// `FRA_DSCP`
+    Dscp(u8),
+
+    Other(u16, Vec<u8>),
+}
+
+impl RuleAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                FRA_UNSPEC
+            }
+            Self::Destination(address) => {
+                buffer.extend(address.to_bytes());
+                FRA_DST
+            }
+            Self::Source(address) => {
+                buffer.extend(address.to_bytes());
+                FRA_SRC
+            }
+            Self::InputInterfaceName(name) => {
+                buffer.extend(name.bytes());
+                buffer.push(0u8); // zero-terminated string
+                FRA_IIFNAME
+            }
+            Self::Goto(priority) => {
+                buffer.extend(priority.to_ne_bytes());
+                FRA_GOTO
+            }
+            Self::Priority(priority) => {
+                buffer.extend(priority.to_ne_bytes());
+                FRA_PRIORITY
+            }
+            Self::FirewallMark(mark) => {
+                buffer.extend(mark.to_ne_bytes());
+                FRA_FWMARK
+            }
+            Self::Flow(flow) => {
+                buffer.extend(flow.to_ne_bytes());
+                FRA_FLOW
+            }
+            Self::TunnelId(id) => {
+                buffer.extend(id.to_be_bytes());
+                FRA_TUN_ID
+            }
+            Self::SuppressInterfaceGroup(group) => {
+                buffer.extend(group.to_ne_bytes());
+                FRA_SUPPRESS_IFGROUP
+            }
+            Self::SuppressPrefixLength(len) => {
+                buffer.extend(len.to_ne_bytes());
+                FRA_SUPPRESS_PREFIXLEN
+            }
+            Self::Table(table) => {
+                buffer.extend(table.to_ne_bytes());
+                FRA_TABLE
+            }
+            Self::FirewallMask(mask) => {
+                buffer.extend(mask.to_ne_bytes());
+                FRA_FWMASK
+            }
+            Self::OutputInterfaceName(name) => {
+                buffer.extend(name.bytes());
+                buffer.push(0u8); // zero-terminated string
+                FRA_OIFNAME
+            }
+            Self::Pad(content) => {
+                buffer.extend(content.iter());
+                FRA_PAD
+            }
+            Self::L3mdev(l3mdev) => {
+                buffer.push(*l3mdev);
+                FRA_L3MDEV
+            }
+            Self::UidRange(range) => {
+                buffer.extend(range.start.to_ne_bytes());
+                buffer.extend(range.end.to_ne_bytes());
+                FRA_UID_RANGE
+            }
+            Self::Protocol(protocol) => {
+                buffer.push(protocol.raw_value());
+                FRA_PROTOCOL
+            }
+            Self::IpProtocol(protocol) => {
+                buffer.push(*protocol);
+                FRA_IP_PROTO
+            }
+            Self::SourcePortRange(range) => {
+                buffer.extend(range.start.to_ne_bytes());
+                buffer.extend(range.end.to_ne_bytes());
+                FRA_SPORT_RANGE
+            }
+            Self::DestinationPortRange(range) => {
+                buffer.extend(range.start.to_ne_bytes());
+                buffer.extend(range.end.to_ne_bytes());
+                FRA_DPORT_RANGE
+            }
+            Self::Dscp(dscp) => {
+                buffer.push(*dscp);
+                FRA_DSCP
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for RuleAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let u32_from = |content: Vec<u8>| {
+            let content = <[u8; 4]>::try_from(content).ok()?;
+            Some(u32::from_ne_bytes(content))
+        };
+
+        let string_from = |content: Vec<u8>| {
+            let content = String::from_utf8(content).ok()?;
+            Some(content.trim_end_matches('\0').to_owned())
+        };
+
+        let port_range_from = |content: Vec<u8>| {
+            let content = <[u8; 4]>::try_from(content).ok()?;
+            Some(RulePortRange {
+                start: u16::from_ne_bytes([content[0], content[1]]),
+                end: u16::from_ne_bytes([content[2], content[3]]),
+            })
+        };
+
+        let attr = match attr_type {
+            FRA_UNSPEC => Self::Unspecified(content),
+            FRA_DST => Self::Destination(NetworkAddress::Other(content)),
+            FRA_SRC => Self::Source(NetworkAddress::Other(content)),
+            FRA_IIFNAME => Self::InputInterfaceName(string_from(content)?),
+            FRA_GOTO => Self::Goto(u32_from(content)?),
+            FRA_PRIORITY => Self::Priority(u32_from(content)?),
+            FRA_FWMARK => Self::FirewallMark(u32_from(content)?),
+            FRA_FLOW => Self::Flow(u32_from(content)?),
+            FRA_TUN_ID => {
+                let content = <[u8; 8]>::try_from(content).ok()?;
+                Self::TunnelId(u64::from_be_bytes(content))
+            }
+            FRA_SUPPRESS_IFGROUP => Self::SuppressInterfaceGroup(u32_from(content)?),
+            FRA_SUPPRESS_PREFIXLEN => Self::SuppressPrefixLength(u32_from(content)?),
+            FRA_TABLE => Self::Table(u32_from(content)?),
+            FRA_FWMASK => Self::FirewallMask(u32_from(content)?),
+            FRA_OIFNAME => Self::OutputInterfaceName(string_from(content)?),
+            FRA_PAD => Self::Pad(content),
+            FRA_L3MDEV => Self::L3mdev(*content.first()?),
+            FRA_UID_RANGE => {
+                let content = <[u8; 8]>::try_from(content).ok()?;
+                Self::UidRange(RuleUidRange {
+                    start: u32::from_ne_bytes([content[0], content[1], content[2], content[3]]),
+                    end: u32::from_ne_bytes([content[4], content[5], content[6], content[7]]),
+                })
+            }
+            FRA_PROTOCOL => Self::Protocol(RouteProtocol::from_raw_value(*content.first()?)),
+            FRA_IP_PROTO => Self::IpProtocol(*content.first()?),
+            FRA_SPORT_RANGE => Self::SourcePortRange(port_range_from(content)?),
+            FRA_DPORT_RANGE => Self::DestinationPortRange(port_range_from(content)?),
+            FRA_DSCP => Self::Dscp(*content.first()?),
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+impl AddressAttribute for RuleAttribute {
+    fn address_mut(&mut self) -> Option<&mut NetworkAddress> {
+        match self {
+            Self::Destination(address) | Self::Source(address) => Some(address),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use std::io::{Error as IoError, Result as IoResult};
+
+use crate::netlink::{Flags, Message, ReceivedMessage, Socket};
+use crate::rtnetlink::{AddressFamily, RouteTable};
+
+use super::{GetRule, NewRule, RuleAction, RuleFlags, RuleMessage};
+
+/// Dumps the routing rules of the given family, or of all families if it is
+/// `AddressFamily::Unspecified`, like `ip rule show` does.
+pub fn dump_rules(socket: &mut Socket, family: AddressFamily) -> IoResult<Vec<RuleMessage>> {
+    let request = GetRule(RuleMessage::new(
+        family,
+        0,
+        0,
+        RouteTable::Unspecified,
+        RuleAction::Unspecified,
+        RuleFlags::empty(),
+        vec![],
+    ));
+    socket.send_message(&Message::new(Flags::Request | Flags::Dump, request))?;
+
+    match socket.receive_message::<NewRule>()? {
+        ReceivedMessage::Multipart(messages) => {
+            Ok(messages.into_iter().map(|x| x.into_payload().0).collect())
+        }
+        ReceivedMessage::Message(message) => Ok(vec![message.into_payload().0]),
+        ReceivedMessage::Error(error) => {
+            Err(IoError::from_raw_os_error(-error.payload().error_code()))
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use bitflags::bitflags;
+
+pub const FIB_RULE_PERMANENT: u32 = 0x00000001;
+pub const FIB_RULE_INVERT: u32 = 0x00000002;
+pub const FIB_RULE_UNRESOLVED: u32 = 0x00000004;
+pub const FIB_RULE_IIF_DETACHED: u32 = 0x00000008;
+pub const FIB_RULE_OIF_DETACHED: u32 = 0x00000010;
+pub const FIB_RULE_FIND_SADDR: u32 = 0x00010000;
+
+bitflags! {
+    #[doc(alias("fib_rule_hdr", "FIB_RULE_"))]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct RuleFlags: u32 {
+        /// The rule cannot be deleted, e.g. the default rules of the local
+        /// table.
+        const Permanent = FIB_RULE_PERMANENT;
+
+        /// The rule matches the packets that its selectors do not match.
+        const Invert = FIB_RULE_INVERT;
+
+        /// The rule is a `RuleAction::Goto` whose target does not exist.
+        const Unresolved = FIB_RULE_UNRESOLVED;
+
+        /// The interface named by `RuleAttribute::InputInterfaceName` does
+        /// not exist.
+        const InputInterfaceDetached = FIB_RULE_IIF_DETACHED;
+
+        /// The interface named by `RuleAttribute::OutputInterfaceName` does
+        /// not exist.
+        const OutputInterfaceDetached = FIB_RULE_OIF_DETACHED;
+
+        const FindSourceAddress = FIB_RULE_FIND_SADDR;
+    }
+}
@@ -0,0 +1,276 @@
+use crate::netlink::{Attribute, Payload, RawAttributeIter, RouteType, Type};
+use crate::rtnetlink::{resolve_addresses, AddressFamily, RouteTable};
+use crate::utils::read_u32;
+
+use super::{RuleAction, RuleAttribute, RuleFlags};
+
+/// The message on which the `GetRule`, `NewRule` and `DeleteRule` requests
+/// are based on. It corresponds to the `fib_rule_hdr` struct in
+/// <linux/fib_rules.h>.
+///
+/// The rules of the routing policy database decide which table a route is
+/// looked up in, based on selectors like the source prefix, the firewall mark
+/// or the incoming interface. IPv4 and IPv6 have rules of their own.
+#[doc(alias("fib_rule_hdr"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleMessage {
+    family: AddressFamily,
+    dst_len: u8,
+    src_len: u8,
+    tos: u8,
+    table: RouteTable,
+    action: RuleAction,
+    flags: RuleFlags,
+    attributes: Vec<RuleAttribute>,
+}
+
+impl RuleMessage {
+    pub const fn new(
+        family: AddressFamily,
+        dst_len: u8,
+        src_len: u8,
+        table: RouteTable,
+        action: RuleAction,
+        flags: RuleFlags,
+        attributes: Vec<RuleAttribute>,
+    ) -> Self {
+        Self {
+            family,
+            dst_len,
+            src_len,
+            tos: 0,
+            table,
+            action,
+            flags,
+            attributes,
+        }
+    }
+
+    /// Matches the packets of the given type of service.
+    pub fn with_tos(mut self, tos: u8) -> Self {
+        self.tos = tos;
+        self
+    }
+
+    pub const fn family(&self) -> AddressFamily {
+        self.family
+    }
+
+    pub const fn dst_len(&self) -> u8 {
+        self.dst_len
+    }
+
+    pub const fn src_len(&self) -> u8 {
+        self.src_len
+    }
+
+    pub const fn tos(&self) -> u8 {
+        self.tos
+    }
+
+    pub const fn table(&self) -> &RouteTable {
+        &self.table
+    }
+
+    pub const fn action(&self) -> RuleAction {
+        self.action
+    }
+
+    pub const fn flags(&self) -> RuleFlags {
+        self.flags
+    }
+
+    pub fn attributes(&self) -> &[RuleAttribute] {
+        &self.attributes
+    }
+
+    /// The id of the table of the rule, given by `RuleAttribute::Table` if
+    /// present, since the header can only hold ids up to 255.
+    pub fn table_id(&self) -> u32 {
+        self.attributes
+            .iter()
+            .find_map(|x| match x {
+                RuleAttribute::Table(table) => Some(*table),
+                _ => None,
+            })
+            .unwrap_or(self.table.raw_value() as u32)
+    }
+
+    /// The `Priority` attribute.
+    pub fn priority(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|x| match x {
+            RuleAttribute::Priority(priority) => Some(*priority),
+            _ => None,
+        })
+    }
+
+    pub fn serialize(&self) -> Box<[u8]> {
+        let mut buffer = Vec::with_capacity(12);
+        buffer.push(self.family.raw_value());
+        buffer.push(self.dst_len);
+        buffer.push(self.src_len);
+        buffer.push(self.tos);
+        buffer.push(self.table.raw_value());
+        buffer.push(0u8); // res1
+        buffer.push(0u8); // res2
+        buffer.push(self.action.raw_value());
+        buffer.extend(self.flags.bits().to_ne_bytes());
+
+        for attr in &self.attributes {
+            attr.serialize_into(&mut buffer);
+        }
+
+        buffer.into_boxed_slice()
+    }
+
+    pub fn deserialize(bytes: &[u8]) -> Option<Self> {
+        // The header is 12 bytes. If the data we receive is shorter than that
+        // it's not going to be valid
+        if bytes.len() < 12 {
+            return None;
+        }
+
+        let mut iter = bytes.iter().cloned();
+
+        let family = iter.next()?;
+        let dst_len = iter.next()?;
+        let src_len = iter.next()?;
+        let tos = iter.next()?;
+        let table = iter.next()?;
+        let _res1 = iter.next()?;
+        let _res2 = iter.next()?;
+        let action = iter.next()?;
+        let flags = read_u32(iter.by_ref())?;
+
+        let family = AddressFamily::from_raw_value(family)?;
+        let table = RouteTable::from_raw_value(table);
+        let action = RuleAction::from_raw_value(action);
+        let flags = RuleFlags::from_bits_retain(flags);
+
+        let mut attributes: Vec<_> = RawAttributeIter::new(iter)
+            .map(RuleAttribute::from_raw)
+            .try_collect()?;
+
+        resolve_addresses(family, &mut attributes);
+
+        Some(Self {
+            family,
+            dst_len,
+            src_len,
+            tos,
+            table,
+            action,
+            flags,
+            attributes,
+        })
+    }
+}
+
+/// A message that is of the `RTM_GETRULE` type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetRule(pub RuleMessage);
+
+impl Payload for GetRule {
+    fn message_type() -> Type {
+        Type::Route(RouteType::GetRule)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(RuleMessage::deserialize(bytes)?))
+    }
+}
+
+/// A message that is of the `RTM_NEWRULE` type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewRule(pub RuleMessage);
+
+impl Payload for NewRule {
+    fn message_type() -> Type {
+        Type::Route(RouteType::NewRule)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(RuleMessage::deserialize(bytes)?))
+    }
+}
+
+/// A message that is of the `RTM_DELRULE` type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeleteRule(pub RuleMessage);
+
+impl Payload for DeleteRule {
+    fn message_type() -> Type {
+        Type::Route(RouteType::DeleteRule)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(RuleMessage::deserialize(bytes)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rtnetlink::{FIB_RULE_INVERT, FRA_PRIORITY, FRA_SRC, FRA_TABLE, FR_ACT_TO_TBL};
+    use crate::rtnetlink::{NetworkAddress, RouteProtocol, FRA_PROTOCOL};
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn fib_rule_hdr_layout() {
+        // struct fib_rule_hdr of `ip rule add not from 192.0.2.0/24 lookup
+        // 1000 pref 100`, followed by its attributes
+        let mut bytes = vec![libc::AF_INET as u8, 0, 24, 0, 0, 0, 0, FR_ACT_TO_TBL];
+        bytes.extend(FIB_RULE_INVERT.to_ne_bytes());
+        for (attr_type, content) in [
+            (FRA_TABLE, 1000u32.to_ne_bytes()),
+            (FRA_PRIORITY, 100u32.to_ne_bytes()),
+            (FRA_SRC, [192, 0, 2, 0]),
+        ] {
+            bytes.extend(8u16.to_ne_bytes());
+            bytes.extend(attr_type.to_ne_bytes());
+            bytes.extend(content);
+        }
+
+        let message = RuleMessage::deserialize(&bytes).unwrap();
+        assert_eq!(message.family(), AddressFamily::Inet);
+        assert_eq!(message.src_len(), 24);
+        assert_eq!(message.action(), RuleAction::ToTable);
+        assert_eq!(message.flags(), RuleFlags::Invert);
+        assert_eq!(message.table_id(), 1000);
+        assert_eq!(message.priority(), Some(100));
+        assert_eq!(
+            message.attributes()[2],
+            RuleAttribute::Source(NetworkAddress::Ipv4(Ipv4Addr::new(192, 0, 2, 0)))
+        );
+        assert_eq!(&message.serialize()[..], &bytes[..]);
+    }
+
+    #[test]
+    fn unknown_table_and_protocol() {
+        // A rule of table 100 installed by a protocol of
+        // /etc/iproute2/rt_protos
+        let mut bytes = vec![libc::AF_INET6 as u8, 0, 0, 0, 100, 0, 0, FR_ACT_TO_TBL];
+        bytes.extend(0u32.to_ne_bytes());
+        bytes.extend(5u16.to_ne_bytes());
+        bytes.extend(FRA_PROTOCOL.to_ne_bytes());
+        bytes.extend([77, 0, 0, 0]);
+
+        let message = RuleMessage::deserialize(&bytes).unwrap();
+        assert_eq!(message.table(), &RouteTable::Other(100));
+        assert_eq!(message.table_id(), 100);
+        assert_eq!(message.attributes(), [RuleAttribute::Protocol(RouteProtocol::Other(77))]);
+        assert_eq!(&message.serialize()[..], &bytes[..]);
+    }
+}
@@ -0,0 +1,363 @@
+use std::io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult};
+
+use crate::netlink::{Flags, Socket};
+use crate::rtnetlink::{AddressFamily, IpPrefix, RouteProtocol, RouteTable};
+
+use super::{
+    DeleteRule, NewRule, RuleAction, RuleAttribute, RuleFlags, RuleMessage, RulePortRange,
+    RuleUidRange,
+};
+
+/// A routing rule of the IPv4 or IPv6 policy database to be added or deleted,
+/// like `ip rule` does.
+///
+/// The rule matches the packets which match all of its selectors, e.g. the
+/// source prefix and the firewall mark, and a rule without any selectors
+/// matches every packet. Unless another action is set, the route of a
+/// matching packet is looked up in the table of the rule, which defaults to
+/// the main table.
+///
+/// When deleting, only the properties that have been set are compared, so
+/// that e.g. a rule is found by its priority alone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleRequest {
+    family: AddressFamily,
+    source: Option<IpPrefix>,
+    destination: Option<IpPrefix>,
+    input_interface: Option<String>,
+    output_interface: Option<String>,
+    mark: Option<u32>,
+    mask: Option<u32>,
+    table: Option<u32>,
+    priority: Option<u32>,
+    suppress_prefix_len: Option<u32>,
+    uid_range: Option<RuleUidRange>,
+    ip_protocol: Option<u8>,
+    source_ports: Option<RulePortRange>,
+    destination_ports: Option<RulePortRange>,
+    l3mdev: bool,
+    action: Option<RuleAction>,
+    goto: Option<u32>,
+    invert: bool,
+    protocol: Option<RouteProtocol>,
+}
+
+impl RuleRequest {
+    /// A rule of the given family, which is either `AddressFamily::Inet` or
+    /// `AddressFamily::Inet6`.
+    pub const fn new(family: AddressFamily) -> Self {
+        Self {
+            family,
+            source: None,
+            destination: None,
+            input_interface: None,
+            output_interface: None,
+            mark: None,
+            mask: None,
+            table: None,
+            priority: None,
+            suppress_prefix_len: None,
+            uid_range: None,
+            ip_protocol: None,
+            source_ports: None,
+            destination_ports: None,
+            l3mdev: false,
+            action: None,
+            goto: None,
+            invert: false,
+            protocol: None,
+        }
+    }
+
+    /// Matches the packets whose source address is part of the prefix.
+    pub fn with_source(mut self, prefix: IpPrefix) -> Self {
+        self.source = Some(prefix);
+        self
+    }
+
+    /// Matches the packets whose destination address is part of the prefix.
+    pub fn with_destination(mut self, prefix: IpPrefix) -> Self {
+        self.destination = Some(prefix);
+        self
+    }
+
+    /// Matches the packets received on the interface of the given name, or
+    /// sent by the host if it is `lo`.
+    pub fn with_input_interface(mut self, name: &str) -> Self {
+        self.input_interface = Some(name.to_owned());
+        self
+    }
+
+    /// Matches the packets sent by sockets bound to the interface of the
+    /// given name.
+    pub fn with_output_interface(mut self, name: &str) -> Self {
+        self.output_interface = Some(name.to_owned());
+        self
+    }
+
+    /// Matches the packets whose firewall mark, masked by the given mask if
+    /// any, equals the given mark.
+    pub fn with_mark(mut self, mark: u32, mask: Option<u32>) -> Self {
+        self.mark = Some(mark);
+        self.mask = mask;
+        self
+    }
+
+    /// Sets the id of the table the routes are looked up in.
+    pub fn with_table(mut self, table: u32) -> Self {
+        self.table = Some(table);
+        self
+    }
+
+    /// Sets the priority of the rule, the lowest being evaluated first. The
+    /// kernel picks one below the lowest existing priority if it is unset.
+    pub fn with_priority(mut self, priority: u32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Rejects the routes found whose prefix is not longer than the given
+    /// length, e.g. 0 to only use the table for more specific routes than the
+    /// default route.
+    pub fn with_suppress_prefix_len(mut self, len: u32) -> Self {
+        self.suppress_prefix_len = Some(len);
+        self
+    }
+
+    /// Matches the packets sent by the users in the inclusive range.
+    pub fn with_uid_range(mut self, start: u32, end: u32) -> Self {
+        self.uid_range = Some(RuleUidRange { start, end });
+        self
+    }
+
+    /// Matches the packets of the given IP protocol, e.g.
+    /// `libc::IPPROTO_UDP`.
+    pub fn with_ip_protocol(mut self, protocol: u8) -> Self {
+        self.ip_protocol = Some(protocol);
+        self
+    }
+
+    /// Matches the packets whose source port is in the inclusive range.
+    pub fn with_source_port_range(mut self, start: u16, end: u16) -> Self {
+        self.source_ports = Some(RulePortRange { start, end });
+        self
+    }
+
+    /// Matches the packets whose destination port is in the inclusive range.
+    pub fn with_destination_port_range(mut self, start: u16, end: u16) -> Self {
+        self.destination_ports = Some(RulePortRange { start, end });
+        self
+    }
+
+    /// Looks the routes up in the table of the VRF device of the packets,
+    /// instead of a table of the rule.
+    pub fn with_l3mdev(mut self, l3mdev: bool) -> Self {
+        self.l3mdev = l3mdev;
+        self
+    }
+
+    /// Sets the action of the rule, e.g. `RuleAction::Unreachable`.
+    pub fn with_action(mut self, action: RuleAction) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    /// Continues with the rule of the given priority.
+    pub fn with_goto(mut self, priority: u32) -> Self {
+        self.action = Some(RuleAction::Goto);
+        self.goto = Some(priority);
+        self
+    }
+
+    /// Makes the rule match the packets which its selectors do not match.
+    pub fn with_invert(mut self, invert: bool) -> Self {
+        self.invert = invert;
+        self
+    }
+
+    /// Sets the protocol which installed the rule.
+    pub fn with_protocol(mut self, protocol: RouteProtocol) -> Self {
+        self.protocol = Some(protocol);
+        self
+    }
+
+    pub const fn family(&self) -> AddressFamily {
+        self.family
+    }
+
+    pub const fn priority(&self) -> Option<u32> {
+        self.priority
+    }
+
+    /// Builds the message of a request adding the rule.
+    ///
+    /// Fails with `InvalidInput` if a prefix is not of the family of the
+    /// rule.
+    pub fn to_message(&self) -> IoResult<RuleMessage> {
+        let action = self.action.unwrap_or(RuleAction::ToTable);
+
+        let table = match self.table {
+            None if action == RuleAction::ToTable && !self.l3mdev => Some(libc::RT_TABLE_MAIN as u32),
+            table => table,
+        };
+
+        self.build_message(action, table)
+    }
+
+    /// Builds the message of a request deleting the rule, in which the
+    /// action and the table are left unspecified unless set.
+    pub fn to_delete_message(&self) -> IoResult<RuleMessage> {
+        self.build_message(self.action.unwrap_or(RuleAction::Unspecified), self.table)
+    }
+
+    fn build_message(&self, action: RuleAction, table: Option<u32>) -> IoResult<RuleMessage> {
+        let mut attributes = Vec::new();
+
+        let mut prefix_len = |prefix: Option<IpPrefix>, source: bool| {
+            let Some(prefix) = prefix else {
+                return Ok(0);
+            };
+
+            if prefix.family() != self.family {
+                return Err(IoError::new(
+                    IoErrorKind::InvalidInput,
+                    "address family differs from the rule",
+                ));
+            }
+
+            let prefix = prefix.network();
+            let address = prefix.address().into();
+            attributes.push(match source {
+                true => RuleAttribute::Source(address),
+                false => RuleAttribute::Destination(address),
+            });
+
+            Ok(prefix.prefix_len())
+        };
+
+        let src_len = prefix_len(self.source, true)?;
+        let dst_len = prefix_len(self.destination, false)?;
+
+        if let Some(priority) = self.priority {
+            attributes.push(RuleAttribute::Priority(priority));
+        }
+
+        if let Some(name) = &self.input_interface {
+            attributes.push(RuleAttribute::InputInterfaceName(name.clone()));
+        }
+
+        if let Some(name) = &self.output_interface {
+            attributes.push(RuleAttribute::OutputInterfaceName(name.clone()));
+        }
+
+        if let Some(mark) = self.mark {
+            attributes.push(RuleAttribute::FirewallMark(mark));
+        }
+
+        if let Some(mask) = self.mask {
+            attributes.push(RuleAttribute::FirewallMask(mask));
+        }
+
+        if let Some(len) = self.suppress_prefix_len {
+            attributes.push(RuleAttribute::SuppressPrefixLength(len));
+        }
+
+        if let Some(range) = self.uid_range {
+            attributes.push(RuleAttribute::UidRange(range));
+        }
+
+        if let Some(protocol) = self.ip_protocol {
+            attributes.push(RuleAttribute::IpProtocol(protocol));
+        }
+
+        if let Some(range) = self.source_ports {
+            attributes.push(RuleAttribute::SourcePortRange(range));
+        }
+
+        if let Some(range) = self.destination_ports {
+            attributes.push(RuleAttribute::DestinationPortRange(range));
+        }
+
+        if self.l3mdev {
+            attributes.push(RuleAttribute::L3mdev(1));
+        }
+
+        if let Some(priority) = self.goto {
+            attributes.push(RuleAttribute::Goto(priority));
+        }
+
+        if let Some(protocol) = &self.protocol {
+            attributes.push(RuleAttribute::Protocol(protocol.clone()));
+        }
+
+        // Like ip does, tables that do not fit into the header are given by
+        // FRA_TABLE only
+        let table = match table {
+            None => RouteTable::Unspecified,
+            Some(table) => match u8::try_from(table) {
+                Ok(table) => RouteTable::from_raw_value(table),
+                Err(_) => {
+                    attributes.push(RuleAttribute::Table(table));
+                    RouteTable::Unspecified
+                }
+            },
+        };
+
+        let flags = match self.invert {
+            true => RuleFlags::Invert,
+            false => RuleFlags::empty(),
+        };
+
+        Ok(RuleMessage::new(
+            self.family,
+            dst_len,
+            src_len,
+            table,
+            action,
+            flags,
+            attributes,
+        ))
+    }
+
+    /// Adds the rule. Fails with `EEXIST` if there is an identical rule.
+    pub fn add(&self, socket: &mut Socket) -> IoResult<()> {
+        let flags = Flags::Create | Flags::Excl;
+        socket.send_acknowledged(flags, NewRule(self.to_message()?))
+    }
+
+    /// Deletes the first rule matching the request. Fails with `ENOENT` if
+    /// there is none.
+    pub fn delete(&self, socket: &mut Socket) -> IoResult<()> {
+        socket.send_acknowledged(Flags::empty(), DeleteRule(self.to_delete_message()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv6Addr;
+
+    #[test]
+    fn defaults_follow_ip_rule() {
+        let message = RuleRequest::new(AddressFamily::Inet6)
+            .with_source("2001:db8::1/32".parse().unwrap())
+            .to_message()
+            .unwrap();
+        assert_eq!(message.src_len(), 32);
+        assert_eq!(message.table(), &RouteTable::Main);
+        assert_eq!(message.action(), RuleAction::ToTable);
+        assert_eq!(
+            message.attributes(),
+            [RuleAttribute::Source("2001:db8::".parse::<Ipv6Addr>().unwrap().into())]
+        );
+
+        // Deleting leaves the table unspecified, so that any table matches
+        let message = RuleRequest::new(AddressFamily::Inet6).to_delete_message().unwrap();
+        assert_eq!(message.table(), &RouteTable::Unspecified);
+        assert_eq!(message.action(), RuleAction::Unspecified);
+
+        let request =
+            RuleRequest::new(AddressFamily::Inet6).with_source("192.0.2.0/24".parse().unwrap());
+        assert_eq!(request.to_message().unwrap_err().kind(), IoErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,360 @@
+use std::mem::{size_of, transmute};
+
+use libc::*;
+
+use crate::netlink::{Attribute, RawAttribute, RawAttributeIter};
+use crate::rtnetlink::InterfaceStats64;
+use crate::utils;
+
+use super::LinkExtendedStats;
+
+pub const IFLA_STATS_UNSPEC: u16 = 0;
+pub const IFLA_STATS_LINK_64: u16 = 1;
+pub const IFLA_STATS_LINK_XSTATS: u16 = 2;
+pub const IFLA_STATS_LINK_XSTATS_SLAVE: u16 = 3;
+pub const IFLA_STATS_LINK_OFFLOAD_XSTATS: u16 = 4;
+pub const IFLA_STATS_AF_SPEC: u16 = 5;
+
+pub const IFLA_OFFLOAD_XSTATS_UNSPEC: u16 = 0;
+pub const IFLA_OFFLOAD_XSTATS_CPU_HIT: u16 = 1;
+pub const IFLA_OFFLOAD_XSTATS_HW_S_INFO: u16 = 2;
+pub const IFLA_OFFLOAD_XSTATS_L3_STATS: u16 = 3;
+
+pub const MPLS_STATS_UNSPEC: u16 = 0;
+pub const MPLS_STATS_LINK: u16 = 1;
+
+/// Statistics of a link, as found in a `NewStats` message. They correspond to
+/// `IFLA_STATS_*` in libc.
+#[doc(alias("IFLA_STATS_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatsAttribute {
+    /// `IFLA_STATS_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `IFLA_STATS_LINK_64`
+    Link64(InterfaceStats64),
+
+    /// `IFLA_STATS_LINK_XSTATS` - statistics specific to the kind of the link.
+    LinkExtendedStats(Vec<LinkExtendedStats>),
+
+    /// `IFLA_STATS_LINK_XSTATS_SLAVE` - statistics specific to the kind of the
+    /// master of the link.
+    LinkExtendedStatsSlave(Vec<LinkExtendedStats>),
+
+    /// `IFLA_STATS_LINK_OFFLOAD_XSTATS`
+    LinkOffloadExtendedStats(Vec<OffloadStatsAttribute>),
+
+    /// `IFLA_STATS_AF_SPEC`
+    AddressFamilySpecific(Vec<StatsAddressFamilySpecific>),
+
+    Other(u16, Vec<u8>),
+}
+
+/// Hardware statistics of a link, a subset of [`InterfaceStats64`].
+#[doc(alias("rtnl_hw_stats64"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct HardwareStats64 {
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_dropped: u64,
+    pub multicast: u64,
+}
+
+/// Statistics of traffic that is offloaded to, or not handled by the hardware.
+/// They correspond to `IFLA_OFFLOAD_XSTATS_*` in libc.
+#[doc(alias("IFLA_OFFLOAD_XSTATS_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OffloadStatsAttribute {
+    /// `IFLA_OFFLOAD_XSTATS_UNSPEC`
+    Unspecified(Vec<u8>),
+
+    /// `IFLA_OFFLOAD_XSTATS_CPU_HIT` - traffic that hit the CPU instead of
+    /// being forwarded by the hardware.
+    CpuHit(InterfaceStats64),
+
+    /// `IFLA_OFFLOAD_XSTATS_HW_S_INFO`
+    HardwareStatsInfo(Vec<u8>),
+
+    /// `IFLA_OFFLOAD_XSTATS_L3_STATS`
+    L3Stats(HardwareStats64),
+
+    Other(u16, Vec<u8>),
+}
+
+/// MPLS statistics of a link.
+#[doc(alias("mpls_link_stats"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct MplsLinkStats {
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_dropped: u64,
+    pub rx_noroute: u64,
+}
+
+/// MPLS specific statistics, corresponding to `MPLS_STATS_*` in libc.
+#[doc(alias("MPLS_STATS_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MplsStatsAttribute {
+    /// `MPLS_STATS_LINK`
+    Link(MplsLinkStats),
+
+    Other(u16, Vec<u8>),
+}
+
+/// Address-family specific statistics of a link, nested in
+/// `IFLA_STATS_AF_SPEC`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatsAddressFamilySpecific {
+    /// `AF_MPLS`
+    Mpls(Vec<MplsStatsAttribute>),
+
+    /// Unrecognized address families
+    Other(u8, Vec<u8>),
+}
+
+impl StatsAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                IFLA_STATS_UNSPEC
+            }
+            Self::Link64(stats) => {
+                // SAFETY: InterfaceStats64 does not contain any paddings.
+                let bytes = unsafe {
+                    transmute::<InterfaceStats64, [u8; size_of::<InterfaceStats64>()]>(
+                        stats.clone(),
+                    )
+                };
+                buffer.extend(bytes);
+                IFLA_STATS_LINK_64
+            }
+            Self::LinkExtendedStats(stats) => {
+                stats.iter().for_each(|s| s.serialize_into(buffer));
+                IFLA_STATS_LINK_XSTATS
+            }
+            Self::LinkExtendedStatsSlave(stats) => {
+                stats.iter().for_each(|s| s.serialize_into(buffer));
+                IFLA_STATS_LINK_XSTATS_SLAVE
+            }
+            Self::LinkOffloadExtendedStats(stats) => {
+                stats.iter().for_each(|s| s.serialize_into(buffer));
+                IFLA_STATS_LINK_OFFLOAD_XSTATS
+            }
+            Self::AddressFamilySpecific(specs) => {
+                specs.iter().for_each(|s| s.serialize_into(buffer));
+                IFLA_STATS_AF_SPEC
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for StatsAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            IFLA_STATS_UNSPEC => Self::Unspecified(content),
+            IFLA_STATS_LINK_64 => {
+                let content = <[u8; size_of::<InterfaceStats64>()]>::try_from(content).ok()?;
+                // SAFETY: InterfaceStats64 is a plain-old-data struct and contains
+                // no paddings.
+                let stats = unsafe {
+                    transmute::<[u8; size_of::<InterfaceStats64>()], InterfaceStats64>(content)
+                };
+                Self::Link64(stats)
+            }
+            IFLA_STATS_LINK_XSTATS => {
+                let stats = RawAttributeIter::new(content.iter().cloned())
+                    .map(LinkExtendedStats::from_raw)
+                    .try_collect()?;
+                Self::LinkExtendedStats(stats)
+            }
+            IFLA_STATS_LINK_XSTATS_SLAVE => {
+                let stats = RawAttributeIter::new(content.iter().cloned())
+                    .map(LinkExtendedStats::from_raw)
+                    .try_collect()?;
+                Self::LinkExtendedStatsSlave(stats)
+            }
+            IFLA_STATS_LINK_OFFLOAD_XSTATS => {
+                let stats = RawAttributeIter::new(content.iter().cloned())
+                    .map(OffloadStatsAttribute::from_raw)
+                    .try_collect()?;
+                Self::LinkOffloadExtendedStats(stats)
+            }
+            IFLA_STATS_AF_SPEC => {
+                let specs = RawAttributeIter::new(content.iter().cloned())
+                    .map(StatsAddressFamilySpecific::from_raw)
+                    .try_collect()?;
+                Self::AddressFamilySpecific(specs)
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+impl OffloadStatsAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Unspecified(content) => {
+                buffer.extend(content.iter());
+                IFLA_OFFLOAD_XSTATS_UNSPEC
+            }
+            Self::CpuHit(stats) => {
+                // SAFETY: InterfaceStats64 does not contain any paddings.
+                let bytes = unsafe {
+                    transmute::<InterfaceStats64, [u8; size_of::<InterfaceStats64>()]>(
+                        stats.clone(),
+                    )
+                };
+                buffer.extend(bytes);
+                IFLA_OFFLOAD_XSTATS_CPU_HIT
+            }
+            Self::HardwareStatsInfo(content) => {
+                buffer.extend(content.iter());
+                IFLA_OFFLOAD_XSTATS_HW_S_INFO
+            }
+            Self::L3Stats(stats) => {
+                // SAFETY: HardwareStats64 does not contain any paddings.
+                let bytes = unsafe {
+                    transmute::<HardwareStats64, [u8; size_of::<HardwareStats64>()]>(
+                        stats.clone(),
+                    )
+                };
+                buffer.extend(bytes);
+                IFLA_OFFLOAD_XSTATS_L3_STATS
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for OffloadStatsAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            IFLA_OFFLOAD_XSTATS_UNSPEC => Self::Unspecified(content),
+            IFLA_OFFLOAD_XSTATS_CPU_HIT => {
+                let content = <[u8; size_of::<InterfaceStats64>()]>::try_from(content).ok()?;
+                // SAFETY: InterfaceStats64 is a plain-old-data struct and contains
+                // no paddings.
+                let stats = unsafe {
+                    transmute::<[u8; size_of::<InterfaceStats64>()], InterfaceStats64>(content)
+                };
+                Self::CpuHit(stats)
+            }
+            IFLA_OFFLOAD_XSTATS_HW_S_INFO => Self::HardwareStatsInfo(content),
+            IFLA_OFFLOAD_XSTATS_L3_STATS => {
+                let content = <[u8; size_of::<HardwareStats64>()]>::try_from(content).ok()?;
+                // SAFETY: HardwareStats64 is a plain-old-data struct and contains
+                // no paddings.
+                let stats = unsafe {
+                    transmute::<[u8; size_of::<HardwareStats64>()], HardwareStats64>(content)
+                };
+                Self::L3Stats(stats)
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+impl MplsStatsAttribute {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Link(stats) => {
+                // SAFETY: MplsLinkStats does not contain any paddings.
+                let bytes = unsafe {
+                    transmute::<MplsLinkStats, [u8; size_of::<MplsLinkStats>()]>(stats.clone())
+                };
+                buffer.extend(bytes);
+                MPLS_STATS_LINK
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for MplsStatsAttribute {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            MPLS_STATS_LINK => {
+                let content = <[u8; size_of::<MplsLinkStats>()]>::try_from(content).ok()?;
+                // SAFETY: MplsLinkStats is a plain-old-data struct and contains no
+                // paddings.
+                let stats = unsafe {
+                    transmute::<[u8; size_of::<MplsLinkStats>()], MplsLinkStats>(content)
+                };
+                Self::Link(stats)
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+impl StatsAddressFamilySpecific {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Mpls(attrs) => {
+                attrs.iter().for_each(|a| a.serialize_into(buffer));
+                AF_MPLS as u16
+            }
+            Self::Other(typ, content) => {
+                buffer.extend(content.iter());
+                *typ as u16
+            }
+        });
+    }
+}
+
+impl Attribute for StatsAddressFamilySpecific {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type as i32 {
+            AF_MPLS => {
+                let attrs = RawAttributeIter::new(content.iter().cloned())
+                    .map(MplsStatsAttribute::from_raw)
+                    .try_collect()?;
+                Self::Mpls(attrs)
+            }
+            typ => Self::Other(typ as u8, content),
+        };
+
+        Some(attr)
+    }
+}
@@ -0,0 +1,382 @@
+use std::mem::{size_of, transmute};
+
+use crate::netlink::{Attribute, RawAttribute, RawAttributeIter};
+use crate::utils;
+
+pub const LINK_XSTATS_TYPE_UNSPEC: u16 = 0;
+pub const LINK_XSTATS_TYPE_BRIDGE: u16 = 1;
+pub const LINK_XSTATS_TYPE_BOND: u16 = 2;
+
+pub const BRIDGE_XSTATS_UNSPEC: u16 = 0;
+pub const BRIDGE_XSTATS_VLAN: u16 = 1;
+pub const BRIDGE_XSTATS_MCAST: u16 = 2;
+pub const BRIDGE_XSTATS_PAD: u16 = 3;
+pub const BRIDGE_XSTATS_STP: u16 = 4;
+
+pub const BOND_XSTATS_UNSPEC: u16 = 0;
+pub const BOND_XSTATS_3AD: u16 = 1;
+
+pub const BOND_3AD_STAT_LACPDU_RX: u16 = 0;
+pub const BOND_3AD_STAT_LACPDU_TX: u16 = 1;
+pub const BOND_3AD_STAT_LACPDU_UNKNOWN_RX: u16 = 2;
+pub const BOND_3AD_STAT_LACPDU_ILLEGAL_RX: u16 = 3;
+pub const BOND_3AD_STAT_MARKER_RX: u16 = 4;
+pub const BOND_3AD_STAT_MARKER_TX: u16 = 5;
+pub const BOND_3AD_STAT_MARKER_RESP_RX: u16 = 6;
+pub const BOND_3AD_STAT_MARKER_RESP_TX: u16 = 7;
+pub const BOND_3AD_STAT_MARKER_UNKNOWN_RX: u16 = 8;
+
+/// Kind-specific statistics of a link, nested in `IFLA_STATS_LINK_XSTATS` and
+/// `IFLA_STATS_LINK_XSTATS_SLAVE`. They correspond to `LINK_XSTATS_TYPE_*` in
+/// libc.
+///
+/// For the slave variant, the kind is the one of the master of the link.
+#[doc(alias("LINK_XSTATS_TYPE_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkExtendedStats {
+    /// `LINK_XSTATS_TYPE_BRIDGE`
+    Bridge(Vec<BridgeExtendedStats>),
+
+    /// `LINK_XSTATS_TYPE_BOND`
+    Bond(Vec<BondExtendedStats>),
+
+    Other(u16, Vec<u8>),
+}
+
+/// Per-VLAN traffic counters of a bridge.
+#[doc(alias("bridge_vlan_xstats"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BridgeVlanStats {
+    pub rx_bytes: u64,
+    pub rx_packets: u64,
+    pub tx_bytes: u64,
+    pub tx_packets: u64,
+    pub vid: u16,
+    pub flags: u16,
+}
+
+/// Spanning tree counters of a bridge port.
+#[doc(alias("bridge_stp_xstats"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct BridgeStpStats {
+    pub transition_blocked: u64,
+    pub transition_forwarding: u64,
+    pub rx_bpdu: u64,
+    pub tx_bpdu: u64,
+    pub rx_tcn: u64,
+    pub tx_tcn: u64,
+}
+
+/// Statistics of a bridge or bridge port, corresponding to `BRIDGE_XSTATS_*` in
+/// libc.
+#[doc(alias("BRIDGE_XSTATS_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgeExtendedStats {
+    /// `BRIDGE_XSTATS_VLAN` - reported once for each VLAN of a bridge.
+    Vlan(BridgeVlanStats),
+
+    /// `BRIDGE_XSTATS_MCAST` - a `struct br_mcast_stats`.
+    Multicast(Vec<u8>),
+
+    /// `BRIDGE_XSTATS_STP`
+    Stp(BridgeStpStats),
+
+    Other(u16, Vec<u8>),
+}
+
+/// 802.3ad (LACP) counters of a bond or bond slave. Counters that the kernel
+/// did not report are left as zero.
+#[doc(alias("BOND_3AD_STAT_"))]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Bond3adStats {
+    pub lacpdu_rx: u64,
+    pub lacpdu_tx: u64,
+    pub lacpdu_unknown_rx: u64,
+    pub lacpdu_illegal_rx: u64,
+    pub marker_rx: u64,
+    pub marker_tx: u64,
+    pub marker_response_rx: u64,
+    pub marker_response_tx: u64,
+    pub marker_unknown_rx: u64,
+}
+
+/// Statistics of a bond or bond slave, corresponding to `BOND_XSTATS_*` in
+/// libc.
+#[doc(alias("BOND_XSTATS_"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BondExtendedStats {
+    /// `BOND_XSTATS_3AD`
+    Lacp(Bond3adStats),
+
+    Other(u16, Vec<u8>),
+}
+
+impl LinkExtendedStats {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Bridge(stats) => {
+                stats.iter().for_each(|s| s.serialize_into(buffer));
+                LINK_XSTATS_TYPE_BRIDGE
+            }
+            Self::Bond(stats) => {
+                stats.iter().for_each(|s| s.serialize_into(buffer));
+                LINK_XSTATS_TYPE_BOND
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for LinkExtendedStats {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            LINK_XSTATS_TYPE_BRIDGE => {
+                let stats = RawAttributeIter::new(content.iter().cloned())
+                    .map(BridgeExtendedStats::from_raw)
+                    .try_collect()?;
+                Self::Bridge(stats)
+            }
+            LINK_XSTATS_TYPE_BOND => {
+                let stats = RawAttributeIter::new(content.iter().cloned())
+                    .map(BondExtendedStats::from_raw)
+                    .try_collect()?;
+                Self::Bond(stats)
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+impl BridgeExtendedStats {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Vlan(stats) => {
+                buffer.extend(stats.rx_bytes.to_ne_bytes());
+                buffer.extend(stats.rx_packets.to_ne_bytes());
+                buffer.extend(stats.tx_bytes.to_ne_bytes());
+                buffer.extend(stats.tx_packets.to_ne_bytes());
+                buffer.extend(stats.vid.to_ne_bytes());
+                buffer.extend(stats.flags.to_ne_bytes());
+                buffer.extend(0u32.to_ne_bytes()); // pad2
+                BRIDGE_XSTATS_VLAN
+            }
+            Self::Multicast(content) => {
+                buffer.extend(content.iter());
+                BRIDGE_XSTATS_MCAST
+            }
+            Self::Stp(stats) => {
+                // SAFETY: BridgeStpStats does not contain any paddings.
+                let bytes = unsafe {
+                    transmute::<BridgeStpStats, [u8; size_of::<BridgeStpStats>()]>(stats.clone())
+                };
+                buffer.extend(bytes);
+                BRIDGE_XSTATS_STP
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for BridgeExtendedStats {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            BRIDGE_XSTATS_VLAN => {
+                let content = <[u8; 40]>::try_from(content).ok()?;
+                let u64_at = |i: usize| u64::from_ne_bytes(content[i..i + 8].try_into().unwrap());
+
+                Self::Vlan(BridgeVlanStats {
+                    rx_bytes: u64_at(0),
+                    rx_packets: u64_at(8),
+                    tx_bytes: u64_at(16),
+                    tx_packets: u64_at(24),
+                    vid: u16::from_ne_bytes([content[32], content[33]]),
+                    flags: u16::from_ne_bytes([content[34], content[35]]),
+                })
+            }
+            BRIDGE_XSTATS_MCAST => Self::Multicast(content),
+            BRIDGE_XSTATS_STP => {
+                let content = <[u8; size_of::<BridgeStpStats>()]>::try_from(content).ok()?;
+                // SAFETY: BridgeStpStats is a plain-old-data struct and contains no
+                // paddings.
+                let stats = unsafe {
+                    transmute::<[u8; size_of::<BridgeStpStats>()], BridgeStpStats>(content)
+                };
+                Self::Stp(stats)
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+impl BondExtendedStats {
+    pub fn serialize_into(&self, buffer: &mut Vec<u8>) {
+        utils::serialize_attribute_into(buffer, |buffer| match self {
+            Self::Lacp(stats) => {
+                let counters = [
+                    (BOND_3AD_STAT_LACPDU_RX, stats.lacpdu_rx),
+                    (BOND_3AD_STAT_LACPDU_TX, stats.lacpdu_tx),
+                    (BOND_3AD_STAT_LACPDU_UNKNOWN_RX, stats.lacpdu_unknown_rx),
+                    (BOND_3AD_STAT_LACPDU_ILLEGAL_RX, stats.lacpdu_illegal_rx),
+                    (BOND_3AD_STAT_MARKER_RX, stats.marker_rx),
+                    (BOND_3AD_STAT_MARKER_TX, stats.marker_tx),
+                    (BOND_3AD_STAT_MARKER_RESP_RX, stats.marker_response_rx),
+                    (BOND_3AD_STAT_MARKER_RESP_TX, stats.marker_response_tx),
+                    (BOND_3AD_STAT_MARKER_UNKNOWN_RX, stats.marker_unknown_rx),
+                ];
+
+                for (attr_type, counter) in counters {
+                    utils::serialize_attribute_into(buffer, |buffer| {
+                        buffer.extend(counter.to_ne_bytes());
+                        attr_type
+                    });
+                }
+                BOND_XSTATS_3AD
+            }
+            Self::Other(attr_type, content) => {
+                buffer.extend(content.iter());
+                *attr_type
+            }
+        });
+    }
+}
+
+impl Attribute for BondExtendedStats {
+    fn from_raw(raw: RawAttribute) -> Option<Self> {
+        let attr_type = raw.attr_type();
+        let content = raw.into_payload();
+
+        let attr = match attr_type {
+            BOND_XSTATS_3AD => {
+                let mut stats = Bond3adStats::default();
+
+                for counter in RawAttributeIter::new(content.iter().cloned()) {
+                    let attr_type = counter.attr_type();
+                    let Ok(value) = <[u8; 8]>::try_from(counter.into_payload()) else {
+                        // BOND_3AD_STAT_PAD
+                        continue;
+                    };
+                    let value = u64::from_ne_bytes(value);
+
+                    match attr_type {
+                        BOND_3AD_STAT_LACPDU_RX => stats.lacpdu_rx = value,
+                        BOND_3AD_STAT_LACPDU_TX => stats.lacpdu_tx = value,
+                        BOND_3AD_STAT_LACPDU_UNKNOWN_RX => stats.lacpdu_unknown_rx = value,
+                        BOND_3AD_STAT_LACPDU_ILLEGAL_RX => stats.lacpdu_illegal_rx = value,
+                        BOND_3AD_STAT_MARKER_RX => stats.marker_rx = value,
+                        BOND_3AD_STAT_MARKER_TX => stats.marker_tx = value,
+                        BOND_3AD_STAT_MARKER_RESP_RX => stats.marker_response_rx = value,
+                        BOND_3AD_STAT_MARKER_RESP_TX => stats.marker_response_tx = value,
+                        BOND_3AD_STAT_MARKER_UNKNOWN_RX => stats.marker_unknown_rx = value,
+                        _ => {}
+                    }
+                }
+
+                Self::Lacp(stats)
+            }
+            x => Self::Other(x, content),
+        };
+
+        Some(attr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bridge_vlan_layout() {
+        // struct bridge_vlan_xstats with vid 10 and flags 1
+        let mut content = Vec::new();
+        for counter in [100u64, 2, 300, 4] {
+            content.extend(counter.to_ne_bytes());
+        }
+        content.extend(10u16.to_ne_bytes());
+        content.extend(1u16.to_ne_bytes());
+        content.extend(0u32.to_ne_bytes());
+
+        let raw = RawAttribute::new(BRIDGE_XSTATS_VLAN, content.clone());
+        let stats = BridgeVlanStats {
+            rx_bytes: 100,
+            rx_packets: 2,
+            tx_bytes: 300,
+            tx_packets: 4,
+            vid: 10,
+            flags: 1,
+        };
+        assert_eq!(BridgeExtendedStats::from_raw(raw), Some(BridgeExtendedStats::Vlan(stats.clone())));
+
+        let mut buffer = Vec::new();
+        BridgeExtendedStats::Vlan(stats).serialize_into(&mut buffer);
+        assert_eq!(&buffer[4..], &content[..]);
+
+        let raw = RawAttribute::new(BRIDGE_XSTATS_VLAN, content[..36].to_vec());
+        assert_eq!(BridgeExtendedStats::from_raw(raw), None);
+    }
+
+    #[test]
+    fn bridge_stp_layout() {
+        // struct bridge_stp_xstats is six counters in a row
+        let content: Vec<_> = (1u64..=6).flat_map(|x| x.to_ne_bytes()).collect();
+        let stats = BridgeStpStats {
+            transition_blocked: 1,
+            transition_forwarding: 2,
+            rx_bpdu: 3,
+            tx_bpdu: 4,
+            rx_tcn: 5,
+            tx_tcn: 6,
+        };
+
+        let raw = RawAttribute::new(BRIDGE_XSTATS_STP, content.clone());
+        assert_eq!(BridgeExtendedStats::from_raw(raw), Some(BridgeExtendedStats::Stp(stats.clone())));
+
+        let mut buffer = Vec::new();
+        BridgeExtendedStats::Stp(stats).serialize_into(&mut buffer);
+        assert_eq!(&buffer[4..], &content[..]);
+    }
+
+    #[test]
+    fn bond_counters() {
+        let mut content = Vec::new();
+        let counter = |buffer: &mut Vec<u8>, attr_type, payload: &[u8]| {
+            utils::serialize_attribute_into(buffer, |buffer| {
+                buffer.extend(payload);
+                attr_type
+            });
+        };
+        counter(&mut content, BOND_3AD_STAT_LACPDU_TX, &8u64.to_ne_bytes());
+        counter(&mut content, 9, &[]); // BOND_3AD_STAT_PAD
+        counter(&mut content, BOND_3AD_STAT_MARKER_UNKNOWN_RX, &1u64.to_ne_bytes());
+
+        let mut buffer = Vec::new();
+        utils::serialize_attribute_into(&mut buffer, |buffer| {
+            counter(buffer, BOND_XSTATS_3AD, &content);
+            LINK_XSTATS_TYPE_BOND
+        });
+
+        let raw = RawAttributeIter::new(buffer.into_iter()).next().unwrap();
+        let expected = LinkExtendedStats::Bond(vec![BondExtendedStats::Lacp(Bond3adStats {
+            lacpdu_tx: 8,
+            marker_unknown_rx: 1,
+            ..Default::default()
+        })]);
+        assert_eq!(LinkExtendedStats::from_raw(raw), Some(expected));
+    }
+}
@@ -0,0 +1,27 @@
+use bitflags::bitflags;
+
+use super::{
+    IFLA_STATS_AF_SPEC, IFLA_STATS_LINK_64, IFLA_STATS_LINK_OFFLOAD_XSTATS,
+    IFLA_STATS_LINK_XSTATS, IFLA_STATS_LINK_XSTATS_SLAVE,
+};
+
+/// Equivalent to the `IFLA_STATS_FILTER_BIT` macro in libc.
+const fn filter_bit(attr: u16) -> u32 {
+    1 << (attr - 1)
+}
+
+bitflags! {
+    /// Selects which statistics the kernel includes in a `NewStats` reply.
+    ///
+    /// Each bit corresponds to a `IFLA_STATS_*` attribute, as built by
+    /// `IFLA_STATS_FILTER_BIT` in libc.
+    #[doc(alias("filter_mask", "IFLA_STATS_FILTER_BIT"))]
+    #[derive(Copy, Debug, Clone, PartialEq, Eq)]
+    pub struct StatsFilter: u32 {
+        const Link64 = filter_bit(IFLA_STATS_LINK_64);
+        const LinkExtendedStats = filter_bit(IFLA_STATS_LINK_XSTATS);
+        const LinkExtendedStatsSlave = filter_bit(IFLA_STATS_LINK_XSTATS_SLAVE);
+        const LinkOffloadExtendedStats = filter_bit(IFLA_STATS_LINK_OFFLOAD_XSTATS);
+        const AddressFamilySpecific = filter_bit(IFLA_STATS_AF_SPEC);
+    }
+}
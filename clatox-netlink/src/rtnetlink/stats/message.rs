@@ -0,0 +1,139 @@
+use crate::netlink::{Attribute, RawAttributeIter};
+use crate::netlink::{Payload, RouteType, Type};
+use crate::rtnetlink::AddressFamily;
+use crate::utils::{align_attribute_len, read_u32};
+
+use super::{StatsAttribute, StatsFilter};
+
+/// The message on which the `GetStats` requests and `NewStats` replies are
+/// based on. It corresponds to the `if_stats_msg` struct in libc.
+///
+/// Leave `index` as 0 and set the `Dump` flag to fetch the statistics of all
+/// links. Only the statistics selected by `filter_mask` are returned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[doc(alias("if_stats_msg"))]
+pub struct StatsMessage {
+    family: AddressFamily,
+    index: u32,
+    filter_mask: StatsFilter,
+    attributes: Vec<StatsAttribute>,
+}
+
+impl StatsMessage {
+    pub const fn new(
+        index: u32,
+        filter_mask: StatsFilter,
+        attributes: Vec<StatsAttribute>,
+    ) -> Self {
+        Self {
+            family: AddressFamily::Unspecified,
+            index,
+            filter_mask,
+            attributes,
+        }
+    }
+
+    pub const fn family(&self) -> AddressFamily {
+        self.family
+    }
+
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub const fn filter_mask(&self) -> StatsFilter {
+        self.filter_mask
+    }
+
+    pub fn attributes(&self) -> &[StatsAttribute] {
+        &self.attributes
+    }
+
+    pub fn serialize(&self) -> Box<[u8]> {
+        let mut buffer = Vec::with_capacity(12);
+        buffer.push(self.family.raw_value());
+        buffer.push(0u8); // pad1
+        buffer.extend(0u16.to_ne_bytes()); // pad2
+        buffer.extend(self.index.to_ne_bytes());
+        buffer.extend(self.filter_mask.bits().to_ne_bytes());
+
+        for attr in &self.attributes {
+            attr.serialize_into(&mut buffer)
+        }
+
+        buffer.into_boxed_slice()
+    }
+
+    pub fn deserialize(bytes: &[u8]) -> Option<Self> {
+        // The header is 12 bytes. If the data we receive is shorter than that
+        // it's not going to be valid
+        if bytes.len() < 12 {
+            return None;
+        }
+
+        let mut iter = bytes.iter().cloned();
+
+        let family = iter.next()?;
+        let _pad1 = iter.next()?;
+        let _pad2 = [iter.next()?, iter.next()?];
+        let index = read_u32(iter.by_ref())?;
+        let filter_mask = read_u32(iter.by_ref())?;
+
+        let family = AddressFamily::from_raw_value(family)?;
+        let filter_mask = StatsFilter::from_bits_retain(filter_mask);
+
+        // We have read 12 bytes so far. Align it to NLA_ALIGNTO bytes and start
+        // deserializing the attributes.
+        let aligned_len = align_attribute_len(12);
+        for _ in 0..(aligned_len - 12) {
+            iter.next()?;
+        }
+
+        let attributes = RawAttributeIter::new(iter)
+            .map(StatsAttribute::from_raw)
+            .try_collect()?;
+
+        Some(StatsMessage {
+            family,
+            index,
+            filter_mask,
+            attributes,
+        })
+    }
+}
+
+/// A message that is of the `RTM_GETSTATS` type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetStats(pub StatsMessage);
+
+impl Payload for GetStats {
+    fn message_type() -> Type {
+        Type::Route(RouteType::GetStats)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(StatsMessage::deserialize(bytes)?))
+    }
+}
+
+/// A message that is of the `RTM_NEWSTATS` type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewStats(pub StatsMessage);
+
+impl Payload for NewStats {
+    fn message_type() -> Type {
+        Type::Route(RouteType::NewStats)
+    }
+
+    fn serialize(&self) -> Box<[u8]> {
+        self.0.serialize()
+    }
+
+    fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Some(Self(StatsMessage::deserialize(bytes)?))
+    }
+}
@@ -0,0 +1,16 @@
+mod attribute;
+mod extended;
+mod filter;
+mod message;
+
+#[doc(inline)]
+pub use attribute::*;
+
+#[doc(inline)]
+pub use extended::*;
+
+#[doc(inline)]
+pub use filter::*;
+
+#[doc(inline)]
+pub use message::*;
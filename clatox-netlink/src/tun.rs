@@ -0,0 +1,273 @@
+//! Creation of TUN/TAP devices through `/dev/net/tun`.
+//!
+//! Unlike most links, TUN/TAP devices cannot be created with rtnetlink. A
+//! device is created (or attached to, if it already exists) by opening
+//! `/dev/net/tun` and issuing the `TUNSETIFF` ioctl. Each opened file
+//! descriptor is a queue, from which packets sent to the device are read and
+//! to which packets received by the device are written.
+//!
+//! Once created, the device can be configured with rtnetlink like any other
+//! link, and is reported with the `tun` link kind.
+
+use libc::*;
+
+use std::fs::{File, OpenOptions};
+use std::io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult};
+use std::os::fd::AsRawFd;
+
+use crate::rtnetlink::{TunFlags, TunType};
+
+const TUN_PATH: &str = "/dev/net/tun";
+
+// The direction bits of ioctl numbers differ on a few architectures, see
+// <asm/ioctl.h>
+#[cfg(any(
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+))]
+const IOC_WRITE: c_ulong = 4 << 29;
+#[cfg(not(any(
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+)))]
+const IOC_WRITE: c_ulong = 1 << 30;
+
+/// `_IOW()` from <asm/ioctl.h>
+const fn iow(ioctl_type: u8, number: u8, size: usize) -> c_ulong {
+    IOC_WRITE | (size as c_ulong) << 16 | (ioctl_type as c_ulong) << 8 | number as c_ulong
+}
+
+// From <linux/if_tun.h>
+const TUNSETIFF: c_ulong = iow(b'T', 202, std::mem::size_of::<c_int>());
+const TUNSETPERSIST: c_ulong = iow(b'T', 203, std::mem::size_of::<c_int>());
+const TUNSETOWNER: c_ulong = iow(b'T', 204, std::mem::size_of::<c_int>());
+const TUNSETGROUP: c_ulong = iow(b'T', 206, std::mem::size_of::<c_int>());
+
+/// The part of `struct ifreq` used by `TUNSETIFF`, padded to the size of
+/// `struct ifreq`, which depends on the size of pointers.
+#[repr(C)]
+struct TunRequest {
+    name: [u8; IFNAMSIZ],
+    flags: c_short,
+    _pad: [u8; TUN_REQUEST_PAD],
+}
+
+const TUN_REQUEST_PAD: usize =
+    std::mem::size_of::<ifreq>() - IFNAMSIZ - std::mem::size_of::<c_short>();
+
+/// Options for creating a TUN/TAP device.
+///
+/// By default, the device is ephemeral: it is removed once all of its queues
+/// are closed. Use `with_persist` to keep it around, e.g. to configure it
+/// before the process that does the packet I/O attaches to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TunOptions {
+    name: Option<String>,
+    device_type: TunType,
+    flags: TunFlags,
+    queues: usize,
+    persist: bool,
+    owner: Option<uid_t>,
+    group: Option<gid_t>,
+}
+
+impl TunOptions {
+    pub const fn new(device_type: TunType) -> Self {
+        Self {
+            name: None,
+            device_type,
+            flags: TunFlags::NoPacketInfo,
+            queues: 1,
+            persist: false,
+            owner: None,
+            group: None,
+        }
+    }
+
+    /// Sets the name of the device. If a device of this name already exists,
+    /// it is attached to instead. Without a name, the kernel picks one, e.g.
+    /// `tun0`. A `%d` in the name is replaced by the first free number.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    /// Sets the flags of the device. The default is `TunFlags::NoPacketInfo`.
+    pub fn with_flags(mut self, flags: TunFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Sets the number of queues to open. More than one queue requires
+    /// `TunFlags::MultiQueue`.
+    pub fn with_queues(mut self, queues: usize) -> Self {
+        self.queues = queues;
+        self
+    }
+
+    /// Makes the device outlive its queues. It then has to be deleted with
+    /// `DeleteLink`.
+    pub fn with_persist(mut self, persist: bool) -> Self {
+        self.persist = persist;
+        self
+    }
+
+    /// Allows the given user to attach to the device without `CAP_NET_ADMIN`.
+    pub fn with_owner(mut self, owner: uid_t) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+
+    /// Allows the given group to attach to the device without
+    /// `CAP_NET_ADMIN`.
+    pub fn with_group(mut self, group: gid_t) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub const fn device_type(&self) -> TunType {
+        self.device_type
+    }
+
+    pub const fn flags(&self) -> TunFlags {
+        self.flags
+    }
+
+    /// Creates the device, or attaches to it if it already exists, and opens
+    /// its queues.
+    pub fn create(&self) -> IoResult<TunDevice> {
+        if self.queues == 0 || (self.queues > 1 && !self.flags.contains(TunFlags::MultiQueue)) {
+            return Err(IoError::new(
+                IoErrorKind::InvalidInput,
+                "more than one queue requires TunFlags::MultiQueue",
+            ));
+        }
+
+        let mut request = TunRequest {
+            name: [0u8; IFNAMSIZ],
+            flags: (self.device_type.raw_value() as u16 | self.flags.bits()) as c_short,
+            _pad: [0u8; TUN_REQUEST_PAD],
+        };
+
+        if let Some(name) = &self.name {
+            // The name must leave room for the terminating zero
+            if name.len() >= IFNAMSIZ {
+                return Err(IoError::new(IoErrorKind::InvalidInput, "interface name too long"));
+            }
+            request.name[..name.len()].copy_from_slice(name.as_bytes());
+        }
+
+        let mut queues = Vec::with_capacity(self.queues);
+        for _ in 0..self.queues {
+            let queue = OpenOptions::new().read(true).write(true).open(TUN_PATH)?;
+
+            // SAFETY: TUNSETIFF reads and writes a struct ifreq, of which
+            // TunRequest has the size and the layout of the used fields. The
+            // kernel fills in the actual name, so later queues attach to the
+            // same device.
+            ioctl_result(unsafe { ioctl(queue.as_raw_fd(), TUNSETIFF as _, &mut request) })?;
+            queues.push(queue);
+        }
+
+        let fd = queues[0].as_raw_fd();
+        // SAFETY: The ioctls below take their argument by value.
+        if let Some(owner) = self.owner {
+            ioctl_result(unsafe { ioctl(fd, TUNSETOWNER as _, owner as c_ulong) })?;
+        }
+        if let Some(group) = self.group {
+            ioctl_result(unsafe { ioctl(fd, TUNSETGROUP as _, group as c_ulong) })?;
+        }
+        if self.persist {
+            ioctl_result(unsafe { ioctl(fd, TUNSETPERSIST as _, 1 as c_ulong) })?;
+        }
+
+        let len = request.name.iter().position(|&x| x == 0).unwrap_or(IFNAMSIZ);
+        let name = String::from_utf8_lossy(&request.name[..len]).into_owned();
+
+        Ok(TunDevice { name, queues })
+    }
+}
+
+fn ioctl_result(result: c_int) -> IoResult<()> {
+    if result < 0 {
+        return Err(IoError::last_os_error());
+    }
+    Ok(())
+}
+
+/// A created TUN/TAP device, along with its opened queues.
+///
+/// Each queue is a file from which one packet is read per `read` call, and to
+/// which one packet is written per `write` call. Closing all of them removes
+/// the device, unless it was made persistent.
+#[derive(Debug)]
+pub struct TunDevice {
+    name: String,
+    queues: Vec<File>,
+}
+
+impl TunDevice {
+    /// The name of the device, as picked by the kernel.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn queues(&self) -> &[File] {
+        &self.queues
+    }
+
+    pub fn into_queues(self) -> Vec<File> {
+        self.queues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    #[test]
+    fn ioctl_numbers() {
+        assert_eq!(TUNSETIFF, 0x400454ca);
+        assert_eq!(TUNSETPERSIST, 0x400454cb);
+        assert_eq!(TUNSETOWNER, 0x400454cc);
+        assert_eq!(TUNSETGROUP, 0x400454ce);
+    }
+
+    #[test]
+    fn request_layout() {
+        use std::mem::{offset_of, size_of};
+
+        assert_eq!(size_of::<TunRequest>(), size_of::<ifreq>());
+        assert_eq!(offset_of!(TunRequest, name), offset_of!(ifreq, ifr_name));
+        assert_eq!(offset_of!(TunRequest, flags), offset_of!(ifreq, ifr_ifru));
+    }
+
+    #[test]
+    fn type_raw_value() {
+        assert_eq!(TunType::from_raw_value(TunType::Tun.raw_value()), TunType::Tun);
+        assert_eq!(TunType::from_raw_value(TunType::Tap.raw_value()), TunType::Tap);
+        assert_eq!(TunType::from_raw_value(0x10), TunType::Other(0x10));
+    }
+
+    #[test]
+    fn invalid_options() {
+        let options = TunOptions::new(TunType::Tun).with_queues(2);
+        assert_eq!(options.create().unwrap_err().kind(), IoErrorKind::InvalidInput);
+
+        let options = TunOptions::new(TunType::Tap).with_name("a-very-long-name");
+        assert_eq!(options.create().unwrap_err().kind(), IoErrorKind::InvalidInput);
+    }
+}
@@ -1,5 +1,3 @@
-use std::net::IpAddr;
-
 use clatox_netlink::netlink::*;
 use clatox_netlink::rtnetlink::*;
 use clatox_netlink::rtnetlink::RouteType;
@@ -11,15 +9,6 @@ fn remove_line_with_brace(output: String) -> String {
         .fold(String::new(), |acc, x| acc + "\n" + x.strip_suffix(',').unwrap())
 }
 
-fn pretty_address(output: &[u8]) -> String {
-    match output.len() {
-        4 => IpAddr::from(<[u8; 4]>::try_from(output).unwrap()).to_string(),
-        6 => output.iter().map(|x| format!("{x:x}")).collect::<Vec<_>>().join(":"),
-        16 => IpAddr::from(<[u8; 16]>::try_from(output).unwrap()).to_string(),
-        _ => format!("{:?}", output),
-    }
-}
-
 fn show_link() {
     let mut socket = Socket::connect_to_kernel(Protocol::Route)
         .expect("unable to open netlink socket");
@@ -46,8 +35,9 @@ fn show_link() {
             use InterfaceInfoAttribute::*;
             match attr {
                 InterfaceName(name) => println!("  interface name: {}", name),
-                Address(addr) => println!("  interface address: {}", pretty_address(addr)),
-                Broadcast(brd) => println!("  interface broadcast: {}", pretty_address(brd)),
+                Address(addr) => println!("  interface address: {}", addr),
+                Broadcast(brd) => println!("  interface broadcast: {}", brd),
+                PermanentAddress(addr) => println!("  interface permanent address: {}", addr),
                 MTU(mtu) => println!("  interface MTU: {}", mtu),
                 MinMTU(mtu) => println!("  interface minimum MTU: {}", mtu),
                 MaxMTU(mtu) => println!("  interface maximum MTU: {}", mtu),
@@ -58,6 +48,16 @@ fn show_link() {
                 ParentDeviceName(name) => println!("  interface parent device name: {}", name),
                 ParentDeviceBusName(name) => println!("  interface parent device bus name: {}", name),
                 InterfaceAlias(name) => println!("  interface alias: {}", name),
+                OperationalState(state) => println!("  interface operational state: {:?}", state),
+                CarrierUpCount(count) => println!("  interface carrier up count: {}", count),
+                CarrierDownCount(count) => println!("  interface carrier down count: {}", count),
+                PropertiesList(properties) => {
+                    for property in properties {
+                        if let AlternativeName(name) = property {
+                            println!("  interface alternative name: {}", name);
+                        }
+                    }
+                }
                 Stats64(stats) =>
                     println!("  interface stats: {}", remove_line_with_brace(format!("{:#?}", stats))),
                 _ => (),
@@ -97,6 +97,8 @@ fn show_addr() {
             AddressFamily::Unspecified,
             0,
             AddressFlags::empty(),
+            RouteScope::Universe,
+            0,
             vec![]
         ))
     );
@@ -111,8 +113,8 @@ fn show_addr() {
         for attr in content.attributes() {
             use InterfaceAddressAttribute::*;
             match attr {
-                Address(addr) => println!("  interface address: {}", pretty_address(addr)),
-                Local(addr) => println!("  interface local address: {}", pretty_address(addr)),
+                Address(addr) => println!("  interface address: {}", addr),
+                Local(addr) => println!("  interface local address: {}", addr),
                 Label(label) => println!("  interface label: {}", label),
                 CacheInfo(cache) =>
                     println!("  interface cache info: {}", remove_line_with_brace(format!("{:#?}", cache))),
@@ -178,12 +180,29 @@ fn show_route() {
         for attr in content.attributes() {
             use RouteAttribute::*;
             match attr {
-                Destination(addr) => println!("  route destination: {}", pretty_address(addr)),
-                Source(addr) => println!("  route source: {}", pretty_address(addr)),
+                Destination(addr) => println!("  route destination: {}", addr),
+                Source(addr) => println!("  route source: {}", addr),
                 IncomingInterface(iif) => println!("  route incoming interface: {}", iif),
                 OutgoingInterface(oif) => println!("  route outgoing interface: {}", oif),
-                Gateway(addr) => println!("  route gateway: {}", pretty_address(addr)),
-                Metrics(met) =>  println!("  route metrics: {}", met),
+                Gateway(addr) => println!("  route gateway: {}", addr),
+                Via(addr) => println!("  route via: {}", addr),
+                Multipath(next_hops) => {
+                    for next_hop in next_hops {
+                        println!(
+                            "  route next hop: interface {}, weight {}",
+                            next_hop.index,
+                            next_hop.weight()
+                        );
+                    }
+                }
+                Metrics(met) => {
+                    if let Some(mtu) = met.mtu {
+                        println!("  route mtu: {}", mtu);
+                    }
+                    if let Some(mss) = met.advertised_mss {
+                        println!("  route advmss: {}", mss);
+                    }
+                }
                 _ => (),
             }
         }
@@ -208,6 +227,47 @@ fn show_route() {
     }
 }
 
+fn show_maddr() {
+    let mut socket = Socket::connect_to_kernel(Protocol::Route)
+        .expect("unable to open netlink socket");
+
+    let groups = dump_multicast_groups(&mut socket, AddressFamily::Unspecified, 0)
+        .expect("unable to dump multicast groups");
+    let addresses = dump_anycast_addresses(&mut socket, 0)
+        .expect("unable to dump anycast addresses");
+
+    // Like `ip maddr`, the addresses are listed per interface
+    let mut indices: Vec<_> = groups.iter().chain(&addresses).map(|x| x.index).collect();
+    indices.sort();
+    indices.dedup();
+
+    for index in indices {
+        println!("  interface index: {}", index);
+        for group in groups.iter().filter(|x| x.index == index) {
+            println!("  multicast group: {}", group.address);
+        }
+        for address in addresses.iter().filter(|x| x.index == index) {
+            println!("  anycast address: {}", address.address);
+        }
+        println!();
+    }
+}
+
+fn show_rule() {
+    let mut socket = Socket::connect_to_kernel(Protocol::Route)
+        .expect("unable to open netlink socket");
+
+    let rules = dump_rules(&mut socket, AddressFamily::Unspecified)
+        .expect("unable to dump rules");
+    for rule in rules {
+        println!("  rule family: {:?}", rule.family());
+        println!("  rule priority: {:?}", rule.priority());
+        println!("  rule action: {:?}", rule.action());
+        println!("  rule table: {}", rule.table_id());
+        println!();
+    }
+}
+
 fn main() {
     println!("Printing info dumped with RTM_GETLINK\n=====================================");
     show_link();
@@ -217,7 +277,15 @@ fn main() {
     println!("Printing info dumped with RTM_GETADDR\n=====================================");
     show_addr();
 
+    println!("");
+    println!("Printing info dumped with RTM_GETMULTICAST and RTM_GETANYCAST\n=============================================================");
+    show_maddr();
+
     println!("");
     println!("Printing info dumped with RTM_GETROUTE\n=====================================");
     show_route();
+
+    println!("");
+    println!("Printing info dumped with RTM_GETRULE\n====================================");
+    show_rule();
 }